@@ -4,13 +4,21 @@ in vec3 n_ws;
 in vec3 pos_ws;
 in vec2 tc;
 
-uniform float pointlight_intensity;
-uniform vec3 pointlight_color;
-uniform vec3 pointlight_pos;
-uniform vec3 camera_pos;
-uniform vec3 dirlight_dir;
-uniform vec3 dirlight_color;
-uniform float dirlight_intensity;
+layout(std140) uniform Camera {
+    mat4 view;
+    mat4 projection;
+    vec3 camera_pos;
+};
+
+layout(std140) uniform Lights {
+    vec3 pointlight_pos;
+    float pointlight_intensity;
+    vec3 pointlight_color;
+    vec3 dirlight_dir;
+    vec3 dirlight_color;
+    float dirlight_intensity;
+};
+
 //uniform vec4 k_diff;
 //uniform vec4 k_spec;
 
@@ -4,11 +4,15 @@ layout(location = 0) in vec3 in_pos;
 layout(location = 1) in vec3 in_norm;
 layout(location = 2) in vec2 in_tc;
 
+layout(std140) uniform Camera {
+    mat4 view;
+    mat4 projection;
+    vec3 camera_pos;
+};
+
 uniform mat4 model;
-uniform mat4 view;
-uniform mat4 projection;
-uniform mat4 view_normal;
-uniform mat4 model_normal;
+uniform mat3 view_normal;
+uniform mat3 model_normal;
 
 out vec3 pos_ws;
 out vec3 n_ws;
@@ -18,6 +22,6 @@ void main()
 {
     gl_Position = projection * view * model * vec4(in_pos, 1.0);
     pos_ws = vec3(model * vec4(in_pos, 1.0));
-    n_ws = vec3(model_normal * vec4(in_norm, 0.0));
+    n_ws = model_normal * in_norm;
 	tc = in_tc;
 }
\ No newline at end of file
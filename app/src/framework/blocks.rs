@@ -0,0 +1,36 @@
+use rmath::{mat4::Mat4, vec3::Vec3};
+
+use crate::framework::camera::Camera;
+
+gl_sys::std140! {
+    /// Matches `uniform Camera` in the shaders; uploaded once per frame.
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct CameraBlock {
+        pub view: Mat4<f32>,
+        pub projection: Mat4<f32>,
+        pub camera_pos: Vec3<f32>,
+    }
+}
+
+impl CameraBlock {
+    pub fn new(camera: &Camera, aspect_ratio: f32) -> Self {
+        Self {
+            view: camera.view(),
+            projection: camera.projection(aspect_ratio),
+            camera_pos: *camera.position(),
+        }
+    }
+}
+
+gl_sys::std140! {
+    /// Matches `uniform Lights` in the shaders. Colors are linear.
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct LightsBlock {
+        pub pointlight_pos: Vec3<f32>,
+        pub pointlight_intensity: f32,
+        pub pointlight_color: Vec3<f32>,
+        pub dirlight_dir: Vec3<f32>,
+        pub dirlight_color: Vec3<f32>,
+        pub dirlight_intensity: f32,
+    }
+}
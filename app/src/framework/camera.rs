@@ -1,4 +1,4 @@
-use rmath::{Scalar, mat4::Mat4, vec3::Vec3, vec4::Vec4};
+use rmath::{Scalar, frustum::Frustum, mat3::Mat3, mat4::Mat4, quat::Quat, vec3::Vec3};
 
 #[derive(Debug)]
 pub struct Camera {
@@ -35,15 +35,19 @@ impl Camera {
 
     pub fn view(&self) -> Mat4<f32> {
         let look = self.position + self.dir;
-        build_view_matrix(&self.position, &look, &self.up)
+        Mat4::look_at(&self.position, &look, &self.up)
     }
 
-    pub fn view_normal(&self) -> Mat4<f32> {
-        self.view().invert().unwrap_or(Mat4::identity()).transpose()
+    pub fn view_normal(&self) -> Mat3<f32> {
+        self.view().normal_matrix()
     }
 
     pub fn projection(&self, aspect_ratio: f32) -> Mat4<f32> {
-        perspective(self.fov_deg, aspect_ratio, self.near_plane, self.far_plane)
+        Mat4::perspective(self.fov_deg, aspect_ratio, self.near_plane, self.far_plane)
+    }
+
+    pub fn frustum(&self, aspect_ratio: f32) -> Frustum<f32> {
+        Frustum::from_matrix(&(self.projection(aspect_ratio) * self.view()))
     }
 
     pub const fn position(&self) -> &Vec3<f32> {
@@ -88,44 +92,23 @@ impl Camera {
     }
 
     pub fn rotate(&mut self, angle: f32, axis: &Vec3<f32>) {
-        self.dir = self.dir.rotate(angle, axis);
+        self.orient(&Quat::from_axis_angle(angle, axis));
     }
 
     pub fn rotate_horizontal(&mut self, angle: f32) {
-        self.dir = self.dir.rotate(angle, &self.horizontal_rotation_axis());
+        self.rotate(angle, &self.horizontal_rotation_axis());
     }
 
     pub fn rotate_vertical(&mut self, angle: f32) {
-        self.dir = self.dir.rotate(angle, &self.up);
+        let up = self.up;
+        self.rotate(angle, &up);
+    }
+
+    pub fn orient(&mut self, rotation: &Quat<f32>) {
+        self.dir = (*rotation * self.dir).normalize();
     }
 
     pub fn horizontal_rotation_axis(&self) -> Vec3<f32> {
         self.up().cross(self.dir()).normalize()
     }
 }
-
-fn build_view_matrix(eye: &Vec3<f32>, center: &Vec3<f32>, up: &Vec3<f32>) -> Mat4<f32> {
-    let f = (*center - *eye).normalize();
-    let s = f.cross(&up).normalize();
-    let u = s.cross(&f);
-
-    Mat4::new(
-        Vec4::new(s.x, u.x, -f.x, 0.0),
-        Vec4::new(s.y, u.y, -f.y, 0.0),
-        Vec4::new(s.z, u.z, -f.z, 0.0),
-        Vec4::new(-s.dot(&eye), -u.dot(&eye), f.dot(&eye), 1.0),
-    )
-}
-
-fn perspective(fov_deg: f32, aspect_ratio: f32, near_plane: f32, far_plane: f32) -> Mat4<f32> {
-    let fov_rad = fov_deg.to_radians();
-    let f = 1.0 / (fov_rad / 2.0).tan();
-    let range_inv = 1.0 / (far_plane - near_plane);
-
-    Mat4::new(
-        Vec4::new(f / aspect_ratio, 0.0, 0.0, 0.0),
-        Vec4::new(0.0, f, 0.0, 0.0),
-        Vec4::new(0.0, 0.0, -(far_plane + near_plane) * range_inv, -1.0),
-        Vec4::new(0.0, 0.0, -2.0 * far_plane * near_plane * range_inv, 0.0),
-    )
-}
@@ -0,0 +1,96 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum HexError {
+    InvalidLength(usize),
+    InvalidDigit(char),
+}
+
+impl fmt::Display for HexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength(len) => {
+                write!(f, "Unexpected number of hex color digits, found {}", len)
+            }
+            Self::InvalidDigit(c) => write!(f, "Invalid hex digit {:?}", c),
+        }
+    }
+}
+
+/// Parses `#rgb` or `#rrggbb` into `N` channels in `[0, 1]`, or with `N = 4`
+/// also `#rgba` and `#rrggbbaa`, where alpha defaults to one. The `#` is
+/// optional.
+pub(super) fn parse<const N: usize>(s: &str) -> Result<[f32; N], HexError> {
+    let digits = s
+        .strip_prefix('#')
+        .unwrap_or(s)
+        .chars()
+        .map(|c| {
+            c.to_digit(16)
+                .map(|d| d as u8)
+                .ok_or(HexError::InvalidDigit(c))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let bytes: Vec<u8> = match digits.len() {
+        len if len == 3 || len == N => digits.iter().map(|d| d * 17).collect(),
+        len if len == 6 || len == 2 * N => digits.chunks(2).map(|p| p[0] * 16 + p[1]).collect(),
+        len => return Err(HexError::InvalidLength(len)),
+    };
+
+    let mut channels = [1.0; N];
+    for (c, b) in channels.iter_mut().zip(bytes) {
+        *c = f32::from(b) / 255.0;
+    }
+    Ok(channels)
+}
+
+/// Formats channels as lowercase `#rrggbb..`, clamping each to `[0, 1]`.
+pub(super) fn format(channels: &[f32]) -> String {
+    channels.iter().fold(String::from("#"), |mut s, c| {
+        s.push_str(&format!(
+            "{:02x}",
+            (c.clamp(0.0, 1.0) * 255.0).round() as u8
+        ));
+        s
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_parse_short() {
+        assert_eq!(parse::<3>("#f80"), Ok([1.0, 136.0 / 255.0, 0.0]));
+        assert_eq!(parse::<4>("f80"), Ok([1.0, 136.0 / 255.0, 0.0, 1.0]));
+        assert_eq!(
+            parse::<4>("#f808"),
+            Ok([1.0, 136.0 / 255.0, 0.0, 136.0 / 255.0])
+        );
+    }
+
+    #[test]
+    fn test_hex_parse_long() {
+        assert_eq!(parse::<3>("#ff8000"), Ok([1.0, 128.0 / 255.0, 0.0]));
+        assert_eq!(parse::<4>("#FF8000"), Ok([1.0, 128.0 / 255.0, 0.0, 1.0]));
+        assert_eq!(
+            parse::<4>("ff800040"),
+            Ok([1.0, 128.0 / 255.0, 0.0, 64.0 / 255.0])
+        );
+    }
+
+    #[test]
+    fn test_hex_parse_errors() {
+        assert_eq!(parse::<3>("#ff80"), Err(HexError::InvalidLength(4)));
+        assert_eq!(parse::<3>("#ff800040"), Err(HexError::InvalidLength(8)));
+        assert_eq!(parse::<4>(""), Err(HexError::InvalidLength(0)));
+        assert_eq!(parse::<3>("#ffg"), Err(HexError::InvalidDigit('g')));
+    }
+
+    #[test]
+    fn test_hex_format() {
+        assert_eq!(format(&[1.0, 0.5, 0.0]), "#ff8000");
+        assert_eq!(format(&[2.0, -1.0, 0.25, 1.0]), "#ff0040ff");
+    }
+}
@@ -0,0 +1,164 @@
+use super::rgb::ColorRGB;
+
+/// Hue in degrees, saturation and value in `[0, 1]`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Hsv {
+    pub h: f32,
+    pub s: f32,
+    pub v: f32,
+}
+
+/// Hue in degrees, saturation and lightness in `[0, 1]`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Hsl {
+    pub h: f32,
+    pub s: f32,
+    pub l: f32,
+}
+
+impl Hsv {
+    pub const fn new(h: f32, s: f32, v: f32) -> Self {
+        Self { h, s, v }
+    }
+}
+
+impl Hsl {
+    pub const fn new(h: f32, s: f32, l: f32) -> Self {
+        Self { h, s, l }
+    }
+}
+
+/// Hue, max and chroma (max - min) of an RGB triple.
+fn hue_max_chroma(color: ColorRGB) -> (f32, f32, f32) {
+    let [r, g, b] = color.to_array();
+    let max = r.max(g).max(b);
+    let chroma = max - r.min(g).min(b);
+    let h = if chroma == 0.0 {
+        0.0
+    } else if max == r {
+        ((g - b) / chroma).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / chroma + 2.0
+    } else {
+        (r - g) / chroma + 4.0
+    };
+    (h * 60.0, max, chroma)
+}
+
+/// RGB with the given hue and chroma, offset so the smallest channel is `min`.
+fn from_hue_chroma(h: f32, chroma: f32, min: f32) -> ColorRGB {
+    let h = h.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    ColorRGB::new(r + min, g + min, b + min)
+}
+
+impl From<ColorRGB> for Hsv {
+    fn from(color: ColorRGB) -> Self {
+        let (h, v, chroma) = hue_max_chroma(color);
+        let s = if v > 0.0 { chroma / v } else { 0.0 };
+        Self { h, s, v }
+    }
+}
+
+impl From<Hsv> for ColorRGB {
+    fn from(Hsv { h, s, v }: Hsv) -> Self {
+        let chroma = v * s;
+        from_hue_chroma(h, chroma, v - chroma)
+    }
+}
+
+impl From<ColorRGB> for Hsl {
+    fn from(color: ColorRGB) -> Self {
+        let (h, max, chroma) = hue_max_chroma(color);
+        let l = max - chroma / 2.0;
+        let s = if chroma == 0.0 {
+            0.0
+        } else {
+            chroma / (1.0 - (2.0 * l - 1.0).abs())
+        };
+        Self { h, s, l }
+    }
+}
+
+impl From<Hsl> for ColorRGB {
+    fn from(Hsl { h, s, l }: Hsl) -> Self {
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        from_hue_chroma(h, chroma, l - chroma / 2.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rmath::{assert_approx_eq, vec3::Vec3};
+
+    fn colors() -> [ColorRGB; 6] {
+        [
+            ColorRGB::new(1.0, 0.0, 0.0),
+            ColorRGB::new(0.2, 0.8, 0.4),
+            ColorRGB::new(0.1, 0.3, 0.9),
+            ColorRGB::new(0.9, 0.1, 0.7),
+            ColorRGB::new(0.5, 0.5, 0.5),
+            ColorRGB::new(0.0, 0.0, 0.0),
+        ]
+    }
+
+    #[test]
+    fn test_hsv_from_rgb() {
+        assert_eq!(
+            Hsv::from(ColorRGB::new(0.0, 1.0, 0.0)),
+            Hsv::new(120.0, 1.0, 1.0)
+        );
+        assert_eq!(
+            Hsv::from(ColorRGB::new(0.0, 0.0, 0.5)),
+            Hsv::new(240.0, 1.0, 0.5)
+        );
+        assert_eq!(
+            Hsv::from(ColorRGB::new(0.5, 0.5, 0.5)),
+            Hsv::new(0.0, 0.0, 0.5)
+        );
+    }
+
+    #[test]
+    fn test_hsl_from_rgb() {
+        assert_eq!(
+            Hsl::from(ColorRGB::new(1.0, 0.0, 0.0)),
+            Hsl::new(0.0, 1.0, 0.5)
+        );
+        assert_eq!(
+            Hsl::from(ColorRGB::new(1.0, 1.0, 1.0)),
+            Hsl::new(0.0, 0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn test_hsv_round_trip() {
+        for color in colors() {
+            let back = ColorRGB::from(Hsv::from(color));
+            assert_approx_eq!(Vec3::from(back), Vec3::from(color), abs = 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_hsl_round_trip() {
+        for color in colors() {
+            let back = ColorRGB::from(Hsl::from(color));
+            assert_approx_eq!(Vec3::from(back), Vec3::from(color), abs = 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_hsv_hue_wraps() {
+        let a = ColorRGB::from(Hsv::new(-60.0, 1.0, 1.0));
+        let b = ColorRGB::from(Hsv::new(300.0, 1.0, 1.0));
+        assert_approx_eq!(Vec3::from(a), Vec3::from(b));
+    }
+}
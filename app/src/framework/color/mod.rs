@@ -1 +1,22 @@
+pub mod hex;
+pub mod hsv;
 pub mod rgb;
+pub mod rgba;
+
+/// Exact sRGB decoding (IEC 61966-2-1) of a single channel.
+pub fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Exact sRGB encoding (IEC 61966-2-1) of a single channel.
+pub fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
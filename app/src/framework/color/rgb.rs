@@ -1,9 +1,16 @@
+use std::str::FromStr;
+
 use derive_more::{Add, AddAssign, Neg, Sub, SubAssign};
 
-use rmath::vec3::Vec3;
+use rmath::{interp::lerp, vec3::Vec3};
+
+use super::{
+    hex::{self, HexError},
+    linear_to_srgb, srgb_to_linear,
+};
 
 #[repr(C)]
-#[derive(Debug, Clone, Default, PartialEq, Add, AddAssign, Sub, SubAssign, Neg)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Add, AddAssign, Sub, SubAssign, Neg)]
 pub struct ColorRGB(Vec3<f32>);
 
 impl ColorRGB {
@@ -22,14 +29,67 @@ impl ColorRGB {
         self.0.z
     }
 
+    #[inline]
+    pub const fn to_array(&self) -> [f32; 3] {
+        self.0.data()
+    }
+
     pub fn blend(ratio: f32, Self(a): &Self, Self(b): &Self) -> Self {
-        let v = a.map_with(b, |a, b| blend_color(ratio, a, b));
-        Self(v)
+        Self(lerp(*a, *b, ratio))
+    }
+
+    /// Decodes sRGB encoded channels to linear light.
+    pub fn to_linear(&self) -> Self {
+        Self(self.0.map(srgb_to_linear))
+    }
+
+    /// Encodes linear light channels as sRGB.
+    pub fn to_srgb(&self) -> Self {
+        Self(self.0.map(linear_to_srgb))
+    }
+
+    /// Relative luminance (Rec. 709 weights) of a linear color.
+    pub fn luminance(&self) -> f32 {
+        0.2126 * self.r() + 0.7152 * self.g() + 0.0722 * self.b()
+    }
+
+    /// sRGB color of a black body at `kelvin`, clamped to 1000K..40000K
+    /// (Tanner Helland's fit).
+    pub fn from_kelvin(kelvin: f32) -> Self {
+        let t = kelvin.clamp(1000.0, 40000.0) / 100.0;
+        let r = if t <= 66.0 {
+            255.0
+        } else {
+            329.698_73 * (t - 60.0).powf(-0.133_204_76)
+        };
+        let g = if t <= 66.0 {
+            99.470_8 * t.ln() - 161.119_57
+        } else {
+            288.122_16 * (t - 60.0).powf(-0.075_514_846)
+        };
+        let b = if t >= 66.0 {
+            255.0
+        } else if t <= 19.0 {
+            0.0
+        } else {
+            138.517_73 * (t - 10.0).ln() - 305.044_8
+        };
+        Self(Vec3::new(r, g, b).map(|c| c.clamp(0.0, 255.0) / 255.0))
+    }
+
+    /// Lowercase `#rrggbb`.
+    pub fn to_hex(&self) -> String {
+        hex::format(&self.to_array())
     }
 }
 
-const fn blend_color(ratio: f32, a: f32, b: f32) -> f32 {
-    (1.0 - ratio) * a + ratio * b
+impl FromStr for ColorRGB {
+    type Err = HexError;
+
+    /// Parses `#rgb` or `#rrggbb`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        hex::parse(s).map(Self::from)
+    }
 }
 
 impl From<Vec3<f32>> for ColorRGB {
@@ -59,3 +119,58 @@ impl From<[f32; 3]> for ColorRGB {
         Self(a.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rmath::assert_approx_eq;
+
+    #[test]
+    fn test_rgb_hex() {
+        let color: ColorRGB = "#ff8000".parse().unwrap();
+        assert_eq!(color, ColorRGB::new(1.0, 128.0 / 255.0, 0.0));
+        assert_eq!(color.to_hex(), "#ff8000");
+        assert_eq!("#f80".parse::<ColorRGB>().unwrap().to_hex(), "#ff8800");
+        assert_eq!(
+            "#ff800040".parse::<ColorRGB>(),
+            Err(HexError::InvalidLength(8))
+        );
+    }
+
+    #[test]
+    fn test_rgb_srgb_round_trip() {
+        for c in [0.0, 0.002, 0.04, 0.2, 0.5, 0.8, 1.0] {
+            let color = ColorRGB::new(c, c * 0.5, 1.0 - c);
+            assert_approx_eq!(
+                Vec3::from(color.to_linear().to_srgb()),
+                Vec3::from(color),
+                abs = 1e-5
+            );
+        }
+        // Mid grey in sRGB is about a fifth of the light.
+        assert_approx_eq!(ColorRGB::new(0.5, 0.5, 0.5).to_linear().r(), 0.214_041_14);
+    }
+
+    #[test]
+    fn test_rgb_luminance() {
+        assert_approx_eq!(ColorRGB::new(1.0, 1.0, 1.0).luminance(), 1.0);
+        assert_eq!(ColorRGB::new(0.0, 1.0, 0.0).luminance(), 0.7152);
+    }
+
+    #[test]
+    fn test_rgb_kelvin() {
+        // Clamped to the ends of the fit.
+        assert_eq!(ColorRGB::from_kelvin(500.0), ColorRGB::from_kelvin(1000.0));
+        assert_eq!(ColorRGB::from_kelvin(1e6), ColorRGB::from_kelvin(40000.0));
+
+        let warm = ColorRGB::from_kelvin(1000.0);
+        assert_eq!((warm.r(), warm.b()), (1.0, 0.0));
+        let cold = ColorRGB::from_kelvin(40000.0);
+        assert_eq!(cold.b(), 1.0);
+        assert!(cold.r() < cold.b());
+
+        // Daylight is close to white.
+        let daylight = ColorRGB::from_kelvin(6600.0);
+        assert_approx_eq!(Vec3::from(daylight), Vec3::one(), abs = 0.02);
+    }
+}
@@ -0,0 +1,149 @@
+use std::str::FromStr;
+
+use derive_more::{Add, AddAssign, Neg, Sub, SubAssign};
+
+use rmath::{interp::lerp, vec4::Vec4};
+
+use super::{
+    hex::{self, HexError},
+    rgb::ColorRGB,
+};
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Add, AddAssign, Sub, SubAssign, Neg)]
+pub struct ColorRGBA(Vec4<f32>);
+
+impl ColorRGBA {
+    #[inline]
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self(Vec4 {
+            x: r,
+            y: g,
+            z: b,
+            w: a,
+        })
+    }
+
+    #[inline]
+    pub const fn from_rgb(rgb: ColorRGB, a: f32) -> Self {
+        Self::new(rgb.r(), rgb.g(), rgb.b(), a)
+    }
+
+    pub const fn r(&self) -> f32 {
+        self.0.x
+    }
+    pub const fn g(&self) -> f32 {
+        self.0.y
+    }
+    pub const fn b(&self) -> f32 {
+        self.0.z
+    }
+    pub const fn a(&self) -> f32 {
+        self.0.w
+    }
+
+    #[inline]
+    pub const fn rgb(&self) -> ColorRGB {
+        ColorRGB::new(self.r(), self.g(), self.b())
+    }
+
+    #[inline]
+    pub const fn to_array(&self) -> [f32; 4] {
+        self.0.data()
+    }
+
+    pub fn blend(ratio: f32, Self(a): &Self, Self(b): &Self) -> Self {
+        Self(lerp(*a, *b, ratio))
+    }
+
+    /// Decodes sRGB encoded color channels to linear light. Alpha is
+    /// already linear and passes through.
+    pub fn to_linear(&self) -> Self {
+        Self::from_rgb(self.rgb().to_linear(), self.a())
+    }
+
+    /// Encodes linear light color channels as sRGB. Alpha passes through.
+    pub fn to_srgb(&self) -> Self {
+        Self::from_rgb(self.rgb().to_srgb(), self.a())
+    }
+
+    /// Lowercase `#rrggbbaa`.
+    pub fn to_hex(&self) -> String {
+        hex::format(&self.to_array())
+    }
+}
+
+impl FromStr for ColorRGBA {
+    type Err = HexError;
+
+    /// Parses `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        hex::parse(s).map(Self::from)
+    }
+}
+
+impl From<ColorRGB> for ColorRGBA {
+    #[inline]
+    fn from(rgb: ColorRGB) -> Self {
+        Self::from_rgb(rgb, 1.0)
+    }
+}
+
+impl From<Vec4<f32>> for ColorRGBA {
+    #[inline]
+    fn from(value: Vec4<f32>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<ColorRGBA> for Vec4<f32> {
+    #[inline]
+    fn from(ColorRGBA(v): ColorRGBA) -> Self {
+        v
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for ColorRGBA {
+    #[inline]
+    fn from(t: (f32, f32, f32, f32)) -> Self {
+        Self(t.into())
+    }
+}
+
+impl From<[f32; 4]> for ColorRGBA {
+    #[inline]
+    fn from(a: [f32; 4]) -> Self {
+        Self(a.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rmath::assert_approx_eq;
+
+    #[test]
+    fn test_rgba_hex() {
+        let color: ColorRGBA = "#ff800040".parse().unwrap();
+        assert_eq!(color, ColorRGBA::new(1.0, 128.0 / 255.0, 0.0, 64.0 / 255.0));
+        assert_eq!(color.to_hex(), "#ff800040");
+        assert_eq!("#ff8000".parse::<ColorRGBA>().unwrap().a(), 1.0);
+        assert_eq!("#f808".parse::<ColorRGBA>().unwrap().to_hex(), "#ff880088");
+        assert_eq!(
+            "#ff80004".parse::<ColorRGBA>(),
+            Err(HexError::InvalidLength(7))
+        );
+    }
+
+    #[test]
+    fn test_rgba_srgb_keeps_alpha() {
+        let color = ColorRGBA::new(0.5, 0.25, 1.0, 0.3);
+        assert_eq!(color.to_linear().a(), 0.3);
+        assert_eq!(color.to_linear().rgb(), color.rgb().to_linear());
+        assert_approx_eq!(
+            Vec4::from(color.to_linear().to_srgb()),
+            Vec4::from(color),
+            abs = 1e-5
+        );
+    }
+}
@@ -2,7 +2,7 @@ use std::rc::Rc;
 
 use crate::{
     UniformWrapper,
-    framework::{camera::Camera, material::Material, mesh::Mesh},
+    framework::{material::Material, mesh::Mesh},
 };
 
 use gl_sys::{program::Program, uniform::UniformLocationError};
@@ -15,25 +15,16 @@ pub struct Drawelement {
 }
 
 impl Drawelement {
-    pub fn bind(
-        &self,
-        program: &Program,
-        camera: &Camera,
-        aspect: f32,
-    ) -> Result<(), UniformLocationError> {
+    /// Camera data comes from the `Camera` uniform block.
+    pub fn bind(&self, program: &Program) -> Result<(), UniformLocationError> {
         program.bind();
         self.material.bind(program)?;
-        program.uniform("view", UniformWrapper(&camera.view()))?;
-        program.uniform("projection", UniformWrapper(&camera.projection(aspect)))?;
         Ok(())
     }
 
     pub fn draw(&self, program: &Program, model: &Mat4<f32>) -> Result<(), UniformLocationError> {
         program.uniform("model", UniformWrapper(model))?;
-        program.uniform(
-            "model_normal",
-            UniformWrapper(&model.invert().unwrap_or(Mat4::identity()).transpose()),
-        )?;
+        program.uniform("model_normal", UniformWrapper(&model.normal_matrix()))?;
         self.mesh.bind();
         self.mesh.draw();
         self.mesh.unbind();
@@ -70,7 +70,9 @@ fn bind_texture_option(
     texture_type: MaterialTextureType,
     unit: u32,
 ) -> Result<(), UniformLocationError> {
-    if let Some(texture) = texture {
+    // Materials carry every texture they have; the shader may not sample
+    // all of them.
+    if let Some(texture) = texture.filter(|_| program.has_uniform(texture_type.as_ref())) {
         texture.bind(unit)?;
         program.uniform_opt(texture_type, texture, unit)?;
     }
@@ -0,0 +1,234 @@
+use rmath::{bounds::Aabb, bvh::Bvh, vec2::Vec2, vec3::Vec3};
+
+use super::{FailureReason, Mesh, MeshLoadError};
+
+/// CPU-side vertex streams and triangle indices of a [`Mesh`]. Building one
+/// needs no GL context; optional streams are empty when absent.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MeshData {
+    pub positions: Vec<Vec3<f32>>,
+    pub normals: Vec<Vec3<f32>>,
+    pub texcoords: Vec<Vec2<f32>>,
+    pub tangents: Vec<Vec3<f32>>,
+    pub indices: Vec<u32>,
+}
+
+impl MeshData {
+    pub fn from_ai_mesh(mesh: &assimp::Mesh<'_>) -> Result<Self, MeshLoadError> {
+        let stream_len = |present: bool| {
+            if present {
+                mesh.num_vertices() as usize
+            } else {
+                0
+            }
+        };
+        let mut data = Self {
+            positions: Vec::with_capacity(mesh.num_vertices() as _),
+            normals: Vec::with_capacity(stream_len(mesh.has_normals())),
+            texcoords: Vec::with_capacity(stream_len(mesh.has_texture_coords(0))),
+            tangents: Vec::with_capacity(stream_len(mesh.has_tangents_and_bitangents())),
+            indices: Vec::new(),
+        };
+
+        for i in 0..mesh.num_vertices() {
+            let a_pos = match mesh.get_vertex(i) {
+                Some(vec) => vec,
+                None => {
+                    return Err(MeshLoadError::MeshConversionFailed(FailureReason::Vertex(
+                        i,
+                    )));
+                }
+            };
+            data.positions.push(a_pos.into());
+
+            if mesh.has_normals() {
+                let a_norm = match mesh.get_normal(i) {
+                    Some(vec) => vec,
+                    None => {
+                        return Err(MeshLoadError::MeshConversionFailed(FailureReason::Normal(
+                            i,
+                        )));
+                    }
+                };
+                data.normals.push(a_norm.into());
+            }
+            if mesh.has_texture_coords(0) {
+                let a_tc = match mesh.get_texture_coord(0, i) {
+                    Some(vec) => vec,
+                    None => {
+                        return Err(MeshLoadError::MeshConversionFailed(
+                            FailureReason::TextureCoordinate(i),
+                        ));
+                    }
+                };
+                data.texcoords.push(Vec2::new(a_tc.x, a_tc.y))
+            }
+            if mesh.has_tangents_and_bitangents() {
+                let a_tan = match mesh.get_tangent(i) {
+                    Some(vec) => vec,
+                    None => {
+                        return Err(MeshLoadError::MeshConversionFailed(
+                            FailureReason::TangentAndBitangent(i),
+                        ));
+                    }
+                };
+                data.tangents.push(a_tan.into());
+            }
+        }
+
+        data.indices = mesh
+            .face_iter()
+            .filter(|f| f.num_indices == 3)
+            .flat_map(|f| [f[0], f[1], f[2]])
+            .collect();
+
+        Ok(data)
+    }
+
+    #[inline]
+    pub fn num_vertices(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn bounds(&self) -> Aabb<f32> {
+        Aabb::from_points(self.positions.iter().copied())
+    }
+
+    /// Builds a [`Bvh`] over the triangles; call [`MeshData::validate`] first.
+    pub fn bvh(&self) -> Bvh<f32> {
+        Bvh::new(&self.positions, &self.indices)
+    }
+
+    /// Checks that every present stream has one entry per position and that
+    /// the indices form whole triangles of existing vertices.
+    pub fn validate(&self) -> Result<(), MeshLoadError> {
+        let expected = self.num_vertices();
+        if let Some(actual) = [
+            self.normals.len(),
+            self.texcoords.len(),
+            self.tangents.len(),
+        ]
+        .into_iter()
+        .find(|&len| len != 0 && len != expected)
+        {
+            return Err(MeshLoadError::UnequalNumberOfVertices {
+                expected: expected as _,
+                actual: actual as _,
+            });
+        }
+
+        if !self.indices.len().is_multiple_of(3) {
+            return Err(MeshLoadError::PartialTriangle(self.indices.len() as _));
+        }
+        match self.indices.iter().find(|&&i| i as usize >= expected) {
+            Some(&index) => Err(MeshLoadError::IndexOutOfRange {
+                index,
+                num_vertices: expected as _,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Uploads the streams into a new [`Mesh`], which does not keep them.
+    pub fn to_mesh(&self) -> Result<Mesh, MeshLoadError> {
+        Mesh::from_data(self)
+    }
+
+    /// Uploads the streams into a new [`Mesh`] that keeps them in [`Mesh::data`].
+    pub fn into_mesh(self) -> Result<Mesh, MeshLoadError> {
+        let mut mesh = Mesh::from_data(&self)?;
+        mesh.data = Some(self);
+        Ok(mesh)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle() -> MeshData {
+        MeshData {
+            positions: vec![
+                Vec3::zero(),
+                Vec3::new(1.0, 0.0, 0.0),
+                Vec3::new(0.0, 1.0, 0.0),
+            ],
+            indices: vec![0, 1, 2],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_mesh_data_validate() {
+        assert!(triangle().validate().is_ok());
+        assert!(MeshData::default().validate().is_ok());
+
+        let full = MeshData {
+            normals: vec![Vec3::new(0.0, 0.0, 1.0); 3],
+            texcoords: vec![Vec2::new(0.0, 0.0); 3],
+            tangents: vec![Vec3::new(1.0, 0.0, 0.0); 3],
+            ..triangle()
+        };
+        assert!(full.validate().is_ok());
+    }
+
+    #[test]
+    fn test_mesh_data_validate_streams() {
+        let data = MeshData {
+            texcoords: vec![Vec2::new(0.0, 0.0); 2],
+            ..triangle()
+        };
+        assert!(matches!(
+            data.validate(),
+            Err(MeshLoadError::UnequalNumberOfVertices {
+                expected: 3,
+                actual: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn test_mesh_data_validate_indices() {
+        let data = MeshData {
+            indices: vec![0, 1, 2, 2, 1, 3],
+            ..triangle()
+        };
+        assert!(matches!(
+            data.validate(),
+            Err(MeshLoadError::IndexOutOfRange {
+                index: 3,
+                num_vertices: 3
+            })
+        ));
+
+        let data = MeshData {
+            indices: vec![0, 1, 2, 0],
+            ..triangle()
+        };
+        assert!(matches!(
+            data.validate(),
+            Err(MeshLoadError::PartialTriangle(4))
+        ));
+
+        let data = MeshData {
+            positions: Vec::new(),
+            ..triangle()
+        };
+        assert!(matches!(
+            data.validate(),
+            Err(MeshLoadError::IndexOutOfRange { index: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_mesh_data_bounds_and_bvh() {
+        let data = triangle();
+        assert_eq!(
+            data.bounds(),
+            Aabb::new(Vec3::zero(), Vec3::new(1.0, 1.0, 0.0))
+        );
+        let bvh = data.bvh();
+        assert_eq!(bvh.len(), 1);
+        assert_eq!(bvh.bounds(), data.bounds());
+    }
+}
@@ -1,25 +1,50 @@
 use std::{path::Path, rc::Rc};
 
+use rmath::{bounds::Aabb, bvh::BvhHit, mat4::Mat4, ray::Ray};
+
 use crate::{
     assimp::AMaterial,
-    framework::{drawelement::Drawelement, mesh::Mesh},
+    framework::{drawelement::Drawelement, mesh::data::MeshData, scene::SceneGraph},
 };
 
 use super::{Material, MeshLoadError, NormalizeOptions};
 
 #[derive(Debug)]
-#[repr(transparent)]
-pub struct SceneImport(Box<[Drawelement]>);
+pub struct ImportOptions {
+    pub normalize: NormalizeOptions,
+    /// Keep each mesh's vertex streams and indices in [`super::Mesh::data`].
+    pub retain_mesh_data: bool,
+    /// Build a BVH per mesh so [`SceneImport::closest_hit`] and
+    /// [`SceneImport::any_hit`] can hit it.
+    pub build_bvh: bool,
+}
+
+impl From<NormalizeOptions> for ImportOptions {
+    fn from(normalize: NormalizeOptions) -> Self {
+        Self {
+            normalize,
+            retain_mesh_data: false,
+            build_bvh: false,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SceneImport {
+    elements: Box<[Drawelement]>,
+    graph: SceneGraph,
+}
 
 impl SceneImport {
-    pub fn import<P>(path: P, normalize: NormalizeOptions) -> Result<Self, MeshLoadError>
+    pub fn import<P, O>(path: P, options: O) -> Result<Self, MeshLoadError>
     where
         P: AsRef<Path>,
+        O: Into<ImportOptions>,
     {
         let path = path.as_ref();
+        let options = options.into();
 
-        let mut scene = load_ai_scene(path)?;
-        normalize.normalize_scene(&mut scene);
+        let scene = load_ai_scene(path)?;
 
         let base_path = match path.parent() {
             Some(parent) => parent,
@@ -39,44 +64,121 @@ impl SceneImport {
             let index = aimesh.material_index as usize;
             let material = materials[index].clone();
 
-            let mesh = Mesh::from_ai_mesh(&aimesh)?;
+            let data = MeshData::from_ai_mesh(&aimesh)?;
+            let mut mesh = data.to_mesh()?;
+            if options.build_bvh {
+                mesh.bvh = Some(data.bvh());
+            }
+            if options.retain_mesh_data {
+                mesh.data = Some(data);
+            }
 
             drawelements.push(Drawelement { material, mesh });
         }
 
-        Ok(Self(drawelements.into_boxed_slice()))
+        let mut import = Self {
+            elements: drawelements.into_boxed_slice(),
+            graph: SceneGraph::from_ai_node(&scene.root_node()),
+        };
+
+        // Normalize through the root transform so node transforms keep
+        // their relative placement.
+        if let Some(normalize) = options.normalize.matrix(&import.bounds()) {
+            let root = import.graph.local_mut(0);
+            *root = normalize * *root;
+            import.graph.update(&Mat4::identity());
+        }
+
+        Ok(import)
     }
 
     #[inline]
     pub const fn elements(&self) -> &[Drawelement] {
-        &self.0
+        &self.elements
+    }
+
+    #[inline]
+    pub fn graph(&self) -> &SceneGraph {
+        &self.graph
+    }
+
+    #[inline]
+    pub fn graph_mut(&mut self) -> &mut SceneGraph {
+        &mut self.graph
+    }
+
+    /// Propagates world matrices, placing the scene root under `model`.
+    pub fn update(&mut self, model: &Mat4<f32>) {
+        self.graph.update(model);
+    }
+
+    /// Every drawelement instance with its world matrix as of the last
+    /// [`SceneImport::update`].
+    pub fn instances(&self) -> impl Iterator<Item = (&Drawelement, &Mat4<f32>)> {
+        self.graph
+            .instances()
+            .map(|(mesh, world)| (&self.elements[mesh], world))
+    }
+
+    /// World space bounds of all instances.
+    pub fn bounds(&self) -> Aabb<f32> {
+        self.instances()
+            .map(|(e, world)| e.mesh.bounds.transform(world))
+            .fold(Aabb::empty(), |acc, aabb| acc.union(&aabb))
+    }
+
+    /// Closest hit of a world space ray over all instances, with the index
+    /// of the drawelement that was hit. Hit distances are in world space.
+    /// Meshes imported without [`ImportOptions::build_bvh`] are never hit.
+    pub fn closest_hit(&self, ray: &Ray<f32>) -> Option<(usize, BvhHit<f32>)> {
+        self.graph
+            .instances()
+            .filter_map(|(mesh, world)| {
+                let bvh = self.elements[mesh].mesh.bvh.as_ref()?;
+                let hit = bvh.closest_hit(&ray.transform(&world.invert()?))?;
+                Some((mesh, hit))
+            })
+            .min_by(|(_, a), (_, b)| a.hit.t.total_cmp(&b.hit.t))
+    }
+
+    /// Whether a world space ray hits any instance within `t_max`.
+    pub fn any_hit(&self, ray: &Ray<f32>, t_max: f32) -> bool {
+        self.graph.instances().any(|(mesh, world)| {
+            let bvh = self.elements[mesh].mesh.bvh.as_ref();
+            bvh.zip(world.invert())
+                .is_some_and(|(bvh, inv)| bvh.any_hit(&ray.transform(&inv), t_max))
+        })
     }
 }
 
 impl AsRef<[Drawelement]> for SceneImport {
     #[inline]
     fn as_ref(&self) -> &[Drawelement] {
-        &self.0
+        &self.elements
     }
 }
 
 impl From<Vec<Drawelement>> for SceneImport {
     #[inline]
     fn from(value: Vec<Drawelement>) -> Self {
-        Self(value.into_boxed_slice())
+        value.into_boxed_slice().into()
     }
 }
 
+/// Draws every element once, untransformed.
 impl From<Box<[Drawelement]>> for SceneImport {
     #[inline]
-    fn from(value: Box<[Drawelement]>) -> Self {
-        Self(value)
+    fn from(elements: Box<[Drawelement]>) -> Self {
+        Self {
+            graph: SceneGraph::flat(elements.len()),
+            elements,
+        }
     }
 }
 
 impl From<SceneImport> for Box<[Drawelement]> {
     #[inline]
-    fn from(SceneImport(elements): SceneImport) -> Self {
+    fn from(SceneImport { elements, .. }: SceneImport) -> Self {
         elements
     }
 }
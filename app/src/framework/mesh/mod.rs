@@ -2,20 +2,21 @@ use std::{fmt, path::PathBuf};
 
 use crate::framework::{
     material::{Material, MaterialConversionError},
-    mesh::normalize::NormalizeOptions,
+    mesh::{data::MeshData, normalize::NormalizeOptions},
 };
 
-use rmath::{vec2::Vec2, vec3::Vec3};
+use rmath::{bounds::Aabb, bvh::Bvh};
 
 use gl_sys::{
-    vao::VertexArrayObject,
-    vbo::{Location, VBOError, VertexBufferObject},
+    vao::{VertexArrayObject, VertexArrayRef},
+    vbo::{Location, Pod, VBOError, VertexBufferObject, VertexBufferRef},
 };
 
+pub mod data;
 pub mod load;
 pub mod normalize;
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default)]
 pub struct VboData {
     pub vbo: VertexBufferObject,
     pub buffer_type: gl_sys::bindings::GLenum,
@@ -30,6 +31,11 @@ pub struct Mesh {
     pub num_indices: u32,
     pub vbos: [Option<VboData>; 4],
     pub primitive_type: gl_sys::bindings::GLenum,
+    pub bounds: Aabb<f32>,
+    /// Built when requested through [`load::ImportOptions`], for ray queries.
+    pub bvh: Option<Bvh<f32>>,
+    /// Source geometry, kept when requested through [`load::ImportOptions`].
+    pub data: Option<MeshData>,
 }
 
 impl Mesh {
@@ -41,6 +47,9 @@ impl Mesh {
             num_indices: 0,
             vbos: Default::default(),
             primitive_type: gl_sys::bindings::GL_TRIANGLES,
+            bounds: Aabb::empty(),
+            bvh: None,
+            data: None,
         }
     }
 
@@ -48,7 +57,7 @@ impl Mesh {
         VertexArrayObject::bind_vertex_array(&self.vao);
     }
     pub fn unbind(&self) {
-        VertexArrayObject::bind_vertex_array(&VertexArrayObject::zero());
+        VertexArrayObject::bind_vertex_array(VertexArrayRef::zero());
     }
     pub fn draw(&self) {
         gl_sys::draw_elements(
@@ -59,104 +68,41 @@ impl Mesh {
     }
 
     pub fn from_ai_mesh(mesh: &assimp::Mesh<'_>) -> Result<Self, MeshLoadError> {
-        let mut positions: Vec<Vec3<f32>> = Vec::with_capacity(mesh.num_vertices() as _);
-        let mut normals: Vec<Vec3<f32>> = Vec::with_capacity(if mesh.has_normals() {
-            mesh.num_vertices() as _
-        } else {
-            0
-        });
-        let mut texcoords: Vec<Vec2<f32>> = Vec::with_capacity(if mesh.has_texture_coords(0) {
-            mesh.num_vertices() as _
-        } else {
-            0
-        });
-        let mut tangents: Vec<Vec3<f32>> =
-            Vec::with_capacity(if mesh.has_tangents_and_bitangents() {
-                mesh.num_vertices() as _
-            } else {
-                0
-            });
-
-        for i in 0..mesh.num_vertices() {
-            let a_pos = match mesh.get_vertex(i) {
-                Some(vec) => vec,
-                None => {
-                    return Err(MeshLoadError::MeshConversionFailed(FailureReason::Vertex(
-                        i,
-                    )));
-                }
-            };
-            positions.push(a_pos.into());
-
-            if mesh.has_normals() {
-                let a_norm = match mesh.get_normal(i) {
-                    Some(vec) => vec,
-                    None => {
-                        return Err(MeshLoadError::MeshConversionFailed(FailureReason::Normal(
-                            i,
-                        )));
-                    }
-                };
-                normals.push(a_norm.into());
-            }
-            if mesh.has_texture_coords(0) {
-                let a_tc = match mesh.get_texture_coord(0, i) {
-                    Some(vec) => vec,
-                    None => {
-                        return Err(MeshLoadError::MeshConversionFailed(
-                            FailureReason::TextureCoordinate(i),
-                        ));
-                    }
-                };
-                texcoords.push(Vec2::new(a_tc.x, a_tc.y))
-            }
-            if mesh.has_tangents_and_bitangents() {
-                let a_tan = match mesh.get_tangent(i) {
-                    Some(vec) => vec,
-                    None => {
-                        return Err(MeshLoadError::MeshConversionFailed(
-                            FailureReason::TangentAndBitangent(i),
-                        ));
-                    }
-                };
-                tangents.push(a_tan.into());
-            }
-        }
+        MeshData::from_ai_mesh(mesh)?.to_mesh()
+    }
 
-        let indices: Vec<u32> = mesh
-            .face_iter()
-            .filter(|f| f.num_indices == 3)
-            .flat_map(|f| [f[0], f[1], f[2]])
-            .collect();
+    pub fn from_data(data: &MeshData) -> Result<Self, MeshLoadError> {
+        data.validate()?;
 
         let mut m = Self::with_defaults();
-        m.add_vbo(0, 3, &positions)?;
-        if !normals.is_empty() {
-            m.add_vbo(1, 3, &normals)?;
+        m.bounds = data.bounds();
+        m.add_vbo(0, 3, &data.positions)?;
+        if !data.normals.is_empty() {
+            m.add_vbo(1, 3, &data.normals)?;
         }
-        if !texcoords.is_empty() {
-            m.add_vbo(2, 2, &texcoords)?;
+        if !data.texcoords.is_empty() {
+            m.add_vbo(2, 2, &data.texcoords)?;
         }
-        if !tangents.is_empty() {
-            m.add_vbo(3, 3, &tangents)?;
+        if !data.tangents.is_empty() {
+            m.add_vbo(3, 3, &data.tangents)?;
         }
-        m.num_indices = indices.len() as _;
+        m.num_indices = data.indices.len() as _;
         VertexArrayObject::bind_vertex_array(&m.vao);
         VertexBufferObject::bind_buffer(gl_sys::bindings::GL_ELEMENT_ARRAY_BUFFER, &m.ibo);
         VertexBufferObject::buffer_data(
             gl_sys::bindings::GL_ELEMENT_ARRAY_BUFFER,
-            &indices,
+            &data.indices,
             gl_sys::bindings::GL_STATIC_DRAW,
         )?;
-        VertexArrayObject::bind_vertex_array(&VertexArrayObject::zero());
+        VertexArrayObject::bind_vertex_array(VertexArrayRef::zero());
         VertexBufferObject::bind_buffer(
             gl_sys::bindings::GL_ELEMENT_ARRAY_BUFFER,
-            &VertexBufferObject::zero(),
+            VertexBufferRef::zero(),
         );
         Ok(m)
     }
 
-    fn add_vbo<T>(
+    fn add_vbo<T: Pod>(
         &mut self,
         index: usize,
         dimensions: u32,
@@ -164,7 +110,7 @@ impl Mesh {
     ) -> Result<(), MeshLoadError> {
         if self.num_vertices != 0 && self.num_vertices != data.len() as _ {
             return Err(MeshLoadError::UnequalNumberOfVertices {
-                expected: self.num_indices,
+                expected: self.num_vertices,
                 actual: data.len() as _,
             });
         }
@@ -175,7 +121,7 @@ impl Mesh {
         VertexArrayObject::bind_vertex_array(&self.vao);
         let vbo = VertexBufferObject::gen_buffers();
         VertexBufferObject::bind_buffer(buffer_type, &vbo);
-        VertexBufferObject::buffer_data(buffer_type, &data, gl_sys::bindings::GL_STATIC_DRAW)?;
+        VertexBufferObject::buffer_data(buffer_type, data, gl_sys::bindings::GL_STATIC_DRAW)?;
         let loc = Location(index as _);
 
         VertexBufferObject::enable_vertex_attrib_array(&loc);
@@ -187,8 +133,8 @@ impl Mesh {
             (dimensions * 4) as _,
             None,
         )?;
-        VertexArrayObject::bind_vertex_array(&VertexArrayObject::zero());
-        VertexBufferObject::bind_buffer(buffer_type, &VertexBufferObject::zero());
+        VertexArrayObject::bind_vertex_array(VertexArrayRef::zero());
+        VertexBufferObject::bind_buffer(buffer_type, VertexBufferRef::zero());
 
         self.vbos[index] = Some(VboData {
             vbo,
@@ -228,6 +174,8 @@ pub enum MeshLoadError {
     MaterialConversionFailed(MaterialConversionError),
     MeshConversionFailed(FailureReason),
     UnequalNumberOfVertices { expected: u32, actual: u32 },
+    IndexOutOfRange { index: u32, num_vertices: u32 },
+    PartialTriangle(u32),
     VboError(VBOError),
 }
 
@@ -245,6 +193,16 @@ impl fmt::Display for MeshLoadError {
                 expected: e,
                 actual: a,
             } => write!(f, "Expected {e} but got {a} vertices"),
+            Self::IndexOutOfRange {
+                index,
+                num_vertices,
+            } => write!(
+                f,
+                "Index {index} is out of range for {num_vertices} vertices"
+            ),
+            Self::PartialTriangle(n) => {
+                write!(f, "{n} indices do not form whole triangles")
+            }
         }
     }
 }
@@ -1,6 +1,8 @@
+pub mod blocks;
 pub mod camera;
 pub mod color;
 pub mod drawelement;
 pub mod material;
 pub mod mesh;
+pub mod scene;
 pub mod texture;
@@ -0,0 +1,206 @@
+use rmath::mat4::Mat4;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    pub name: String,
+    /// Transform relative to the parent node.
+    pub local: Mat4<f32>,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    /// Indices into the scene's drawelements.
+    pub meshes: Vec<usize>,
+}
+
+impl Node {
+    #[inline]
+    pub fn parent(&self) -> Option<usize> {
+        self.parent
+    }
+
+    #[inline]
+    pub fn children(&self) -> &[usize] {
+        &self.children
+    }
+}
+
+/// Node hierarchy stored flat, with every parent ahead of its children so
+/// world matrices can be propagated in a single pass.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SceneGraph {
+    nodes: Vec<Node>,
+    world: Vec<Mat4<f32>>,
+}
+
+impl SceneGraph {
+    /// A single root node drawing `num_meshes` meshes untransformed.
+    pub fn flat(num_meshes: usize) -> Self {
+        Self::from_nodes(vec![Node {
+            name: String::from("root"),
+            local: Mat4::identity(),
+            parent: None,
+            children: Vec::new(),
+            meshes: (0..num_meshes).collect(),
+        }])
+    }
+
+    pub fn from_ai_node(root: &assimp::Node<'_>) -> Self {
+        fn visit(node: &assimp::Node<'_>, parent: Option<usize>, nodes: &mut Vec<Node>) {
+            let index = nodes.len();
+            nodes.push(Node {
+                name: node.name().to_owned(),
+                local: node.transformation().into(),
+                parent,
+                children: Vec::with_capacity(node.num_children() as _),
+                meshes: node.meshes().iter().map(|&m| m as usize).collect(),
+            });
+            if let Some(parent) = parent {
+                nodes[parent].children.push(index);
+            }
+            for child in node.child_iter() {
+                visit(&child, Some(index), nodes);
+            }
+        }
+
+        let mut nodes = Vec::new();
+        visit(root, None, &mut nodes);
+        Self::from_nodes(nodes)
+    }
+
+    fn from_nodes(nodes: Vec<Node>) -> Self {
+        let mut graph = Self {
+            world: vec![Mat4::identity(); nodes.len()],
+            nodes,
+        };
+        graph.update(&Mat4::identity());
+        graph
+    }
+
+    #[inline]
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    /// The local transform of a node; changes take effect on the next
+    /// [`SceneGraph::update`]. The hierarchy itself cannot be edited.
+    #[inline]
+    pub fn local_mut(&mut self, index: usize) -> &mut Mat4<f32> {
+        &mut self.nodes[index].local
+    }
+
+    pub fn find(&self, name: &str) -> Option<usize> {
+        self.nodes.iter().position(|n| n.name == name)
+    }
+
+    /// Recomputes every world matrix, placing the root under `root`.
+    pub fn update(&mut self, root: &Mat4<f32>) {
+        for (i, node) in self.nodes.iter().enumerate() {
+            let parent = node.parent.map_or(*root, |p| self.world[p]);
+            self.world[i] = parent * node.local;
+        }
+    }
+
+    #[inline]
+    pub fn world(&self, node: usize) -> &Mat4<f32> {
+        &self.world[node]
+    }
+
+    /// Every mesh reference with the world matrix of its node; a mesh used
+    /// by several nodes is yielded once per node.
+    pub fn instances(&self) -> impl Iterator<Item = (usize, &Mat4<f32>)> {
+        self.nodes
+            .iter()
+            .zip(&self.world)
+            .flat_map(|(node, world)| node.meshes.iter().map(move |&m| (m, world)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rmath::{assert_approx_eq, vec3::Vec3};
+
+    fn node(name: &str, local: Mat4<f32>, parent: Option<usize>, meshes: Vec<usize>) -> Node {
+        Node {
+            name: String::from(name),
+            local,
+            parent,
+            children: Vec::new(),
+            meshes,
+        }
+    }
+
+    /// root -> arm -> hand, with mesh 0 on the root and the hand and mesh 1
+    /// on the arm.
+    fn chain() -> SceneGraph {
+        let mut nodes = vec![
+            node(
+                "root",
+                Mat4::translation(&Vec3::new(1.0, 0.0, 0.0)),
+                None,
+                vec![0],
+            ),
+            node(
+                "arm",
+                Mat4::translation(&Vec3::new(0.0, 2.0, 0.0)),
+                Some(0),
+                vec![1],
+            ),
+            node("hand", Mat4::scale(&Vec3::scalar(2.0)), Some(1), vec![0]),
+        ];
+        nodes[0].children.push(1);
+        nodes[1].children.push(2);
+        SceneGraph::from_nodes(nodes)
+    }
+
+    fn origin(graph: &SceneGraph, node: usize) -> Vec3<f32> {
+        graph.world(node).transform_point(&Vec3::zero())
+    }
+
+    #[test]
+    fn test_scene_flat() {
+        let graph = SceneGraph::flat(3);
+        assert_eq!(graph.nodes().len(), 1);
+        let instances: Vec<_> = graph.instances().map(|(m, w)| (m, *w)).collect();
+        assert_eq!(instances, [0, 1, 2].map(|m| (m, Mat4::identity())).to_vec());
+    }
+
+    #[test]
+    fn test_scene_propagation() {
+        let mut graph = chain();
+        assert_eq!(graph.find("hand"), Some(2));
+        assert_eq!(graph.nodes()[2].parent(), Some(1));
+        assert_eq!(graph.nodes()[0].children(), [1]);
+
+        assert_approx_eq!(origin(&graph, 2), Vec3::new(1.0, 2.0, 0.0));
+        assert_approx_eq!(
+            graph.world(2).transform_vector(&Vec3::new(1.0, 0.0, 0.0)),
+            Vec3::new(2.0, 0.0, 0.0)
+        );
+
+        graph.update(&Mat4::translation(&Vec3::new(0.0, 0.0, -5.0)));
+        assert_approx_eq!(origin(&graph, 2), Vec3::new(1.0, 2.0, -5.0));
+
+        // A changed local transform reaches the descendants on update.
+        *graph.local_mut(1) = Mat4::translation(&Vec3::new(0.0, 3.0, 0.0));
+        assert_approx_eq!(origin(&graph, 2), Vec3::new(1.0, 2.0, -5.0));
+        graph.update(&Mat4::identity());
+        assert_approx_eq!(origin(&graph, 1), Vec3::new(1.0, 3.0, 0.0));
+        assert_approx_eq!(origin(&graph, 2), Vec3::new(1.0, 3.0, 0.0));
+    }
+
+    #[test]
+    fn test_scene_instances() {
+        let graph = chain();
+        let instances: Vec<_> = graph
+            .instances()
+            .map(|(mesh, world)| (mesh, world.transform_point(&Vec3::zero())))
+            .collect();
+        assert_eq!(instances.len(), 3);
+
+        let mesh0: Vec<_> = instances.iter().filter(|(m, _)| *m == 0).collect();
+        assert_eq!(mesh0.len(), 2);
+        assert_approx_eq!(mesh0[0].1, Vec3::new(1.0, 0.0, 0.0));
+        assert_approx_eq!(mesh0[1].1, Vec3::new(1.0, 2.0, 0.0));
+        assert_eq!(instances[1].0, 1);
+    }
+}
@@ -82,6 +82,20 @@ impl Texture2D {
         Ok(s)
     }
 
+    #[inline]
+    pub const fn id(&self) -> gl_sys::bindings::GLuint {
+        self.id
+    }
+
+    /// Attaches the base level of this texture to `framebuffer`.
+    pub fn attach_to(
+        &self,
+        framebuffer: &gl_sys::framebuffer::Framebuffer,
+        attachment: gl_sys::framebuffer::Attachment,
+    ) {
+        framebuffer.attach_texture_2d(attachment, self.id, 0);
+    }
+
     pub fn bind(&self, unit: u32) -> Result<(), UniformLocationError> {
         upload::active_texture(unit);
         gl_sys::get_error()?;
@@ -206,6 +220,10 @@ impl Drop for Texture2D {
 impl Uniform for &Texture2D {
     type Options = u32; // texture unit
 
+    fn accepts(gl_type: gl_sys::bindings::GLenum) -> bool {
+        gl_sys::uniform::glsl_type::is_sampler(gl_type)
+    }
+
     fn set(&self, options: Option<Self::Options>, location: &UniformLocation) {
         let unit = options.unwrap_or(0);
         let UniformLocation(location) = *location;
@@ -4,7 +4,7 @@ use gl_sys::{
     self,
     uniform::{UniformLocation, uniform_trait::Uniform},
 };
-use rmath::{mat4::Mat4, vec3::Vec3, vec4::Vec4};
+use rmath::{mat3::Mat3, mat4::Mat4, vec3::Vec3, vec4::Vec4};
 
 pub mod assimp;
 pub mod framework;
@@ -34,6 +34,10 @@ impl<T> DerefMut for UniformWrapper<T> {
 impl Uniform for UniformWrapper<&Mat4<f32>> {
     type Options = bool; // transpose
 
+    fn accepts(gl_type: gl_sys::bindings::GLenum) -> bool {
+        gl_type == gl_sys::bindings::GL_FLOAT_MAT4
+    }
+
     fn set(&self, options: Option<Self::Options>, UniformLocation(location): &UniformLocation) {
         let transpose = options.unwrap_or(false);
         let cols = self.cols();
@@ -44,8 +48,29 @@ impl Uniform for UniformWrapper<&Mat4<f32>> {
     }
 }
 
+impl Uniform for UniformWrapper<&Mat3<f32>> {
+    type Options = bool; // transpose
+
+    fn accepts(gl_type: gl_sys::bindings::GLenum) -> bool {
+        gl_type == gl_sys::bindings::GL_FLOAT_MAT3
+    }
+
+    fn set(&self, options: Option<Self::Options>, UniformLocation(location): &UniformLocation) {
+        let transpose = options.unwrap_or(false);
+        let cols = self.cols();
+        let value = cols.as_ptr() as _;
+        unsafe {
+            gl_sys::bindings::glUniformMatrix3fv(*location, 1, u8::from(transpose), value);
+        }
+    }
+}
+
 impl Uniform for UniformWrapper<&Vec4<f32>> {
     type Options = ();
+    fn accepts(gl_type: gl_sys::bindings::GLenum) -> bool {
+        gl_type == gl_sys::bindings::GL_FLOAT_VEC4
+    }
+
     fn set(&self, _options: Option<Self::Options>, UniformLocation(location): &UniformLocation) {
         unsafe {
             gl_sys::bindings::glUniform4f(*location, self.x, self.y, self.z, self.w);
@@ -56,6 +81,10 @@ impl Uniform for UniformWrapper<&Vec4<f32>> {
 impl Uniform for UniformWrapper<&Vec3<f32>> {
     type Options = ();
 
+    fn accepts(gl_type: gl_sys::bindings::GLenum) -> bool {
+        gl_type == gl_sys::bindings::GL_FLOAT_VEC3
+    }
+
     fn set(&self, _options: Option<Self::Options>, UniformLocation(location): &UniformLocation) {
         let Vec3 { x, y, z } = self.0;
         unsafe {
@@ -6,23 +6,22 @@ use std::{
 };
 
 use imgui_sys::ImGuiCondition;
-use rusty_gl::{
-    UniformWrapper,
-    framework::{
-        camera::Camera,
-        color::rgb::ColorRGB,
-        material::material_textures::MaterialTextureType,
-        mesh::{load::SceneImport, normalize::NormalizeOptions},
-    },
+use rusty_gl::framework::{
+    blocks::{CameraBlock, LightsBlock},
+    camera::Camera,
+    color::rgb::ColorRGB,
+    material::material_textures::MaterialTextureType,
+    mesh::{load::SceneImport, normalize::NormalizeOptions},
 };
 
-use rmath::{mat4::Mat4, vec3::Vec3};
+use rmath::{quat::Quat, transform::Transform, vec3::Vec3};
 
 use gl_sys::{
     self,
     program::Program,
     shader::{Shader, ShaderType},
-    vao::VertexArrayObject,
+    ubo::UniformBuffer,
+    vao::{VertexArrayObject, VertexArrayRef},
 };
 
 use glfw_sys::{
@@ -47,6 +46,8 @@ fn main() -> ExitCode {
 
     glfw_sys::init().expect("Failed to initialize GLFW");
 
+    // 4.1 is the newest core profile macOS offers; later features such as
+    // compute are checked against the context at runtime by gl_sys.
     glfw_sys::window_hint(glfw_sys::bindings::GLFW_CONTEXT_VERSION_MAJOR, 4)
         .expect("Failed to set window hint");
     glfw_sys::window_hint(glfw_sys::bindings::GLFW_CONTEXT_VERSION_MINOR, 1)
@@ -76,7 +77,7 @@ fn main() -> ExitCode {
 
     let mut window = Window::try_new(960, 540, "Rust").expect("Failed to create GLFW window");
 
-    let scene = SceneImport::import(entrypoint.as_str(), NormalizeOptions::Scale(200))
+    let mut scene = SceneImport::import(entrypoint.as_str(), NormalizeOptions::Scale(200))
         .expect("Failed to load model");
 
     let vertex_shader =
@@ -91,8 +92,10 @@ fn main() -> ExitCode {
     VertexArrayObject::bind_vertex_array(&vao);
     let program = Program::from_shaders(&[vertex_shader, fragment_shader])
         .expect("Failed to create shader program");
-    VertexArrayObject::bind_vertex_array(&VertexArrayObject::zero());
-    const MODEL_MATRIX: Mat4<f32> = Mat4::identity();
+    VertexArrayObject::bind_vertex_array(VertexArrayRef::zero());
+    let mut model = Transform::<f32>::identity();
+    // Pitch, yaw and roll in degrees; `Quat` has no way back to Euler angles.
+    let mut model_euler = [0.0f32; 3];
 
     let mut camera = Camera::with_defaults(
         Vec3::new(0.5, 2.00, 0.0),
@@ -108,7 +111,25 @@ fn main() -> ExitCode {
     const DIRLIGHT_COLOR: ColorRGB = ColorRGB::new(1.0, 0.97, 0.8);
     const DIRLIGHT_INTENSITY: f32 = 1.5;
 
-    const COLOR_EXP: ColorRGB = ColorRGB::new(2.2, 2.2, 2.2);
+    let mut camera_ubo =
+        UniformBuffer::<CameraBlock>::new(0).expect("Failed to create camera uniform buffer");
+    camera_ubo
+        .attach(&program, "Camera")
+        .expect("Camera block does not match CameraBlock");
+
+    let mut lights_ubo =
+        UniformBuffer::<LightsBlock>::new(1).expect("Failed to create lights uniform buffer");
+    lights_ubo
+        .attach(&program, "Lights")
+        .expect("Lights block does not match LightsBlock");
+    lights_ubo.upload(&LightsBlock {
+        pointlight_pos: POINTLIGHT_POS,
+        pointlight_intensity: POINTLIGHT_INTENSITY,
+        pointlight_color: POINTLIGHT_COLOR.to_linear().into(),
+        dirlight_dir: DIRLIGHT_DIR,
+        dirlight_color: DIRLIGHT_COLOR.to_linear().into(),
+        dirlight_intensity: DIRLIGHT_INTENSITY,
+    });
 
     gl_sys::enable(gl_sys::bindings::GL_DEPTH_TEST);
 
@@ -138,6 +159,35 @@ fn main() -> ExitCode {
                     .expect("Could not format Camera view");
             }
             imgui_sys::end();
+
+            imgui_sys::set_next_window_size([300.0, 100.0], ImGuiCondition::Once);
+
+            imgui_sys::set_next_window_pos([10.0, 90.0], ImGuiCondition::Once, [0.0, 0.0]);
+
+            imgui_sys::begin("Model").expect("Could not init window");
+            {
+                let mut translation = model.translation.data();
+                if imgui_sys::drag_float3("Pos", &mut translation, 0.1)
+                    .expect("Could not edit Model translation")
+                {
+                    model.translation = translation.into();
+                }
+
+                if imgui_sys::drag_float3("Rot", &mut model_euler, 1.0)
+                    .expect("Could not edit Model rotation")
+                {
+                    let [pitch, yaw, roll] = model_euler;
+                    model.rotation = Quat::from_euler(pitch, yaw, roll);
+                }
+
+                let mut scale = model.scale.data();
+                if imgui_sys::drag_float3("Scale", &mut scale, 0.01)
+                    .expect("Could not edit Model scale")
+                {
+                    model.scale = scale.into();
+                }
+            }
+            imgui_sys::end();
         }
         imgui_sys::end_drawing();
         window.swap_buffers();
@@ -176,44 +226,22 @@ fn main() -> ExitCode {
             }
         }
         if timer.should_render() {
-            gl_sys::clear_color(BACKGROUND.r(), BACKGROUND.g(), BACKGROUND.g(), 0.0);
+            let [r, g, b] = BACKGROUND.to_array();
+            gl_sys::clear_color(r, g, b, 0.0);
             gl_sys::clear(
                 gl_sys::bindings::GL_COLOR_BUFFER_BIT | gl_sys::bindings::GL_DEPTH_BUFFER_BIT,
             );
 
             let aspect_ratio = window.aspect_ratio();
-            for element in scene.elements() {
-                element
-                    .bind(&program, &camera, aspect_ratio)
-                    .expect("Failed to bind element");
+            let frustum = camera.frustum(aspect_ratio);
+            camera_ubo.upload(&CameraBlock::new(&camera, aspect_ratio));
+            scene.update(&model.to_mat4());
+            for (element, world) in scene
+                .instances()
+                .filter(|(e, world)| frustum.is_visible(&e.mesh.bounds.transform(world)))
+            {
+                element.bind(&program).expect("Failed to bind element");
 
-                program
-                    .uniform("pointlight_pos", UniformWrapper(&POINTLIGHT_POS))
-                    .expect("Failed to set pointlight position");
-                program
-                    .uniform(
-                        "pointlight_color",
-                        UniformWrapper(&Vec3::pow(POINTLIGHT_COLOR, COLOR_EXP)),
-                    )
-                    .expect("Failed to set pointlight color");
-                program
-                    .uniform("pointlight_intensity", POINTLIGHT_INTENSITY)
-                    .expect("Failed to set pointlight intensity");
-                program
-                    .uniform("dirlight_dir", UniformWrapper(&DIRLIGHT_DIR))
-                    .expect("Failed to set dirlight direction");
-                program
-                    .uniform(
-                        "dirlight_color",
-                        UniformWrapper(&Vec3::pow(DIRLIGHT_COLOR, COLOR_EXP)),
-                    )
-                    .expect("Failed to set dirlight color");
-                program
-                    .uniform("dirlight_intensity", DIRLIGHT_INTENSITY)
-                    .expect("Failed to set dirlight intensity");
-                program
-                    .uniform("camera_pos", UniformWrapper(camera.position()))
-                    .expect("Failed to set camera position");
                 program
                     .uniform(
                         "has_alphamap",
@@ -229,13 +257,18 @@ fn main() -> ExitCode {
                     )
                     .expect("Failed to se has_alphamap");
                 element
-                    .draw(&program, &MODEL_MATRIX)
+                    .draw(&program, world)
                     .expect("Failed to draw element");
                 element.unbind(&program);
             }
             timer.rendered();
         }
     }
+    // GL objects must be deleted while the context is still current.
+    drop(scene);
+    drop(camera_ubo);
+    drop(lights_ubo);
+    drop(vao);
     glfw_sys::terminate();
     ExitCode::SUCCESS
 }
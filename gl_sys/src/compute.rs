@@ -0,0 +1,176 @@
+use std::{fmt, ops::BitOr};
+
+use crate::{
+    GL_SHADER_TYPE, GLbitfield, GLint, GLintptr, GLuint,
+    gl43::{
+        GL_ALL_BARRIER_BITS, GL_BUFFER_UPDATE_BARRIER_BIT, GL_COMMAND_BARRIER_BIT,
+        GL_COMPUTE_SHADER, GL_COMPUTE_WORK_GROUP_SIZE, GL_DISPATCH_INDIRECT_BUFFER,
+        GL_ELEMENT_ARRAY_BARRIER_BIT, GL_FRAMEBUFFER_BARRIER_BIT,
+        GL_SHADER_IMAGE_ACCESS_BARRIER_BIT, GL_SHADER_STORAGE_BARRIER_BIT,
+        GL_TEXTURE_FETCH_BARRIER_BIT, GL_UNIFORM_BARRIER_BIT, GL_VERTEX_ATTRIB_ARRAY_BARRIER_BIT,
+        glDispatchCompute, glDispatchComputeIndirect, glMemoryBarrier,
+    },
+    glGetProgramiv,
+    program::Program,
+    shader::{Shader, get_shader_iv},
+    vbo::{VertexBufferObject, VertexBufferRef},
+    version::{Feature, Version, VersionError},
+};
+
+/// Which kinds of access must see writes made by earlier shaders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Barriers(pub GLbitfield);
+
+impl Barriers {
+    pub const VERTEX_ATTRIB_ARRAY: Self = Self(GL_VERTEX_ATTRIB_ARRAY_BARRIER_BIT);
+    pub const ELEMENT_ARRAY: Self = Self(GL_ELEMENT_ARRAY_BARRIER_BIT);
+    pub const UNIFORM: Self = Self(GL_UNIFORM_BARRIER_BIT);
+    pub const TEXTURE_FETCH: Self = Self(GL_TEXTURE_FETCH_BARRIER_BIT);
+    pub const SHADER_IMAGE_ACCESS: Self = Self(GL_SHADER_IMAGE_ACCESS_BARRIER_BIT);
+    pub const COMMAND: Self = Self(GL_COMMAND_BARRIER_BIT);
+    pub const BUFFER_UPDATE: Self = Self(GL_BUFFER_UPDATE_BARRIER_BIT);
+    pub const FRAMEBUFFER: Self = Self(GL_FRAMEBUFFER_BARRIER_BIT);
+    pub const SHADER_STORAGE: Self = Self(GL_SHADER_STORAGE_BARRIER_BIT);
+    pub const ALL: Self = Self(GL_ALL_BARRIER_BITS);
+
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for Barriers {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// A program made of a single compute shader.
+pub struct ComputeProgram {
+    program: Program,
+    local_size: [GLint; 3],
+}
+
+impl ComputeProgram {
+    pub fn from_shader(shader: Shader) -> Result<Self, ComputeError> {
+        Version::require(Feature::ComputeShader)?;
+        if get_shader_iv(shader.id(), GL_SHADER_TYPE) != GL_COMPUTE_SHADER as GLint {
+            return Err(ComputeError::NotCompute);
+        }
+
+        let program = Program::from_shaders(&[shader]).map_err(ComputeError::LinkError)?;
+        let mut local_size = [0; 3];
+        unsafe {
+            glGetProgramiv(
+                program.id(),
+                GL_COMPUTE_WORK_GROUP_SIZE,
+                local_size.as_mut_ptr(),
+            );
+        }
+        Ok(Self {
+            program,
+            local_size,
+        })
+    }
+
+    #[inline]
+    pub const fn program(&self) -> &Program {
+        &self.program
+    }
+
+    /// The `local_size_x/y/z` the shader was compiled with.
+    #[inline]
+    pub const fn local_size(&self) -> [GLint; 3] {
+        self.local_size
+    }
+
+    /// Number of work groups needed to cover `invocations` in each
+    /// dimension.
+    pub fn groups_for(&self, invocations: [GLuint; 3]) -> [GLuint; 3] {
+        let mut groups = [0; 3];
+        for (g, (n, size)) in groups
+            .iter_mut()
+            .zip(invocations.iter().zip(self.local_size))
+        {
+            *g = n.div_ceil(size.max(1) as GLuint);
+        }
+        groups
+    }
+
+    /// Binds the program and runs `x * y * z` work groups.
+    pub fn dispatch(&self, x: GLuint, y: GLuint, z: GLuint) {
+        self.program.bind();
+        unsafe {
+            glDispatchCompute(x, y, z);
+        }
+    }
+
+    /// Makes writes of earlier dispatches visible to `barriers`. Compute
+    /// support implies memory barriers, so there is no version check here.
+    pub fn memory_barrier(&self, barriers: Barriers) {
+        unsafe {
+            glMemoryBarrier(barriers.0);
+        }
+    }
+
+    /// Like [`ComputeProgram::dispatch`], reading the group counts as three
+    /// `uint`s at `offset` in `buffer`.
+    pub fn dispatch_indirect<'a>(&self, buffer: impl Into<VertexBufferRef<'a>>, offset: GLintptr) {
+        self.program.bind();
+        VertexBufferObject::bind_buffer(GL_DISPATCH_INDIRECT_BUFFER, buffer);
+        unsafe {
+            glDispatchComputeIndirect(offset);
+        }
+        VertexBufferObject::bind_buffer(GL_DISPATCH_INDIRECT_BUFFER, VertexBufferRef::zero());
+    }
+}
+
+#[derive(Debug)]
+pub enum ComputeError {
+    Unsupported(VersionError),
+    NotCompute,
+    LinkError(String),
+}
+
+impl From<VersionError> for ComputeError {
+    fn from(value: VersionError) -> Self {
+        Self::Unsupported(value)
+    }
+}
+
+impl fmt::Display for ComputeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsupported(e) => fmt::Display::fmt(e, f),
+            Self::NotCompute => write!(f, "Shader is not a compute shader"),
+            Self::LinkError(m) => write!(f, "{}", m),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn test_barriers_combine() {
+        let b = Barriers::SHADER_STORAGE | Barriers::COMMAND;
+        assert!(b.contains(Barriers::SHADER_STORAGE));
+        assert!(b.contains(Barriers::COMMAND));
+        assert!(!b.contains(Barriers::UNIFORM));
+        assert!(Barriers::ALL.contains(b));
+    }
+
+    #[test]
+    fn test_compute_groups_for() {
+        let compute = ComputeProgram {
+            program: Program::from_uniforms(Vec::new(), HashMap::new()),
+            local_size: [64, 4, 1],
+        };
+        assert_eq!(compute.groups_for([1000, 8, 3]), [16, 2, 3]);
+        assert_eq!(compute.groups_for([64, 0, 1]), [1, 0, 1]);
+    }
+}
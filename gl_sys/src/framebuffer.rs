@@ -0,0 +1,392 @@
+use std::fmt;
+
+use crate::{
+    GL_COLOR_ATTACHMENT0, GL_COLOR_BUFFER_BIT, GL_DEPTH_ATTACHMENT, GL_DEPTH_STENCIL_ATTACHMENT,
+    GL_DRAW_FRAMEBUFFER, GL_FRAMEBUFFER, GL_FRAMEBUFFER_COMPLETE,
+    GL_FRAMEBUFFER_INCOMPLETE_ATTACHMENT, GL_FRAMEBUFFER_INCOMPLETE_DRAW_BUFFER,
+    GL_FRAMEBUFFER_INCOMPLETE_LAYER_TARGETS, GL_FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT,
+    GL_FRAMEBUFFER_INCOMPLETE_MULTISAMPLE, GL_FRAMEBUFFER_INCOMPLETE_READ_BUFFER,
+    GL_FRAMEBUFFER_UNDEFINED, GL_FRAMEBUFFER_UNSUPPORTED, GL_NEAREST, GL_READ_FRAMEBUFFER,
+    GL_RENDERBUFFER, GL_STENCIL_ATTACHMENT, GL_TEXTURE_2D, GLbitfield, GLenum, GLint, GLsizei,
+    GLuint, glBindFramebuffer, glBindRenderbuffer, glBlitFramebuffer, glCheckFramebufferStatus,
+    glDrawBuffers, glFramebufferRenderbuffer, glFramebufferTexture2D, glReadBuffer,
+    glRenderbufferStorage, glRenderbufferStorageMultisample,
+};
+
+#[cfg(test)]
+use crate::mock::{
+    glDeleteFramebuffers, glDeleteRenderbuffers, glGenFramebuffers, glGenRenderbuffers,
+};
+#[cfg(not(test))]
+use crate::{glDeleteFramebuffers, glDeleteRenderbuffers, glGenFramebuffers, glGenRenderbuffers};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    Framebuffer,
+    Read,
+    Draw,
+}
+
+impl Target {
+    pub const fn key(&self) -> GLenum {
+        match self {
+            Self::Framebuffer => GL_FRAMEBUFFER,
+            Self::Read => GL_READ_FRAMEBUFFER,
+            Self::Draw => GL_DRAW_FRAMEBUFFER,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Attachment {
+    Color(u32),
+    Depth,
+    Stencil,
+    DepthStencil,
+}
+
+impl Attachment {
+    pub const fn key(&self) -> GLenum {
+        match self {
+            Self::Color(i) => GL_COLOR_ATTACHMENT0 + *i,
+            Self::Depth => GL_DEPTH_ATTACHMENT,
+            Self::Stencil => GL_STENCIL_ATTACHMENT,
+            Self::DepthStencil => GL_DEPTH_STENCIL_ATTACHMENT,
+        }
+    }
+}
+
+/// Owning renderbuffer handle; the storage is deleted when this is dropped.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Renderbuffer {
+    id: GLuint,
+    width: GLsizei,
+    height: GLsizei,
+    samples: GLsizei,
+}
+
+impl Drop for Renderbuffer {
+    fn drop(&mut self) {
+        if self.id != 0 {
+            unsafe {
+                glDeleteRenderbuffers(1, &self.id);
+            }
+        }
+    }
+}
+
+impl Renderbuffer {
+    pub fn new(internal_format: GLenum, width: GLsizei, height: GLsizei) -> Self {
+        Self::multisample(0, internal_format, width, height)
+    }
+
+    /// With `samples == 0` this allocates single-sampled storage.
+    pub fn multisample(
+        samples: GLsizei,
+        internal_format: GLenum,
+        width: GLsizei,
+        height: GLsizei,
+    ) -> Self {
+        let id = Self::gen_renderbuffers();
+        unsafe {
+            glBindRenderbuffer(GL_RENDERBUFFER, id);
+            if samples == 0 {
+                glRenderbufferStorage(GL_RENDERBUFFER, internal_format, width, height);
+            } else {
+                glRenderbufferStorageMultisample(
+                    GL_RENDERBUFFER,
+                    samples,
+                    internal_format,
+                    width,
+                    height,
+                );
+            }
+            glBindRenderbuffer(GL_RENDERBUFFER, 0);
+        }
+        Self {
+            id,
+            width,
+            height,
+            samples,
+        }
+    }
+
+    fn gen_renderbuffers() -> GLuint {
+        let mut renderbuffer = 0;
+        unsafe {
+            glGenRenderbuffers(1, &mut renderbuffer);
+        }
+        renderbuffer
+    }
+
+    #[inline]
+    pub const fn id(&self) -> GLuint {
+        self.id
+    }
+
+    #[inline]
+    pub const fn width(&self) -> GLsizei {
+        self.width
+    }
+
+    #[inline]
+    pub const fn height(&self) -> GLsizei {
+        self.height
+    }
+
+    #[inline]
+    pub const fn samples(&self) -> GLsizei {
+        self.samples
+    }
+}
+
+/// Owning framebuffer handle; the framebuffer is deleted when this is
+/// dropped. The default value stands for the window's framebuffer.
+///
+/// Attaching and configuring binds the framebuffer to `GL_FRAMEBUFFER` and
+/// leaves it bound.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Framebuffer(GLuint);
+
+impl Drop for Framebuffer {
+    fn drop(&mut self) {
+        if self.0 != 0 {
+            unsafe {
+                glDeleteFramebuffers(1, &self.0);
+            }
+        }
+    }
+}
+
+impl Framebuffer {
+    #[inline]
+    pub fn gen_framebuffers() -> Self {
+        let mut framebuffer = 0;
+        unsafe {
+            glGenFramebuffers(1, &mut framebuffer);
+        }
+        Self(framebuffer)
+    }
+
+    #[inline]
+    pub const fn id(&self) -> GLuint {
+        self.0
+    }
+
+    #[inline]
+    pub fn bind(&self, target: Target) {
+        unsafe {
+            glBindFramebuffer(target.key(), self.0);
+        }
+    }
+
+    /// Binds the window's framebuffer.
+    #[inline]
+    pub fn bind_default(target: Target) {
+        unsafe {
+            glBindFramebuffer(target.key(), 0);
+        }
+    }
+
+    /// Attaches mip `level` of a `GL_TEXTURE_2D` texture.
+    pub fn attach_texture_2d(&self, attachment: Attachment, texture: GLuint, level: GLint) {
+        self.attach_texture(attachment, GL_TEXTURE_2D, texture, level);
+    }
+
+    /// Attaches a texture of any 2D target, e.g. a cube map face or
+    /// `GL_TEXTURE_2D_MULTISAMPLE`.
+    pub fn attach_texture(
+        &self,
+        attachment: Attachment,
+        texture_target: GLenum,
+        texture: GLuint,
+        level: GLint,
+    ) {
+        self.bind(Target::Framebuffer);
+        unsafe {
+            glFramebufferTexture2D(
+                GL_FRAMEBUFFER,
+                attachment.key(),
+                texture_target,
+                texture,
+                level,
+            );
+        }
+    }
+
+    pub fn attach_renderbuffer(&self, attachment: Attachment, renderbuffer: &Renderbuffer) {
+        self.bind(Target::Framebuffer);
+        unsafe {
+            glFramebufferRenderbuffer(
+                GL_FRAMEBUFFER,
+                attachment.key(),
+                GL_RENDERBUFFER,
+                renderbuffer.id,
+            );
+        }
+    }
+
+    /// Routes fragment output `i` to color attachment `attachments[i]`.
+    pub fn draw_buffers(&self, attachments: &[u32]) -> Result<(), FramebufferError> {
+        let buffers: Vec<GLenum> = attachments
+            .iter()
+            .map(|&i| Attachment::Color(i).key())
+            .collect();
+        let n = GLsizei::try_from(buffers.len()).map_err(|_| FramebufferError::CastError)?;
+        self.bind(Target::Framebuffer);
+        unsafe {
+            glDrawBuffers(n, buffers.as_ptr());
+        }
+        Ok(())
+    }
+
+    /// Selects the color attachment read by blits and `glReadPixels`.
+    pub fn read_buffer(&self, attachment: u32) {
+        self.bind(Target::Read);
+        unsafe {
+            glReadBuffer(Attachment::Color(attachment).key());
+        }
+    }
+
+    pub fn check_status(&self) -> Result<(), FramebufferError> {
+        self.bind(Target::Framebuffer);
+        let status = unsafe { glCheckFramebufferStatus(GL_FRAMEBUFFER) };
+        FramebufferError::from_status(status)
+    }
+
+    /// Copies `src_rect` of `src` into `dst_rect` of `dst`, with `None`
+    /// standing for the window's framebuffer. Rects are `[x0, y0, x1, y1]`.
+    /// Leaves both framebuffers bound to their read and draw targets.
+    pub fn blit(
+        src: Option<&Framebuffer>,
+        dst: Option<&Framebuffer>,
+        src_rect: [GLint; 4],
+        dst_rect: [GLint; 4],
+        mask: GLbitfield,
+        filter: GLenum,
+    ) {
+        let [sx0, sy0, sx1, sy1] = src_rect;
+        let [dx0, dy0, dx1, dy1] = dst_rect;
+        unsafe {
+            glBindFramebuffer(GL_READ_FRAMEBUFFER, src.map_or(0, Framebuffer::id));
+            glBindFramebuffer(GL_DRAW_FRAMEBUFFER, dst.map_or(0, Framebuffer::id));
+            glBlitFramebuffer(sx0, sy0, sx1, sy1, dx0, dy0, dx1, dy1, mask, filter);
+        }
+    }
+
+    /// Resolves the multisampled color buffer into `dst`; both must be
+    /// `width` by `height`.
+    pub fn resolve(&self, dst: Option<&Framebuffer>, width: GLint, height: GLint) {
+        let rect = [0, 0, width, height];
+        Self::blit(Some(self), dst, rect, rect, GL_COLOR_BUFFER_BIT, GL_NEAREST);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FramebufferError {
+    Undefined,
+    IncompleteAttachment,
+    MissingAttachment,
+    IncompleteDrawBuffer,
+    IncompleteReadBuffer,
+    Unsupported,
+    IncompleteMultisample,
+    IncompleteLayerTargets,
+    CastError,
+    Other(GLenum),
+}
+
+impl FramebufferError {
+    pub fn from_status(status: GLenum) -> Result<(), Self> {
+        Err(match status {
+            GL_FRAMEBUFFER_COMPLETE => return Ok(()),
+            GL_FRAMEBUFFER_UNDEFINED => Self::Undefined,
+            GL_FRAMEBUFFER_INCOMPLETE_ATTACHMENT => Self::IncompleteAttachment,
+            GL_FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT => Self::MissingAttachment,
+            GL_FRAMEBUFFER_INCOMPLETE_DRAW_BUFFER => Self::IncompleteDrawBuffer,
+            GL_FRAMEBUFFER_INCOMPLETE_READ_BUFFER => Self::IncompleteReadBuffer,
+            GL_FRAMEBUFFER_UNSUPPORTED => Self::Unsupported,
+            GL_FRAMEBUFFER_INCOMPLETE_MULTISAMPLE => Self::IncompleteMultisample,
+            GL_FRAMEBUFFER_INCOMPLETE_LAYER_TARGETS => Self::IncompleteLayerTargets,
+            _ => Self::Other(status),
+        })
+    }
+}
+
+impl fmt::Display for FramebufferError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Undefined => write!(f, "Default framebuffer does not exist"),
+            Self::IncompleteAttachment => write!(f, "Framebuffer attachment is incomplete"),
+            Self::MissingAttachment => write!(f, "Framebuffer has no attachments"),
+            Self::IncompleteDrawBuffer => write!(f, "Draw buffer has no attachment"),
+            Self::IncompleteReadBuffer => write!(f, "Read buffer has no attachment"),
+            Self::Unsupported => write!(f, "Attachment formats are unsupported"),
+            Self::IncompleteMultisample => write!(f, "Attachments differ in sample count"),
+            Self::IncompleteLayerTargets => write!(f, "Attachments differ in layering"),
+            Self::CastError => write!(f, "Failed to cast value"),
+            Self::Other(c) => write!(f, "Unknown framebuffer status: {}", c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock;
+
+    #[test]
+    fn test_framebuffer_status() {
+        assert_eq!(
+            FramebufferError::from_status(GL_FRAMEBUFFER_COMPLETE),
+            Ok(())
+        );
+        assert_eq!(
+            FramebufferError::from_status(GL_FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT),
+            Err(FramebufferError::MissingAttachment)
+        );
+        assert_eq!(
+            FramebufferError::from_status(GL_FRAMEBUFFER_INCOMPLETE_MULTISAMPLE),
+            Err(FramebufferError::IncompleteMultisample)
+        );
+        assert_eq!(
+            FramebufferError::from_status(0),
+            Err(FramebufferError::Other(0))
+        );
+    }
+
+    #[test]
+    fn test_framebuffer_attachment_keys() {
+        assert_eq!(Attachment::Color(0).key(), GL_COLOR_ATTACHMENT0);
+        assert_eq!(Attachment::Color(3).key(), GL_COLOR_ATTACHMENT0 + 3);
+        assert_eq!(Attachment::DepthStencil.key(), GL_DEPTH_STENCIL_ATTACHMENT);
+    }
+
+    #[test]
+    fn test_framebuffer_deleted_on_drop() {
+        let before = mock::live_framebuffers();
+        let a = Framebuffer::gen_framebuffers();
+        let b = Framebuffer::gen_framebuffers();
+        assert_ne!(a.id(), b.id());
+        assert_eq!(mock::live_framebuffers(), before + 2);
+        drop(a);
+        assert_eq!(mock::live_framebuffers(), before + 1);
+        drop(b);
+        drop(Framebuffer::default());
+        assert_eq!(mock::live_framebuffers(), before);
+    }
+
+    #[test]
+    fn test_renderbuffer_deleted_on_drop() {
+        let before = mock::live_renderbuffers();
+        let rb = Renderbuffer {
+            id: Renderbuffer::gen_renderbuffers(),
+            width: 4,
+            height: 4,
+            samples: 0,
+        };
+        assert_eq!(mock::live_renderbuffers(), before + 1);
+        drop(rb);
+        assert_eq!(mock::live_renderbuffers(), before);
+    }
+}
@@ -0,0 +1,51 @@
+//! OpenGL 4.2 and 4.3 names. The macOS headers stop at 4.1, so there the
+//! constants are spelled out and the functions only panic; callers check
+//! [`Version::require`](crate::version::Version::require) first, which
+//! always fails on macOS.
+
+#[cfg(not(target_os = "macos"))]
+pub use crate::bindings::{
+    GL_ALL_BARRIER_BITS, GL_BUFFER_UPDATE_BARRIER_BIT, GL_COMMAND_BARRIER_BIT, GL_COMPUTE_SHADER,
+    GL_COMPUTE_WORK_GROUP_SIZE, GL_DISPATCH_INDIRECT_BUFFER, GL_ELEMENT_ARRAY_BARRIER_BIT,
+    GL_FRAMEBUFFER_BARRIER_BIT, GL_MAX_SHADER_STORAGE_BUFFER_BINDINGS,
+    GL_SHADER_IMAGE_ACCESS_BARRIER_BIT, GL_SHADER_STORAGE_BARRIER_BIT, GL_SHADER_STORAGE_BUFFER,
+    GL_TEXTURE_FETCH_BARRIER_BIT, GL_UNIFORM_BARRIER_BIT, GL_VERTEX_ATTRIB_ARRAY_BARRIER_BIT,
+    glDispatchCompute, glDispatchComputeIndirect, glMemoryBarrier,
+};
+
+#[cfg(target_os = "macos")]
+pub use fallback::*;
+
+#[cfg(target_os = "macos")]
+#[allow(non_snake_case)]
+mod fallback {
+    use crate::{GLbitfield, GLenum, GLintptr, GLuint};
+
+    pub const GL_VERTEX_ATTRIB_ARRAY_BARRIER_BIT: GLbitfield = 0x0000_0001;
+    pub const GL_ELEMENT_ARRAY_BARRIER_BIT: GLbitfield = 0x0000_0002;
+    pub const GL_UNIFORM_BARRIER_BIT: GLbitfield = 0x0000_0004;
+    pub const GL_TEXTURE_FETCH_BARRIER_BIT: GLbitfield = 0x0000_0008;
+    pub const GL_SHADER_IMAGE_ACCESS_BARRIER_BIT: GLbitfield = 0x0000_0020;
+    pub const GL_COMMAND_BARRIER_BIT: GLbitfield = 0x0000_0040;
+    pub const GL_BUFFER_UPDATE_BARRIER_BIT: GLbitfield = 0x0000_0200;
+    pub const GL_FRAMEBUFFER_BARRIER_BIT: GLbitfield = 0x0000_0400;
+    pub const GL_SHADER_STORAGE_BARRIER_BIT: GLbitfield = 0x0000_2000;
+    pub const GL_ALL_BARRIER_BITS: GLbitfield = 0xFFFF_FFFF;
+    pub const GL_SHADER_STORAGE_BUFFER: GLenum = 0x90D2;
+    pub const GL_MAX_SHADER_STORAGE_BUFFER_BINDINGS: GLenum = 0x90DD;
+    pub const GL_DISPATCH_INDIRECT_BUFFER: GLenum = 0x90EE;
+    pub const GL_COMPUTE_SHADER: GLenum = 0x91B9;
+    pub const GL_COMPUTE_WORK_GROUP_SIZE: GLenum = 0x8267;
+
+    pub unsafe fn glMemoryBarrier(_barriers: GLbitfield) {
+        unreachable!("glMemoryBarrier is unavailable on macOS");
+    }
+
+    pub unsafe fn glDispatchCompute(_x: GLuint, _y: GLuint, _z: GLuint) {
+        unreachable!("glDispatchCompute is unavailable on macOS");
+    }
+
+    pub unsafe fn glDispatchComputeIndirect(_indirect: GLintptr) {
+        unreachable!("glDispatchComputeIndirect is unavailable on macOS");
+    }
+}
@@ -2,11 +2,19 @@ use crate::bindings::*;
 use std::fmt;
 
 pub mod bindings;
+pub mod compute;
+pub mod framebuffer;
+mod gl43;
+#[cfg(test)]
+mod mock;
 pub mod program;
 pub mod shader;
+pub mod ssbo;
+pub mod ubo;
 pub mod uniform;
 pub mod vao;
 pub mod vbo;
+pub mod version;
 
 #[inline]
 pub fn draw_arrays(mode: u32, first: i32, count: i32) {
@@ -0,0 +1,89 @@
+//! Stand-ins for the GL object functions so ownership can be tested without
+//! a context. Names are unique across threads; live counts are per thread,
+//! so parallel tests do not see each other's objects.
+
+#![allow(non_snake_case)]
+
+use std::{
+    cell::Cell,
+    sync::atomic::{AtomicU32, Ordering},
+    thread::LocalKey,
+};
+
+use crate::{GLsizei, GLuint};
+
+static NEXT_NAME: AtomicU32 = AtomicU32::new(1);
+
+thread_local! {
+    static LIVE_BUFFERS: Cell<usize> = const { Cell::new(0) };
+    static LIVE_VERTEX_ARRAYS: Cell<usize> = const { Cell::new(0) };
+    static LIVE_FRAMEBUFFERS: Cell<usize> = const { Cell::new(0) };
+    static LIVE_RENDERBUFFERS: Cell<usize> = const { Cell::new(0) };
+}
+
+pub fn live_buffers() -> usize {
+    LIVE_BUFFERS.get()
+}
+
+pub fn live_vertex_arrays() -> usize {
+    LIVE_VERTEX_ARRAYS.get()
+}
+
+pub fn live_framebuffers() -> usize {
+    LIVE_FRAMEBUFFERS.get()
+}
+
+pub fn live_renderbuffers() -> usize {
+    LIVE_RENDERBUFFERS.get()
+}
+
+unsafe fn generate(live: &'static LocalKey<Cell<usize>>, n: GLsizei, names: *mut GLuint) {
+    for i in 0..n as usize {
+        unsafe {
+            names
+                .add(i)
+                .write(NEXT_NAME.fetch_add(1, Ordering::Relaxed))
+        };
+    }
+    live.set(live.get() + n as usize);
+}
+
+unsafe fn delete(live: &'static LocalKey<Cell<usize>>, n: GLsizei, names: *const GLuint) {
+    // Like GL, zero is silently ignored.
+    let deleted = (0..n as usize)
+        .filter(|&i| unsafe { names.add(i).read() } != 0)
+        .count();
+    live.set(live.get() - deleted);
+}
+
+pub unsafe fn glGenBuffers(n: GLsizei, buffers: *mut GLuint) {
+    unsafe { generate(&LIVE_BUFFERS, n, buffers) }
+}
+
+pub unsafe fn glDeleteBuffers(n: GLsizei, buffers: *const GLuint) {
+    unsafe { delete(&LIVE_BUFFERS, n, buffers) }
+}
+
+pub unsafe fn glGenVertexArrays(n: GLsizei, arrays: *mut GLuint) {
+    unsafe { generate(&LIVE_VERTEX_ARRAYS, n, arrays) }
+}
+
+pub unsafe fn glDeleteVertexArrays(n: GLsizei, arrays: *const GLuint) {
+    unsafe { delete(&LIVE_VERTEX_ARRAYS, n, arrays) }
+}
+
+pub unsafe fn glGenFramebuffers(n: GLsizei, framebuffers: *mut GLuint) {
+    unsafe { generate(&LIVE_FRAMEBUFFERS, n, framebuffers) }
+}
+
+pub unsafe fn glDeleteFramebuffers(n: GLsizei, framebuffers: *const GLuint) {
+    unsafe { delete(&LIVE_FRAMEBUFFERS, n, framebuffers) }
+}
+
+pub unsafe fn glGenRenderbuffers(n: GLsizei, renderbuffers: *mut GLuint) {
+    unsafe { generate(&LIVE_RENDERBUFFERS, n, renderbuffers) }
+}
+
+pub unsafe fn glDeleteRenderbuffers(n: GLsizei, renderbuffers: *const GLuint) {
+    unsafe { delete(&LIVE_RENDERBUFFERS, n, renderbuffers) }
+}
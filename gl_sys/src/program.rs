@@ -1,12 +1,65 @@
+use std::{collections::HashMap, ffi};
+
 use crate::{
-    GL_INFO_LOG_LENGTH, GL_LINK_STATUS, GL_VALIDATE_STATUS, glAttachShader, glCreateProgram,
-    glDeleteProgram, glGetProgramInfoLog, glGetProgramiv, glLinkProgram, glUseProgram,
+    GL_ACTIVE_ATTRIBUTE_MAX_LENGTH, GL_ACTIVE_ATTRIBUTES, GL_ACTIVE_UNIFORM_BLOCK_MAX_NAME_LENGTH,
+    GL_ACTIVE_UNIFORM_BLOCKS, GL_ACTIVE_UNIFORM_MAX_LENGTH, GL_ACTIVE_UNIFORMS, GL_INFO_LOG_LENGTH,
+    GL_LINK_STATUS, GL_UNIFORM_BLOCK_BINDING, GL_UNIFORM_BLOCK_DATA_SIZE, GL_VALIDATE_STATUS,
+    GLchar, GLenum, GLint, GLsizei, GLuint, glAttachShader, glCreateProgram, glDeleteProgram,
+    glGetActiveAttrib, glGetActiveUniform, glGetActiveUniformBlockName, glGetActiveUniformBlockiv,
+    glGetAttribLocation, glGetProgramInfoLog, glGetProgramiv, glLinkProgram, glUseProgram,
     glValidateProgram,
     shader::Shader,
     uniform::{UniformLocation, UniformLocationError, uniform_trait::Uniform},
 };
 
-pub struct Program(pub u32);
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActiveUniform {
+    /// As reported by GL, so arrays end in `[0]`.
+    pub name: String,
+    pub type_: GLenum,
+    /// Number of array elements, 1 for non-arrays.
+    pub size: GLint,
+    /// `None` for members of uniform blocks.
+    pub location: Option<UniformLocation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActiveAttribute {
+    pub name: String,
+    pub type_: GLenum,
+    pub size: GLint,
+    pub location: GLint,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UniformBlock {
+    pub name: String,
+    pub index: GLuint,
+    /// Size of the block's storage in bytes.
+    pub size: GLint,
+    /// Binding point at link time.
+    pub binding: GLuint,
+}
+
+/// What setting a uniform the linker removed, or that never existed, does.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnusedUniforms {
+    #[default]
+    Error,
+    Ignore,
+}
+
+/// A linked program together with its active uniforms, attributes and
+/// uniform blocks, queried once after linking.
+#[derive(Debug)]
+pub struct Program {
+    id: u32,
+    uniforms: Vec<ActiveUniform>,
+    attributes: Vec<ActiveAttribute>,
+    blocks: Vec<UniformBlock>,
+    locations: HashMap<String, (UniformLocation, GLenum)>,
+    unused: UnusedUniforms,
+}
 
 impl Program {
     pub fn from_shaders(shaders: &[Shader]) -> Result<Self, String> {
@@ -49,7 +102,102 @@ impl Program {
             }
             return Err(error_msg);
         }
-        Ok(Program(id))
+
+        let uniforms = active_uniforms(id);
+        let locations = index_locations(&uniforms, |name| {
+            UniformLocation::query(id, name).ok().flatten()
+        });
+        Ok(Self {
+            id,
+            uniforms,
+            attributes: active_attributes(id),
+            blocks: uniform_blocks(id),
+            locations,
+            unused: UnusedUniforms::default(),
+        })
+    }
+
+    /// A program without a GL object, holding only the given uniform tables.
+    #[cfg(test)]
+    pub(crate) fn from_uniforms(
+        uniforms: Vec<ActiveUniform>,
+        locations: HashMap<String, (UniformLocation, GLenum)>,
+    ) -> Self {
+        Self {
+            id: 0,
+            uniforms,
+            attributes: Vec::new(),
+            blocks: Vec::new(),
+            locations,
+            unused: UnusedUniforms::default(),
+        }
+    }
+
+    #[inline]
+    pub const fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn with_unused_uniforms(mut self, unused: UnusedUniforms) -> Self {
+        self.unused = unused;
+        self
+    }
+
+    #[inline]
+    pub fn set_unused_uniforms(&mut self, unused: UnusedUniforms) {
+        self.unused = unused;
+    }
+
+    #[inline]
+    pub fn uniforms(&self) -> &[ActiveUniform] {
+        &self.uniforms
+    }
+
+    #[inline]
+    pub fn attributes(&self) -> &[ActiveAttribute] {
+        &self.attributes
+    }
+
+    #[inline]
+    pub fn uniform_blocks(&self) -> &[UniformBlock] {
+        &self.blocks
+    }
+
+    pub fn attribute_location(&self, name: &str) -> Option<GLint> {
+        self.attributes
+            .iter()
+            .find(|a| a.name == name)
+            .map(|a| a.location)
+    }
+
+    /// Whether `name` is an active uniform or an element of one.
+    #[inline]
+    pub fn has_uniform(&self, name: &str) -> bool {
+        self.locations.contains_key(name)
+    }
+
+    /// The cached location of `name`, checked against the GLSL type. Array
+    /// elements can be named `name[i]`. `None` means the uniform is unused
+    /// and [`UnusedUniforms::Ignore`] is set.
+    pub fn uniform_location<U: Uniform>(
+        &self,
+        name: &str,
+    ) -> Result<Option<UniformLocation>, UniformLocationError> {
+        match self.locations.get(name) {
+            Some(&(location, gl_type)) if U::accepts(gl_type) => Ok(Some(location)),
+            Some(&(_, gl_type)) => Err(UniformLocationError::TypeMismatch {
+                name: String::from(name),
+                gl_type,
+                value_type: std::any::type_name::<U>(),
+            }),
+            None => match self.unused {
+                UnusedUniforms::Error => Err(UniformLocationError::UnusedUniform {
+                    id: self.id,
+                    name: String::from(name),
+                }),
+                UnusedUniforms::Ignore => Ok(None),
+            },
+        }
     }
 
     pub fn uniform<U: Uniform>(
@@ -57,9 +205,9 @@ impl Program {
         name: impl AsRef<str>,
         value: U,
     ) -> Result<(), UniformLocationError> {
-        let name = name.as_ref();
-        let loc = UniformLocation::try_for_program(self, name)?;
-        UniformLocation::provide(&loc, value);
+        if let Some(loc) = self.uniform_location::<U>(name.as_ref())? {
+            UniformLocation::provide(&loc, value);
+        }
         Ok(())
     }
     pub fn uniform_opt<U: Uniform>(
@@ -68,16 +216,16 @@ impl Program {
         value: U,
         opt: U::Options,
     ) -> Result<(), UniformLocationError> {
-        let name = name.as_ref();
-        let loc = UniformLocation::try_for_program(self, name)?;
-        UniformLocation::provide_opt(&loc, value, opt);
+        if let Some(loc) = self.uniform_location::<U>(name.as_ref())? {
+            UniformLocation::provide_opt(&loc, value, opt);
+        }
         Ok(())
     }
 
     #[inline]
     pub fn bind(&self) {
         unsafe {
-            glUseProgram(self.0);
+            glUseProgram(self.id);
         }
     }
 
@@ -88,3 +236,196 @@ impl Program {
         }
     }
 }
+
+fn program_iv(id: u32, pname: GLenum) -> GLint {
+    let mut value = 0;
+    unsafe { glGetProgramiv(id, pname, &mut value) };
+    value
+}
+
+/// Reads a name through `get`, which receives the buffer size, a length out
+/// pointer and the buffer.
+fn read_name(max_length: GLint, get: impl FnOnce(GLsizei, *mut GLsizei, *mut GLchar)) -> String {
+    let mut name = vec![0u8; max_length.max(1) as usize];
+    let mut length = 0;
+    get(name.len() as _, &mut length, name.as_mut_ptr() as _);
+    name.truncate(length.max(0) as usize);
+    String::from_utf8_lossy(&name).into_owned()
+}
+
+fn active_uniforms(id: u32) -> Vec<ActiveUniform> {
+    let max_length = program_iv(id, GL_ACTIVE_UNIFORM_MAX_LENGTH);
+    (0..program_iv(id, GL_ACTIVE_UNIFORMS) as GLuint)
+        .map(|index| {
+            let (mut size, mut type_) = (0, 0);
+            let name = read_name(max_length, |buf_size, length, name| unsafe {
+                glGetActiveUniform(id, index, buf_size, length, &mut size, &mut type_, name);
+            });
+            let location = UniformLocation::query(id, &name).ok().flatten();
+            ActiveUniform {
+                name,
+                type_,
+                size,
+                location,
+            }
+        })
+        .collect()
+}
+
+fn active_attributes(id: u32) -> Vec<ActiveAttribute> {
+    let max_length = program_iv(id, GL_ACTIVE_ATTRIBUTE_MAX_LENGTH);
+    (0..program_iv(id, GL_ACTIVE_ATTRIBUTES) as GLuint)
+        .map(|index| {
+            let (mut size, mut type_) = (0, 0);
+            let name = read_name(max_length, |buf_size, length, name| unsafe {
+                glGetActiveAttrib(id, index, buf_size, length, &mut size, &mut type_, name);
+            });
+            let location = ffi::CString::new(name.as_str())
+                .map_or(-1, |c| unsafe { glGetAttribLocation(id, c.as_ptr() as _) });
+            ActiveAttribute {
+                name,
+                type_,
+                size,
+                location,
+            }
+        })
+        .collect()
+}
+
+fn uniform_blocks(id: u32) -> Vec<UniformBlock> {
+    let max_length = program_iv(id, GL_ACTIVE_UNIFORM_BLOCK_MAX_NAME_LENGTH);
+    (0..program_iv(id, GL_ACTIVE_UNIFORM_BLOCKS) as GLuint)
+        .map(|index| {
+            let block_iv = |pname| {
+                let mut value = 0;
+                unsafe { glGetActiveUniformBlockiv(id, index, pname, &mut value) };
+                value
+            };
+            UniformBlock {
+                name: read_name(max_length, |buf_size, length, name| unsafe {
+                    glGetActiveUniformBlockName(id, index, buf_size, length, name);
+                }),
+                index,
+                size: block_iv(GL_UNIFORM_BLOCK_DATA_SIZE),
+                binding: block_iv(GL_UNIFORM_BLOCK_BINDING) as GLuint,
+            }
+        })
+        .collect()
+}
+
+/// Maps every settable name to its location and type. Arrays are reachable
+/// as `name`, `name[0]` and `name[i]`, the later elements located through
+/// `element_location`.
+fn index_locations(
+    uniforms: &[ActiveUniform],
+    element_location: impl Fn(&str) -> Option<UniformLocation>,
+) -> HashMap<String, (UniformLocation, GLenum)> {
+    let mut locations = HashMap::new();
+    for uniform in uniforms {
+        let Some(location) = uniform.location else {
+            continue;
+        };
+        locations.insert(uniform.name.clone(), (location, uniform.type_));
+        if let Some(base) = uniform.name.strip_suffix("[0]") {
+            locations.insert(String::from(base), (location, uniform.type_));
+            for i in 1..uniform.size {
+                let element = format!("{base}[{i}]");
+                if let Some(location) = element_location(&element) {
+                    locations.insert(element, (location, uniform.type_));
+                }
+            }
+        }
+    }
+    locations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GL_FLOAT, GL_FLOAT_VEC3, GL_SAMPLER_2D};
+
+    fn program(unused: UnusedUniforms) -> Program {
+        let uniforms = vec![
+            ActiveUniform {
+                name: String::from("intensity"),
+                type_: GL_FLOAT,
+                size: 1,
+                location: Some(UniformLocation(0)),
+            },
+            ActiveUniform {
+                name: String::from("weights[0]"),
+                type_: GL_FLOAT,
+                size: 3,
+                location: Some(UniformLocation(1)),
+            },
+            ActiveUniform {
+                name: String::from("diffuse"),
+                type_: GL_SAMPLER_2D,
+                size: 1,
+                location: Some(UniformLocation(4)),
+            },
+            ActiveUniform {
+                name: String::from("camera_pos"),
+                type_: GL_FLOAT_VEC3,
+                size: 1,
+                location: None,
+            },
+        ];
+        let locations = index_locations(&uniforms, |name| match name {
+            "weights[1]" => Some(UniformLocation(2)),
+            "weights[2]" => Some(UniformLocation(3)),
+            _ => None,
+        });
+        Program::from_uniforms(uniforms, locations).with_unused_uniforms(unused)
+    }
+
+    #[test]
+    fn test_program_uniform_location_cached() {
+        let program = program(UnusedUniforms::Error);
+        let location = |name| program.uniform_location::<f32>(name).unwrap();
+        assert_eq!(location("intensity"), Some(UniformLocation(0)));
+        assert_eq!(location("weights"), Some(UniformLocation(1)));
+        assert_eq!(location("weights[0]"), Some(UniformLocation(1)));
+        assert_eq!(location("weights[2]"), Some(UniformLocation(3)));
+        assert_eq!(
+            program.uniform_location::<i32>("diffuse").unwrap(),
+            Some(UniformLocation(4))
+        );
+    }
+
+    #[test]
+    fn test_program_uniform_type_mismatch() {
+        let program = program(UnusedUniforms::Ignore);
+        let err = program.uniform_location::<i32>("intensity").unwrap_err();
+        assert!(matches!(
+            err,
+            UniformLocationError::TypeMismatch {
+                gl_type: GL_FLOAT,
+                ..
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "Uniform intensity of type float cannot be set from i32"
+        );
+        assert!(program.uniform_location::<f32>("diffuse").is_err());
+    }
+
+    #[test]
+    fn test_program_unused_uniforms() {
+        let strict = program(UnusedUniforms::Error);
+        assert!(matches!(
+            strict.uniform_location::<f32>("missing"),
+            Err(UniformLocationError::UnusedUniform { .. })
+        ));
+        // Block members have no location of their own.
+        assert!(strict.uniform_location::<f32>("camera_pos").is_err());
+        assert!(strict.has_uniform("weights[1]"));
+        assert!(!strict.has_uniform("normalmap"));
+        assert!(!strict.has_uniform("camera_pos"));
+
+        let lenient = program(UnusedUniforms::Ignore);
+        assert_eq!(lenient.uniform_location::<f32>("missing").unwrap(), None);
+        assert!(lenient.uniform(String::from("missing"), 1.0f32).is_ok());
+    }
+}
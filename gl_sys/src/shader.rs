@@ -1,18 +1,27 @@
 use std::{ffi, fmt, fs, io, path::Path, ptr};
 
-use crate::*;
+use crate::{
+    gl43::GL_COMPUTE_SHADER,
+    version::{Feature, Version, VersionError},
+    *,
+};
 
 #[derive(Debug)]
 pub enum ShaderError {
     FileSystemError(io::Error),
     FFIError(ffi::NulError),
     CompilationError(String),
+    Unsupported(VersionError),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ShaderType {
     Fragment,
     Vertex,
+    Geometry,
+    TessControl,
+    TessEvaluation,
+    Compute,
 }
 
 impl ShaderType {
@@ -20,6 +29,20 @@ impl ShaderType {
         match self {
             Self::Fragment => GL_FRAGMENT_SHADER,
             Self::Vertex => GL_VERTEX_SHADER,
+            Self::Geometry => GL_GEOMETRY_SHADER,
+            Self::TessControl => GL_TESS_CONTROL_SHADER,
+            Self::TessEvaluation => GL_TESS_EVALUATION_SHADER,
+            Self::Compute => GL_COMPUTE_SHADER,
+        }
+    }
+
+    /// The feature the context must support for this stage, if any.
+    pub const fn feature(&self) -> Option<Feature> {
+        match self {
+            Self::Fragment | Self::Vertex => None,
+            Self::Geometry => Some(Feature::GeometryShader),
+            Self::TessControl | Self::TessEvaluation => Some(Feature::TessellationShader),
+            Self::Compute => Some(Feature::ComputeShader),
         }
     }
 }
@@ -38,12 +61,20 @@ impl From<ffi::NulError> for ShaderError {
     }
 }
 
+impl From<VersionError> for ShaderError {
+    #[inline]
+    fn from(value: VersionError) -> Self {
+        Self::Unsupported(value)
+    }
+}
+
 impl fmt::Display for ShaderError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::FileSystemError(e) => fmt::Display::fmt(e, f),
             Self::FFIError(e) => fmt::Display::fmt(e, f),
             Self::CompilationError(m) => write!(f, "{}", m),
+            Self::Unsupported(e) => fmt::Display::fmt(e, f),
         }
     }
 }
@@ -63,6 +94,9 @@ impl Shader {
     where
         B: Into<Vec<u8>>,
     {
+        if let Some(feature) = shader_type.feature() {
+            Version::require(feature)?;
+        }
         let shader = unsafe { glCreateShader(shader_type.key()) };
         let c_str = ffi::CString::new(source)?;
         let c_str_ptr = c_str.as_ptr();
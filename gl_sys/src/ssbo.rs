@@ -0,0 +1,194 @@
+use std::{fmt, marker::PhantomData};
+
+use crate::{
+    GLError, GLenum, GLint, GLintptr, GLsizeiptr, GLuint, get_error,
+    gl43::{GL_MAX_SHADER_STORAGE_BUFFER_BINDINGS, GL_SHADER_STORAGE_BUFFER},
+    glBindBufferBase, glBufferSubData, glGetBufferSubData, glGetIntegerv,
+    vbo::{Pod, VertexBufferObject, VertexBufferRef},
+    version::{Feature, Version, VersionError},
+};
+
+/// Owning shader storage buffer holding an array of `T`, bound to a fixed
+/// binding point (`layout(binding = N) buffer` in GLSL).
+///
+/// `T` must match the std430 layout of the GLSL element type; a `vec3`
+/// member occupies 16 bytes, so pad it or use `vec4`.
+#[derive(Debug)]
+pub struct ShaderStorageBuffer<T> {
+    buffer: VertexBufferObject,
+    binding: GLuint,
+    len: usize,
+    /// Whether the buffer has a data store yet; an empty first upload still
+    /// needs one before `bind_base`.
+    allocated: bool,
+    usage: GLenum,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Pod> ShaderStorageBuffer<T> {
+    pub fn new(binding: GLuint, data: &[T], usage: GLenum) -> Result<Self, StorageBufferError> {
+        Version::require(Feature::ShaderStorageBuffer)?;
+        let mut max = 0;
+        unsafe { glGetIntegerv(GL_MAX_SHADER_STORAGE_BUFFER_BINDINGS, &mut max) };
+        if binding >= max as GLuint {
+            return Err(StorageBufferError::BindingOutOfRange { binding, max });
+        }
+
+        let mut ssbo = Self {
+            buffer: VertexBufferObject::gen_buffers(),
+            binding,
+            len: 0,
+            allocated: false,
+            usage,
+            _marker: PhantomData,
+        };
+        ssbo.upload(data)?;
+        ssbo.bind_base();
+        Ok(ssbo)
+    }
+
+    /// A buffer of `len` zeroed elements, e.g. for compute shader output.
+    pub fn zeroed(binding: GLuint, len: usize, usage: GLenum) -> Result<Self, StorageBufferError> {
+        Self::new(binding, &vec![T::zeroed(); len], usage)
+    }
+
+    #[inline]
+    pub const fn binding(&self) -> GLuint {
+        self.binding
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub fn handle(&self) -> VertexBufferRef<'_> {
+        self.buffer.handle()
+    }
+
+    #[inline]
+    pub fn bind_base(&self) {
+        unsafe {
+            glBindBufferBase(GL_SHADER_STORAGE_BUFFER, self.binding, self.buffer.id());
+        }
+    }
+
+    /// Replaces the contents, reallocating when the length changes.
+    pub fn upload(&mut self, data: &[T]) -> Result<(), StorageBufferError> {
+        VertexBufferObject::bind_buffer(GL_SHADER_STORAGE_BUFFER, &self.buffer);
+        if !self.reallocates(data.len()) {
+            let bytes: &[u8] = bytemuck::cast_slice(data);
+            unsafe {
+                glBufferSubData(
+                    GL_SHADER_STORAGE_BUFFER,
+                    0,
+                    bytes.len() as GLsizeiptr,
+                    bytes.as_ptr() as _,
+                );
+            }
+        } else {
+            VertexBufferObject::buffer_data(GL_SHADER_STORAGE_BUFFER, data, self.usage)
+                .map_err(|_| StorageBufferError::CastError)?;
+            self.len = data.len();
+            self.allocated = true;
+        }
+        VertexBufferObject::bind_buffer(GL_SHADER_STORAGE_BUFFER, VertexBufferRef::zero());
+        get_error()?;
+        Ok(())
+    }
+
+    /// Whether uploading `len` elements needs a new data store instead of
+    /// overwriting the current one.
+    #[inline]
+    const fn reallocates(&self, len: usize) -> bool {
+        !self.allocated || len != self.len
+    }
+
+    /// Copies the contents back. Writes from shaders are only visible after
+    /// a [`memory_barrier`](crate::compute::ComputeProgram::memory_barrier)
+    /// with `BUFFER_UPDATE`.
+    pub fn read(&self) -> Result<Vec<T>, StorageBufferError> {
+        let mut data = vec![T::zeroed(); self.len];
+        let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut data);
+        VertexBufferObject::bind_buffer(GL_SHADER_STORAGE_BUFFER, &self.buffer);
+        unsafe {
+            glGetBufferSubData(
+                GL_SHADER_STORAGE_BUFFER,
+                0 as GLintptr,
+                bytes.len() as GLsizeiptr,
+                bytes.as_mut_ptr() as _,
+            );
+        }
+        VertexBufferObject::bind_buffer(GL_SHADER_STORAGE_BUFFER, VertexBufferRef::zero());
+        get_error()?;
+        Ok(data)
+    }
+}
+
+#[derive(Debug)]
+pub enum StorageBufferError {
+    Unsupported(VersionError),
+    BindingOutOfRange { binding: GLuint, max: GLint },
+    CastError,
+    GlError(GLError),
+}
+
+impl fmt::Display for StorageBufferError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsupported(e) => fmt::Display::fmt(e, f),
+            Self::BindingOutOfRange { binding, max } => write!(
+                f,
+                "Shader storage buffer binding {} exceeds the maximum of {}",
+                binding, max
+            ),
+            Self::CastError => write!(f, "Failed to cast value"),
+            Self::GlError(e) => fmt::Display::fmt(e, f),
+        }
+    }
+}
+
+impl From<VersionError> for StorageBufferError {
+    fn from(value: VersionError) -> Self {
+        Self::Unsupported(value)
+    }
+}
+
+impl From<GLError> for StorageBufferError {
+    fn from(value: GLError) -> Self {
+        Self::GlError(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ssbo_reallocates() {
+        let mut ssbo = ShaderStorageBuffer::<u32> {
+            buffer: VertexBufferObject::default(),
+            binding: 0,
+            len: 0,
+            allocated: false,
+            usage: 0,
+            _marker: PhantomData,
+        };
+        // The first upload allocates, even when empty.
+        assert!(ssbo.reallocates(0));
+
+        ssbo.allocated = true;
+        assert!(!ssbo.reallocates(0));
+        assert!(ssbo.reallocates(4));
+
+        ssbo.len = 4;
+        assert!(!ssbo.reallocates(4));
+        assert!(ssbo.reallocates(3));
+    }
+}
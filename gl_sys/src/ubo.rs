@@ -0,0 +1,522 @@
+use std::{ffi, fmt, marker::PhantomData};
+
+use crate::{
+    GL_ACTIVE_UNIFORM_MAX_LENGTH, GL_DYNAMIC_DRAW, GL_INVALID_INDEX,
+    GL_MAX_UNIFORM_BUFFER_BINDINGS, GL_UNIFORM_BLOCK_ACTIVE_UNIFORM_INDICES,
+    GL_UNIFORM_BLOCK_ACTIVE_UNIFORMS, GL_UNIFORM_BLOCK_DATA_SIZE, GL_UNIFORM_BUFFER,
+    GL_UNIFORM_OFFSET, GLError, GLint, GLsizei, GLsizeiptr, GLuint, get_error, glBindBufferBase,
+    glBufferData, glBufferSubData, glGetActiveUniformBlockiv, glGetActiveUniformName,
+    glGetActiveUniformsiv, glGetIntegerv, glGetProgramiv, glGetUniformBlockIndex,
+    glUniformBlockBinding,
+    program::Program,
+    vbo::{VertexBufferObject, VertexBufferRef},
+};
+
+/// A value with a std140 base alignment and size, in bytes.
+pub trait Std140 {
+    const ALIGN: usize;
+    const SIZE: usize;
+
+    /// Writes the value into `out`, which is exactly `Self::SIZE` bytes and
+    /// zeroed where the layout has padding.
+    fn write_std140(&self, out: &mut [u8]);
+}
+
+/// A struct usable as the contents of a uniform block, usually declared
+/// with [`std140!`](crate::std140).
+pub trait Std140Block: Std140 {
+    const FIELDS: &'static [Field];
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Field {
+    pub name: &'static str,
+    pub offset: usize,
+    pub size: usize,
+}
+
+pub const fn align_up(n: usize, align: usize) -> usize {
+    n.div_ceil(align) * align
+}
+
+/// Lays out `(name, align, size)` members in order following the std140
+/// rules for structure members.
+pub const fn layout<const N: usize>(members: [(&'static str, usize, usize); N]) -> [Field; N] {
+    let mut fields = [Field {
+        name: "",
+        offset: 0,
+        size: 0,
+    }; N];
+    let mut end = 0;
+    let mut i = 0;
+    while i < N {
+        let (name, align, size) = members[i];
+        let offset = align_up(end, align);
+        fields[i] = Field { name, offset, size };
+        end = offset + size;
+        i += 1;
+    }
+    fields
+}
+
+/// Base alignment of a structure: its largest member alignment, rounded up
+/// to that of a `vec4`.
+pub const fn struct_align(aligns: &[usize]) -> usize {
+    let mut max = 16;
+    let mut i = 0;
+    while i < aligns.len() {
+        if aligns[i] > max {
+            max = aligns[i];
+        }
+        i += 1;
+    }
+    align_up(max, 16)
+}
+
+pub const fn struct_size(fields: &[Field], align: usize) -> usize {
+    match fields.last() {
+        Some(last) => align_up(last.offset + last.size, align),
+        None => 0,
+    }
+}
+
+macro_rules! impl_std140_scalar {
+    ($($ty:ty),*) => {$(
+        impl Std140 for $ty {
+            const ALIGN: usize = 4;
+            const SIZE: usize = 4;
+
+            fn write_std140(&self, out: &mut [u8]) {
+                out.copy_from_slice(&self.to_ne_bytes());
+            }
+        }
+    )*};
+}
+
+impl_std140_scalar!(f32, i32, u32);
+
+/// GLSL arrays; every element is padded to a multiple of 16 bytes.
+impl<T: Std140, const N: usize> Std140 for [T; N] {
+    const ALIGN: usize = align_up(T::ALIGN, 16);
+    const SIZE: usize = align_up(T::SIZE, Self::ALIGN) * N;
+
+    fn write_std140(&self, out: &mut [u8]) {
+        let stride = align_up(T::SIZE, Self::ALIGN);
+        for (element, out) in self.iter().zip(out.chunks_exact_mut(stride)) {
+            element.write_std140(&mut out[..T::SIZE]);
+        }
+    }
+}
+
+/// Declares a struct implementing [`Std140Block`] with the std140 layout of
+/// its fields. Field names must match the members of the GLSL block.
+///
+/// ```ignore
+/// gl_sys::std140! {
+///     #[derive(Debug, Clone, Copy)]
+///     pub struct Lights {
+///         pub position: Vec3<f32>,
+///         pub intensity: f32,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! std140 {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($field_vis:vis $field:ident: $ty:ty),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $($field_vis $field: $ty),*
+        }
+
+        impl $crate::ubo::Std140 for $name {
+            const ALIGN: usize =
+                $crate::ubo::struct_align(&[$(<$ty as $crate::ubo::Std140>::ALIGN),*]);
+            const SIZE: usize = $crate::ubo::struct_size(
+                <Self as $crate::ubo::Std140Block>::FIELDS,
+                <Self as $crate::ubo::Std140>::ALIGN,
+            );
+
+            fn write_std140(&self, out: &mut [u8]) {
+                let mut fields = <Self as $crate::ubo::Std140Block>::FIELDS.iter();
+                $(
+                    let field = fields.next().unwrap();
+                    $crate::ubo::Std140::write_std140(
+                        &self.$field,
+                        &mut out[field.offset..field.offset + field.size],
+                    );
+                )*
+            }
+        }
+
+        impl $crate::ubo::Std140Block for $name {
+            const FIELDS: &'static [$crate::ubo::Field] = &$crate::ubo::layout([$((
+                stringify!($field),
+                <$ty as $crate::ubo::Std140>::ALIGN,
+                <$ty as $crate::ubo::Std140>::SIZE,
+            )),*]);
+        }
+    };
+}
+
+/// Owning uniform buffer holding one `T`, bound to a fixed binding point
+/// that programs attach their blocks to.
+#[derive(Debug)]
+pub struct UniformBuffer<T> {
+    buffer: VertexBufferObject,
+    binding: GLuint,
+    data: Vec<u8>,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Std140Block> UniformBuffer<T> {
+    pub fn new(binding: GLuint) -> Result<Self, UniformBufferError> {
+        let mut max = 0;
+        unsafe { glGetIntegerv(GL_MAX_UNIFORM_BUFFER_BINDINGS, &mut max) };
+        if binding >= max as GLuint {
+            return Err(UniformBufferError::BindingOutOfRange { binding, max });
+        }
+        let size = GLsizeiptr::try_from(T::SIZE).map_err(|_| UniformBufferError::CastError)?;
+
+        let buffer = VertexBufferObject::gen_buffers();
+        VertexBufferObject::bind_buffer(GL_UNIFORM_BUFFER, &buffer);
+        unsafe {
+            glBufferData(GL_UNIFORM_BUFFER, size, std::ptr::null(), GL_DYNAMIC_DRAW);
+        }
+        VertexBufferObject::bind_buffer(GL_UNIFORM_BUFFER, VertexBufferRef::zero());
+        get_error()?;
+
+        let ubo = Self {
+            buffer,
+            binding,
+            data: vec![0; T::SIZE],
+            _marker: PhantomData,
+        };
+        ubo.bind_base();
+        Ok(ubo)
+    }
+
+    #[inline]
+    pub const fn binding(&self) -> GLuint {
+        self.binding
+    }
+
+    #[inline]
+    pub fn handle(&self) -> VertexBufferRef<'_> {
+        self.buffer.handle()
+    }
+
+    /// Binds the buffer to its binding point, e.g. after another buffer
+    /// took it over.
+    #[inline]
+    pub fn bind_base(&self) {
+        unsafe {
+            glBindBufferBase(GL_UNIFORM_BUFFER, self.binding, self.buffer.id());
+        }
+    }
+
+    pub fn upload(&mut self, value: &T) {
+        value.write_std140(&mut self.data);
+        VertexBufferObject::bind_buffer(GL_UNIFORM_BUFFER, &self.buffer);
+        unsafe {
+            glBufferSubData(
+                GL_UNIFORM_BUFFER,
+                0,
+                self.data.len() as _,
+                self.data.as_ptr() as _,
+            );
+        }
+        VertexBufferObject::bind_buffer(GL_UNIFORM_BUFFER, VertexBufferRef::zero());
+    }
+
+    /// Checks `block` of `program` against the layout of `T` and points it
+    /// at this buffer's binding.
+    pub fn attach(&self, program: &Program, block: &str) -> Result<(), UniformBufferError> {
+        let index = check_layout::<T>(program, block)?;
+        unsafe {
+            glUniformBlockBinding(program.id(), index, self.binding);
+        }
+        get_error()?;
+        Ok(())
+    }
+}
+
+/// Compares the offsets and size the driver reports for `block` with the
+/// std140 layout of `T`, returning the block index.
+pub fn check_layout<T: Std140Block>(
+    program: &Program,
+    block: &str,
+) -> Result<GLuint, UniformBufferError> {
+    let id = program.id();
+    let block_cstr = ffi::CString::new(block)?;
+    let index = unsafe { glGetUniformBlockIndex(id, block_cstr.as_ptr() as _) };
+    if index == GL_INVALID_INDEX {
+        return Err(UniformBufferError::UnknownBlock(String::from(block)));
+    }
+
+    let block_iv = |pname| {
+        let mut value = 0;
+        unsafe { glGetActiveUniformBlockiv(id, index, pname, &mut value) };
+        value
+    };
+
+    let actual = block_iv(GL_UNIFORM_BLOCK_DATA_SIZE) as usize;
+    let end = T::FIELDS.last().map_or(0, |f| f.offset + f.size);
+    if actual < end || actual > align_up(end, 16) {
+        return Err(UniformBufferError::SizeMismatch {
+            block: String::from(block),
+            expected: T::SIZE,
+            actual,
+        });
+    }
+
+    let count = block_iv(GL_UNIFORM_BLOCK_ACTIVE_UNIFORMS);
+    let mut indices = vec![0 as GLint; count as usize];
+    unsafe {
+        glGetActiveUniformBlockiv(
+            id,
+            index,
+            GL_UNIFORM_BLOCK_ACTIVE_UNIFORM_INDICES,
+            indices.as_mut_ptr(),
+        );
+    }
+    let indices: Vec<GLuint> = indices.into_iter().map(|i| i as GLuint).collect();
+    let mut offsets = vec![0 as GLint; indices.len()];
+    unsafe {
+        glGetActiveUniformsiv(
+            id,
+            count,
+            indices.as_ptr(),
+            GL_UNIFORM_OFFSET,
+            offsets.as_mut_ptr(),
+        );
+    }
+
+    let mut max_length = 0;
+    unsafe { glGetProgramiv(id, GL_ACTIVE_UNIFORM_MAX_LENGTH, &mut max_length) };
+    let mut name = vec![0u8; max_length.max(1) as usize];
+    for (&uniform, &offset) in indices.iter().zip(&offsets) {
+        let mut length: GLsizei = 0;
+        unsafe {
+            glGetActiveUniformName(
+                id,
+                uniform,
+                name.len() as _,
+                &mut length,
+                name.as_mut_ptr() as _,
+            );
+        }
+        let name = String::from_utf8_lossy(&name[..length as usize]);
+        let member = member_name(block, &name);
+        // Members of nested structs report their own offsets; only top
+        // level members are compared.
+        if member.contains(['.', '[']) {
+            continue;
+        }
+        let field = T::FIELDS.iter().find(|f| f.name == member).ok_or_else(|| {
+            UniformBufferError::UnknownMember {
+                block: String::from(block),
+                member: String::from(member),
+            }
+        })?;
+        if field.offset != offset as usize {
+            return Err(UniformBufferError::OffsetMismatch {
+                block: String::from(block),
+                member: String::from(member),
+                expected: field.offset,
+                actual: offset as usize,
+            });
+        }
+    }
+    get_error()?;
+    Ok(index)
+}
+
+/// Strips the `Block.` prefix of instanced blocks and the `[0]` suffix of
+/// arrays.
+fn member_name<'a>(block: &str, name: &'a str) -> &'a str {
+    let name = name
+        .strip_prefix(block)
+        .and_then(|n| n.strip_prefix('.'))
+        .unwrap_or(name);
+    name.strip_suffix("[0]").unwrap_or(name)
+}
+
+#[derive(Debug)]
+pub enum UniformBufferError {
+    UnknownBlock(String),
+    UnknownMember {
+        block: String,
+        member: String,
+    },
+    SizeMismatch {
+        block: String,
+        expected: usize,
+        actual: usize,
+    },
+    OffsetMismatch {
+        block: String,
+        member: String,
+        expected: usize,
+        actual: usize,
+    },
+    BindingOutOfRange {
+        binding: GLuint,
+        max: GLint,
+    },
+    CastError,
+    FFIError(ffi::NulError),
+    GlError(GLError),
+}
+
+impl fmt::Display for UniformBufferError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownBlock(block) => write!(f, "Uniform block {} not found", block),
+            Self::UnknownMember { block, member } => {
+                write!(f, "Uniform block {} has no field for {}", block, member)
+            }
+            Self::SizeMismatch {
+                block,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Uniform block {} is {} bytes, expected {}",
+                block, actual, expected
+            ),
+            Self::OffsetMismatch {
+                block,
+                member,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Member {} of uniform block {} is at offset {}, expected {}",
+                member, block, actual, expected
+            ),
+            Self::BindingOutOfRange { binding, max } => write!(
+                f,
+                "Uniform buffer binding {} exceeds the maximum of {}",
+                binding, max
+            ),
+            Self::CastError => write!(f, "Failed to cast value"),
+            Self::FFIError(e) => fmt::Display::fmt(e, f),
+            Self::GlError(e) => fmt::Display::fmt(e, f),
+        }
+    }
+}
+
+impl From<GLError> for UniformBufferError {
+    fn from(value: GLError) -> Self {
+        Self::GlError(value)
+    }
+}
+
+impl From<ffi::NulError> for UniformBufferError {
+    fn from(value: ffi::NulError) -> Self {
+        Self::FFIError(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Vec3([f32; 3]);
+
+    impl Std140 for Vec3 {
+        const ALIGN: usize = 16;
+        const SIZE: usize = 12;
+
+        fn write_std140(&self, out: &mut [u8]) {
+            self.0
+                .iter()
+                .zip(out.chunks_exact_mut(4))
+                .for_each(|(c, out)| {
+                    out.copy_from_slice(&c.to_ne_bytes());
+                });
+        }
+    }
+
+    crate::std140! {
+        struct Inner {
+            a: f32,
+            b: Vec3,
+        }
+    }
+
+    crate::std140! {
+        struct Block {
+            intensity: f32,
+            position: Vec3,
+            packed: f32,
+            weights: [f32; 3],
+            inner: Inner,
+            count: i32,
+        }
+    }
+
+    fn offsets<T: Std140Block>() -> Vec<(&'static str, usize)> {
+        T::FIELDS.iter().map(|f| (f.name, f.offset)).collect()
+    }
+
+    #[test]
+    fn test_std140_layout() {
+        assert_eq!(offsets::<Inner>(), [("a", 0), ("b", 16)]);
+        assert_eq!((Inner::ALIGN, Inner::SIZE), (16, 32));
+        assert_eq!(<[f32; 3]>::SIZE, 48);
+        assert_eq!(
+            offsets::<Block>(),
+            [
+                ("intensity", 0),
+                ("position", 16),
+                ("packed", 28),
+                ("weights", 32),
+                ("inner", 80),
+                ("count", 112),
+            ]
+        );
+        assert_eq!(Block::SIZE, 128);
+    }
+
+    #[test]
+    fn test_std140_write() {
+        let block = Block {
+            intensity: 1.0,
+            position: Vec3([2.0, 3.0, 4.0]),
+            packed: 5.0,
+            weights: [6.0, 7.0, 8.0],
+            inner: Inner {
+                a: 9.0,
+                b: Vec3([10.0, 11.0, 12.0]),
+            },
+            count: -1,
+        };
+        let mut out = vec![0; Block::SIZE];
+        block.write_std140(&mut out);
+        let read = |offset: usize| f32::from_ne_bytes(out[offset..offset + 4].try_into().unwrap());
+        assert_eq!(read(0), 1.0);
+        assert_eq!(read(4), 0.0);
+        assert_eq!(
+            [read(16), read(20), read(24), read(28)],
+            [2.0, 3.0, 4.0, 5.0]
+        );
+        assert_eq!([read(32), read(48), read(64)], [6.0, 7.0, 8.0]);
+        assert_eq!([read(80), read(96), read(104)], [9.0, 10.0, 12.0]);
+        assert_eq!(i32::from_ne_bytes(out[112..116].try_into().unwrap()), -1);
+    }
+
+    #[test]
+    fn test_std140_member_name() {
+        assert_eq!(member_name("Lights", "Lights.color"), "color");
+        assert_eq!(member_name("Lights", "color"), "color");
+        assert_eq!(member_name("Lights", "weights[0]"), "weights");
+        assert_eq!(member_name("Lights", "inner.a"), "inner.a");
+    }
+}
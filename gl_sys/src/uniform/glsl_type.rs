@@ -0,0 +1,104 @@
+//! The type enums `glGetActiveUniform` and `glGetActiveAttrib` report.
+
+use crate::*;
+
+macro_rules! glsl_types {
+    (@sampler sampler) => { true };
+    (@sampler) => { false };
+    ($($key:ident => $name:literal $($sampler:ident)?),* $(,)?) => {
+        /// GLSL spelling of a type enum, e.g. `vec3` for `GL_FLOAT_VEC3`.
+        pub fn name(gl_type: GLenum) -> &'static str {
+            $(if gl_type == $key {
+                return $name;
+            })*
+            "unknown"
+        }
+
+        /// Samplers are set with `glUniform1i` to a texture unit.
+        pub fn is_sampler(gl_type: GLenum) -> bool {
+            $(if gl_type == $key {
+                return glsl_types!(@sampler $($sampler)?);
+            })*
+            false
+        }
+    };
+}
+
+glsl_types!(
+    GL_FLOAT => "float",
+    GL_FLOAT_VEC2 => "vec2",
+    GL_FLOAT_VEC3 => "vec3",
+    GL_FLOAT_VEC4 => "vec4",
+    GL_INT => "int",
+    GL_INT_VEC2 => "ivec2",
+    GL_INT_VEC3 => "ivec3",
+    GL_INT_VEC4 => "ivec4",
+    GL_UNSIGNED_INT => "uint",
+    GL_UNSIGNED_INT_VEC2 => "uvec2",
+    GL_UNSIGNED_INT_VEC3 => "uvec3",
+    GL_UNSIGNED_INT_VEC4 => "uvec4",
+    GL_BOOL => "bool",
+    GL_BOOL_VEC2 => "bvec2",
+    GL_BOOL_VEC3 => "bvec3",
+    GL_BOOL_VEC4 => "bvec4",
+    GL_FLOAT_MAT2 => "mat2",
+    GL_FLOAT_MAT3 => "mat3",
+    GL_FLOAT_MAT4 => "mat4",
+    GL_FLOAT_MAT2x3 => "mat2x3",
+    GL_FLOAT_MAT2x4 => "mat2x4",
+    GL_FLOAT_MAT3x2 => "mat3x2",
+    GL_FLOAT_MAT3x4 => "mat3x4",
+    GL_FLOAT_MAT4x2 => "mat4x2",
+    GL_FLOAT_MAT4x3 => "mat4x3",
+    GL_SAMPLER_1D => "sampler1D" sampler,
+    GL_SAMPLER_2D => "sampler2D" sampler,
+    GL_SAMPLER_3D => "sampler3D" sampler,
+    GL_SAMPLER_CUBE => "samplerCube" sampler,
+    GL_SAMPLER_1D_SHADOW => "sampler1DShadow" sampler,
+    GL_SAMPLER_2D_SHADOW => "sampler2DShadow" sampler,
+    GL_SAMPLER_1D_ARRAY => "sampler1DArray" sampler,
+    GL_SAMPLER_2D_ARRAY => "sampler2DArray" sampler,
+    GL_SAMPLER_1D_ARRAY_SHADOW => "sampler1DArrayShadow" sampler,
+    GL_SAMPLER_2D_ARRAY_SHADOW => "sampler2DArrayShadow" sampler,
+    GL_SAMPLER_2D_MULTISAMPLE => "sampler2DMS" sampler,
+    GL_SAMPLER_2D_MULTISAMPLE_ARRAY => "sampler2DMSArray" sampler,
+    GL_SAMPLER_CUBE_SHADOW => "samplerCubeShadow" sampler,
+    GL_SAMPLER_BUFFER => "samplerBuffer" sampler,
+    GL_SAMPLER_2D_RECT => "sampler2DRect" sampler,
+    GL_SAMPLER_2D_RECT_SHADOW => "sampler2DRectShadow" sampler,
+    GL_INT_SAMPLER_1D => "isampler1D" sampler,
+    GL_INT_SAMPLER_2D => "isampler2D" sampler,
+    GL_INT_SAMPLER_3D => "isampler3D" sampler,
+    GL_INT_SAMPLER_CUBE => "isamplerCube" sampler,
+    GL_INT_SAMPLER_1D_ARRAY => "isampler1DArray" sampler,
+    GL_INT_SAMPLER_2D_ARRAY => "isampler2DArray" sampler,
+    GL_INT_SAMPLER_2D_MULTISAMPLE => "isampler2DMS" sampler,
+    GL_INT_SAMPLER_2D_MULTISAMPLE_ARRAY => "isampler2DMSArray" sampler,
+    GL_INT_SAMPLER_BUFFER => "isamplerBuffer" sampler,
+    GL_INT_SAMPLER_2D_RECT => "isampler2DRect" sampler,
+    GL_UNSIGNED_INT_SAMPLER_1D => "usampler1D" sampler,
+    GL_UNSIGNED_INT_SAMPLER_2D => "usampler2D" sampler,
+    GL_UNSIGNED_INT_SAMPLER_3D => "usampler3D" sampler,
+    GL_UNSIGNED_INT_SAMPLER_CUBE => "usamplerCube" sampler,
+    GL_UNSIGNED_INT_SAMPLER_1D_ARRAY => "usampler1DArray" sampler,
+    GL_UNSIGNED_INT_SAMPLER_2D_ARRAY => "usampler2DArray" sampler,
+    GL_UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE => "usampler2DMS" sampler,
+    GL_UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE_ARRAY => "usampler2DMSArray" sampler,
+    GL_UNSIGNED_INT_SAMPLER_BUFFER => "usamplerBuffer" sampler,
+    GL_UNSIGNED_INT_SAMPLER_2D_RECT => "usampler2DRect" sampler,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glsl_type_name() {
+        assert_eq!(name(GL_FLOAT_VEC3), "vec3");
+        assert_eq!(name(GL_FLOAT_MAT4x3), "mat4x3");
+        assert_eq!(name(0), "unknown");
+        assert!(is_sampler(GL_SAMPLER_2D));
+        assert!(is_sampler(GL_UNSIGNED_INT_SAMPLER_BUFFER));
+        assert!(!is_sampler(GL_INT));
+    }
+}
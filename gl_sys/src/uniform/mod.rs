@@ -3,11 +3,12 @@ use std::{
     fmt::{self, Debug},
 };
 
+pub mod glsl_type;
 pub mod uniform_trait;
 
 use crate::{
     GLError,
-    bindings::{GLint, glGetUniformLocation},
+    bindings::{GLenum, GLint, glGetUniformLocation},
     get_error,
     program::Program,
     uniform::uniform_trait::Uniform,
@@ -17,24 +18,33 @@ use crate::{
 pub struct UniformLocation(pub GLint);
 
 impl UniformLocation {
-    pub fn try_for_program<S>(Program(id): &Program, name: S) -> Result<Self, UniformLocationError>
+    /// Queries the driver directly; [`Program::uniform`] uses the locations
+    /// cached at link time instead.
+    pub fn try_for_program<S>(program: &Program, name: S) -> Result<Self, UniformLocationError>
     where
         S: AsRef<str>,
     {
         let name = name.as_ref();
+        match Self::query(program.id(), name)? {
+            Some(location) => Ok(location),
+            None => Err(UniformLocationError::UnusedUniform {
+                id: program.id(),
+                name: String::from(name),
+            }),
+        }
+    }
+
+    pub(crate) fn query(program: u32, name: &str) -> Result<Option<Self>, UniformLocationError> {
         let name_cstr = ffi::CString::new(name)?;
         let name_ptr = name_cstr.as_ptr() as _;
 
-        let res = unsafe { glGetUniformLocation(*id, name_ptr) };
+        let res = unsafe { glGetUniformLocation(program, name_ptr) };
 
         get_error()?;
 
         match res {
-            -1 => Err(UniformLocationError::UnusedUniform {
-                id: *id,
-                name: String::from(name),
-            }),
-            loc => Ok(Self(loc)),
+            -1 => Ok(None),
+            loc => Ok(Some(Self(loc))),
         }
     }
 
@@ -55,7 +65,15 @@ impl UniformLocation {
 
 #[derive(Debug)]
 pub enum UniformLocationError {
-    UnusedUniform { id: u32, name: String },
+    UnusedUniform {
+        id: u32,
+        name: String,
+    },
+    TypeMismatch {
+        name: String,
+        gl_type: GLenum,
+        value_type: &'static str,
+    },
     FFIError(ffi::NulError),
     GlError(GLError),
 }
@@ -66,6 +84,17 @@ impl fmt::Display for UniformLocationError {
             Self::UnusedUniform { name, id } => {
                 write!(f, "Uniform {} unused in program with id {}", name, id)
             }
+            Self::TypeMismatch {
+                name,
+                gl_type,
+                value_type,
+            } => write!(
+                f,
+                "Uniform {} of type {} cannot be set from {}",
+                name,
+                glsl_type::name(*gl_type),
+                value_type
+            ),
             Self::FFIError(e) => fmt::Display::fmt(e, f),
             Self::GlError(e) => fmt::Display::fmt(e, f),
         }
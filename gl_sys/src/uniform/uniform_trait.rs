@@ -1,17 +1,24 @@
 use crate::{
-    bindings::{GLfloat, GLint, glUniform1f, glUniform1i},
-    uniform::UniformLocation,
+    bindings::{GL_BOOL, GL_FLOAT, GL_INT, GLenum, GLfloat, GLint, glUniform1f, glUniform1i},
+    uniform::{UniformLocation, glsl_type},
 };
 
 pub trait Uniform {
     type Options;
 
+    /// Whether a uniform of GLSL type `gl_type` can be set from this value.
+    fn accepts(gl_type: GLenum) -> bool;
+
     fn set(&self, options: Option<Self::Options>, location: &UniformLocation);
 }
 
 impl Uniform for GLfloat {
     type Options = ();
 
+    fn accepts(gl_type: GLenum) -> bool {
+        gl_type == GL_FLOAT
+    }
+
     fn set(&self, _options: Option<Self::Options>, UniformLocation(location): &UniformLocation) {
         unsafe {
             glUniform1f(*location, *self);
@@ -21,6 +28,11 @@ impl Uniform for GLfloat {
 
 impl Uniform for GLint {
     type Options = ();
+
+    fn accepts(gl_type: GLenum) -> bool {
+        matches!(gl_type, GL_INT | GL_BOOL) || glsl_type::is_sampler(gl_type)
+    }
+
     fn set(&self, _options: Option<Self::Options>, UniformLocation(location): &UniformLocation) {
         unsafe { glUniform1i(*location, *self) };
     }
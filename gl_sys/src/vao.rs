@@ -1,14 +1,52 @@
-use crate::{GLuint, glBindVertexArray, glDeleteVertexArrays, glGenVertexArrays};
+use std::marker::PhantomData;
 
-#[derive(Debug, Clone, Default)]
-pub struct VertexArrayObject(pub GLuint);
+use crate::{GLuint, glBindVertexArray};
+
+#[cfg(test)]
+use crate::mock::{glDeleteVertexArrays, glGenVertexArrays};
+#[cfg(not(test))]
+use crate::{glDeleteVertexArrays, glGenVertexArrays};
+
+/// Owning vertex array handle; the array is deleted when this is dropped.
+/// The default value owns no array.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct VertexArrayObject(GLuint);
+
+/// Non-owning handle to a vertex array, valid while the owner is borrowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VertexArrayRef<'a>(GLuint, PhantomData<&'a VertexArrayObject>);
+
+impl VertexArrayRef<'_> {
+    /// Binding this unbinds the current vertex array.
+    #[inline]
+    pub const fn zero() -> VertexArrayRef<'static> {
+        VertexArrayRef(0, PhantomData)
+    }
 
-impl VertexArrayObject {
     #[inline]
-    pub const fn zero() -> Self {
-        Self(0)
+    pub const fn id(&self) -> GLuint {
+        self.0
     }
+}
 
+impl<'a> From<&'a VertexArrayObject> for VertexArrayRef<'a> {
+    #[inline]
+    fn from(vao: &'a VertexArrayObject) -> Self {
+        vao.handle()
+    }
+}
+
+impl Drop for VertexArrayObject {
+    fn drop(&mut self) {
+        if self.0 != 0 {
+            unsafe {
+                glDeleteVertexArrays(1, &self.0);
+            }
+        }
+    }
+}
+
+impl VertexArrayObject {
     #[inline]
     pub fn gen_vertex_arrays() -> Self {
         let mut vao = 0;
@@ -19,18 +57,39 @@ impl VertexArrayObject {
     }
 
     #[inline]
-    pub fn bind_vertex_array(vao: &Self) {
-        let Self(index) = vao;
-        unsafe {
-            glBindVertexArray(*index);
-        }
+    pub const fn id(&self) -> GLuint {
+        self.0
     }
 
     #[inline]
-    pub fn delete_vertex_array(vao: &Self) {
-        let Self(index) = vao;
+    pub const fn handle(&self) -> VertexArrayRef<'_> {
+        VertexArrayRef(self.0, PhantomData)
+    }
+
+    #[inline]
+    pub fn bind_vertex_array<'a>(vao: impl Into<VertexArrayRef<'a>>) {
         unsafe {
-            glDeleteVertexArrays(1, index);
+            glBindVertexArray(vao.into().0);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock;
+
+    #[test]
+    fn test_vao_deleted_on_drop() {
+        let before = mock::live_vertex_arrays();
+        let vaos: Vec<_> = (0..3)
+            .map(|_| VertexArrayObject::gen_vertex_arrays())
+            .collect();
+        assert_eq!(mock::live_vertex_arrays(), before + 3);
+        assert_eq!(VertexArrayRef::from(&vaos[1]).id(), vaos[1].id());
+
+        drop(vaos);
+        drop(VertexArrayObject::default());
+        assert_eq!(mock::live_vertex_arrays(), before);
+    }
+}
@@ -1,22 +1,60 @@
-use std::fmt;
+use std::{fmt, marker::PhantomData};
+
+pub use bytemuck::Pod;
 
 use crate::{
-    GLboolean, GLint, GLsizei, GLsizeiptr, GLuint, glBindBuffer, glBufferData, glDeleteBuffers,
-    glEnableVertexAttribArray, glGenBuffers, glVertexAttribPointer,
+    GLboolean, GLint, GLsizei, GLsizeiptr, GLuint, glBindBuffer, glBufferData,
+    glEnableVertexAttribArray, glVertexAttribPointer,
 };
 
+#[cfg(test)]
+use crate::mock::{glDeleteBuffers, glGenBuffers};
+#[cfg(not(test))]
+use crate::{glDeleteBuffers, glGenBuffers};
+
 #[derive(Debug, Clone)]
 pub struct Location(pub GLuint);
 
-#[derive(Debug, Clone, Default)]
-pub struct VertexBufferObject(pub GLuint);
+/// Owning buffer handle; the buffer is deleted when this is dropped. The
+/// default value owns no buffer.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct VertexBufferObject(GLuint);
 
-impl VertexBufferObject {
+/// Non-owning handle to a buffer, valid while the owner is borrowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VertexBufferRef<'a>(GLuint, PhantomData<&'a VertexBufferObject>);
+
+impl VertexBufferRef<'_> {
+    /// Binding this unbinds the current buffer.
     #[inline]
-    pub const fn zero() -> Self {
-        Self(0)
+    pub const fn zero() -> VertexBufferRef<'static> {
+        VertexBufferRef(0, PhantomData)
+    }
+
+    #[inline]
+    pub const fn id(&self) -> GLuint {
+        self.0
+    }
+}
+
+impl<'a> From<&'a VertexBufferObject> for VertexBufferRef<'a> {
+    #[inline]
+    fn from(buffer: &'a VertexBufferObject) -> Self {
+        buffer.handle()
+    }
+}
+
+impl Drop for VertexBufferObject {
+    fn drop(&mut self) {
+        if self.0 != 0 {
+            unsafe {
+                glDeleteBuffers(1, &self.0);
+            }
+        }
     }
+}
 
+impl VertexBufferObject {
     #[inline]
     pub fn gen_buffers() -> Self {
         let mut buffer = 0;
@@ -27,16 +65,19 @@ impl VertexBufferObject {
     }
 
     #[inline]
-    pub fn bind_buffer(n: u32, Self(buffer): &Self) {
-        unsafe {
-            glBindBuffer(n, *buffer);
-        }
+    pub const fn id(&self) -> GLuint {
+        self.0
     }
 
     #[inline]
-    pub fn delete_buffer(Self(buffer): &Self) {
+    pub const fn handle(&self) -> VertexBufferRef<'_> {
+        VertexBufferRef(self.0, PhantomData)
+    }
+
+    #[inline]
+    pub fn bind_buffer<'a>(n: u32, buffer: impl Into<VertexBufferRef<'a>>) {
         unsafe {
-            glDeleteBuffers(1, buffer);
+            glBindBuffer(n, buffer.into().0);
         }
     }
 
@@ -71,9 +112,10 @@ impl VertexBufferObject {
         Ok(())
     }
 
-    pub fn buffer_data<T>(n: u32, data: &[T], usage: u32) -> Result<(), VBOError> {
-        let pointer = data.as_ptr() as _;
-        let size = match GLsizeiptr::try_from(data.len() * std::mem::size_of::<T>()) {
+    pub fn buffer_data<T: Pod>(n: u32, data: &[T], usage: u32) -> Result<(), VBOError> {
+        let bytes: &[u8] = bytemuck::cast_slice(data);
+        let pointer = bytes.as_ptr() as _;
+        let size = match GLsizeiptr::try_from(bytes.len()) {
             Ok(s) => s,
             Err(_) => return Err(VBOError::CastError),
         };
@@ -96,3 +138,36 @@ impl fmt::Display for VBOError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock;
+
+    #[test]
+    fn test_vbo_deleted_on_drop() {
+        let before = mock::live_buffers();
+        let a = VertexBufferObject::gen_buffers();
+        let b = VertexBufferObject::gen_buffers();
+        assert_ne!(a.id(), b.id());
+        assert_eq!(mock::live_buffers(), before + 2);
+
+        let handle = a.handle();
+        assert_eq!(handle.id(), a.id());
+        drop(a);
+        assert_eq!(mock::live_buffers(), before + 1);
+
+        let buffers = vec![b, VertexBufferObject::gen_buffers()];
+        assert_eq!(mock::live_buffers(), before + 2);
+        drop(buffers);
+        assert_eq!(mock::live_buffers(), before);
+    }
+
+    #[test]
+    fn test_vbo_default_owns_nothing() {
+        let before = mock::live_buffers();
+        drop(VertexBufferObject::default());
+        assert_eq!(mock::live_buffers(), before);
+        assert_eq!(VertexBufferRef::zero().id(), 0);
+    }
+}
@@ -0,0 +1,124 @@
+use std::fmt;
+
+use crate::{GL_MAJOR_VERSION, GL_MINOR_VERSION, GLint, glGetIntegerv};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version {
+    pub major: GLint,
+    pub minor: GLint,
+}
+
+impl Version {
+    pub const fn new(major: GLint, minor: GLint) -> Self {
+        Self { major, minor }
+    }
+
+    /// Version of the current context.
+    pub fn current() -> Self {
+        let (mut major, mut minor) = (0, 0);
+        unsafe {
+            glGetIntegerv(GL_MAJOR_VERSION, &mut major);
+            glGetIntegerv(GL_MINOR_VERSION, &mut minor);
+        }
+        Self { major, minor }
+    }
+
+    pub fn supports(&self, feature: Feature) -> bool {
+        *self >= feature.required()
+    }
+
+    /// Fails when the current context is too old for `feature`.
+    pub fn require(feature: Feature) -> Result<(), VersionError> {
+        let actual = Self::current();
+        if actual.supports(feature) {
+            Ok(())
+        } else {
+            Err(VersionError { feature, actual })
+        }
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// Functionality beyond the 4.1 core profile the app asks for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feature {
+    GeometryShader,
+    TessellationShader,
+    MemoryBarrier,
+    ComputeShader,
+    ShaderStorageBuffer,
+}
+
+impl Feature {
+    pub const fn required(&self) -> Version {
+        match self {
+            Self::GeometryShader => Version::new(3, 2),
+            Self::TessellationShader => Version::new(4, 0),
+            Self::MemoryBarrier => Version::new(4, 2),
+            Self::ComputeShader | Self::ShaderStorageBuffer => Version::new(4, 3),
+        }
+    }
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::GeometryShader => write!(f, "Geometry shaders"),
+            Self::TessellationShader => write!(f, "Tessellation shaders"),
+            Self::MemoryBarrier => write!(f, "Memory barriers"),
+            Self::ComputeShader => write!(f, "Compute shaders"),
+            Self::ShaderStorageBuffer => write!(f, "Shader storage buffers"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionError {
+    pub feature: Feature,
+    pub actual: Version,
+}
+
+impl fmt::Display for VersionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} require OpenGL {}, but the context only supports {}",
+            self.feature,
+            self.feature.required(),
+            self.actual
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_supports() {
+        let mac = Version::new(4, 1);
+        assert!(mac.supports(Feature::GeometryShader));
+        assert!(mac.supports(Feature::TessellationShader));
+        assert!(!mac.supports(Feature::MemoryBarrier));
+        assert!(!mac.supports(Feature::ComputeShader));
+        assert!(Version::new(4, 6).supports(Feature::ShaderStorageBuffer));
+        assert!(Version::new(5, 0) > Version::new(4, 6));
+    }
+
+    #[test]
+    fn test_version_error_message() {
+        let err = VersionError {
+            feature: Feature::ComputeShader,
+            actual: Version::new(4, 1),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Compute shaders require OpenGL 4.3, but the context only supports 4.1"
+        );
+    }
+}
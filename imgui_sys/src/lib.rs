@@ -4,8 +4,8 @@ use glfw_sys::bindings::GLFWwindow;
 
 use crate::{
     bindings::{
-        ImGui_Begin, ImGui_CreateContext, ImGui_DestroyContext, ImGui_End, ImGui_GetDrawData,
-        ImGui_GetIO, ImGui_ImplGlfw_InitForOpenGL, ImGui_ImplGlfw_NewFrame,
+        ImGui_Begin, ImGui_CreateContext, ImGui_DestroyContext, ImGui_DragFloat3, ImGui_End,
+        ImGui_GetDrawData, ImGui_GetIO, ImGui_ImplGlfw_InitForOpenGL, ImGui_ImplGlfw_NewFrame,
         ImGui_ImplGlfw_Shutdown, ImGui_ImplOpenGL3_Init, ImGui_ImplOpenGL3_NewFrame,
         ImGui_ImplOpenGL3_RenderDrawData, ImGui_ImplOpenGL3_Shutdown, ImGui_NewFrame, ImGui_Render,
         ImGui_SetNextWindowPos, ImGui_SetNextWindowSize, ImGui_Text, ImGuiCond,
@@ -107,6 +107,28 @@ pub fn text(title: impl Into<Vec<u8>>) -> Result<(), NulError> {
     Ok(())
 }
 
+/// Three floats edited by dragging or typing. Returns whether `v` changed.
+#[inline]
+pub fn drag_float3(
+    label: impl Into<Vec<u8>>,
+    v: &mut [f32; 3],
+    speed: f32,
+) -> Result<bool, NulError> {
+    let label = CString::new(label)?;
+    let changed = unsafe {
+        ImGui_DragFloat3(
+            label.as_ptr(),
+            v.as_mut_ptr(),
+            speed,
+            0.0,
+            0.0,
+            c"%.2f".as_ptr(),
+            0,
+        )
+    };
+    Ok(changed)
+}
+
 #[inline]
 pub fn set_next_window_size<V: Into<ImVec2>, C: Into<ImGuiCond>>(size: V, cond: C) {
     unsafe {
@@ -0,0 +1,124 @@
+//! BVH build and query throughput. Set `RMATH_BENCH_OBJ` to a (large) OBJ
+//! file to benchmark real geometry; a tessellated sphere is used otherwise.
+
+use std::{env, f32::consts::PI, fs, hint::black_box};
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use rmath::{
+    Scalar,
+    bounds::Aabb,
+    bvh::Bvh,
+    random::{Pcg32, on_unit_sphere},
+    ray::Ray,
+    vec3::Vec3,
+};
+
+/// Positions and fan triangulated faces of an OBJ file.
+fn load_obj(path: &str) -> (Vec<Vec3<f32>>, Vec<u32>) {
+    let source = fs::read_to_string(path).expect("Failed to read OBJ file");
+    let mut positions = Vec::new();
+    let mut indices = Vec::new();
+    for line in source.lines() {
+        let mut tokens = line.split_whitespace();
+        match tokens.next() {
+            Some("v") => {
+                let c: Vec<f32> = tokens.take(3).map(|t| t.parse().unwrap()).collect();
+                positions.push(Vec3::new(c[0], c[1], c[2]));
+            }
+            Some("f") => {
+                let face: Vec<u32> = tokens
+                    .map(|t| {
+                        let i: i64 = t.split('/').next().unwrap().parse().unwrap();
+                        if i < 0 {
+                            (positions.len() as i64 + i) as u32
+                        } else {
+                            i as u32 - 1
+                        }
+                    })
+                    .collect();
+                for k in 1..face.len().saturating_sub(1) {
+                    indices.extend([face[0], face[k], face[k + 1]]);
+                }
+            }
+            _ => (),
+        }
+    }
+    (positions, indices)
+}
+
+fn sphere(rings: u32, segments: u32) -> (Vec<Vec3<f32>>, Vec<u32>) {
+    let mut positions = Vec::new();
+    for r in 0..=rings {
+        let theta = PI * r as f32 / rings as f32;
+        for s in 0..=segments {
+            let phi = 2.0 * PI * s as f32 / segments as f32;
+            positions.push(Vec3::new(
+                theta.sin() * phi.cos(),
+                theta.cos(),
+                theta.sin() * phi.sin(),
+            ));
+        }
+    }
+    let mut indices = Vec::new();
+    for r in 0..rings {
+        for s in 0..segments {
+            let a = r * (segments + 1) + s;
+            let b = a + segments + 1;
+            indices.extend([a, b, a + 1, a + 1, b, b + 1]);
+        }
+    }
+    (positions, indices)
+}
+
+fn bench_bvh(c: &mut Criterion) {
+    let (positions, indices) = match env::var("RMATH_BENCH_OBJ") {
+        Ok(path) => load_obj(&path),
+        Err(_) => sphere(400, 400),
+    };
+    let bvh = Bvh::new(&positions, &indices);
+    let bounds = bvh.bounds();
+    let center = bounds.center();
+    let radius = bounds.diagonal().length();
+
+    let mut rng = Pcg32::from_seed(0);
+    let rays: Vec<_> = (0..1024)
+        .map(|_| {
+            let origin = on_unit_sphere(&mut rng) * Scalar(radius) + center;
+            let target = center
+                + Vec3::new(
+                    rng.next_range(-0.5, 0.5),
+                    rng.next_range(-0.5, 0.5),
+                    rng.next_range(-0.5, 0.5),
+                ) * bounds.extent();
+            Ray::from_points(&origin, &target)
+        })
+        .collect();
+
+    let mut group = c.benchmark_group(format!("bvh/{}_triangles", indices.len() / 3));
+    group.sample_size(10);
+    group.bench_function("build", |b| {
+        b.iter(|| Bvh::new(black_box(&positions), black_box(&indices)))
+    });
+    group.sample_size(50);
+    group.bench_function("closest_hit_x1024", |b| {
+        b.iter(|| rays.iter().filter_map(|r| bvh.closest_hit(r)).count())
+    });
+    group.bench_function("any_hit_x1024", |b| {
+        b.iter(|| {
+            rays.iter()
+                .filter(|r| bvh.any_hit(r, f32::INFINITY))
+                .count()
+        })
+    });
+    let query = Aabb::new(
+        center - bounds.extent() * Scalar(0.1),
+        center + bounds.extent() * Scalar(0.1),
+    );
+    group.bench_function("query_aabb", |b| {
+        b.iter(|| bvh.query_aabb(black_box(&query)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_bvh);
+criterion_main!(benches);
@@ -0,0 +1,205 @@
+//! Tolerant float comparison for vectors, matrices and transforms.
+
+use crate::{
+    float::Float, mat3::Mat3, mat4::Mat4, quat::Quat, transform::Transform, vec2::Vec2, vec3::Vec3,
+    vec4::Vec4,
+};
+
+pub trait ApproxEq {
+    type Epsilon: Float;
+
+    /// Every component differs by at most `epsilon`.
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool;
+
+    /// Every component differs by at most `tolerance` times the larger
+    /// magnitude of the pair, or by `tolerance` when both are below one.
+    fn relative_eq(&self, other: &Self, tolerance: Self::Epsilon) -> bool;
+
+    /// Every component is at most `max_ulps` representable values away.
+    /// Values straddling zero only compare equal when both are zero.
+    fn ulps_eq(&self, other: &Self, max_ulps: u32) -> bool;
+
+    /// `relative_eq` with a tolerance of 16 machine epsilons.
+    fn approx_eq(&self, other: &Self) -> bool {
+        let tolerance = Self::Epsilon::EPSILON * Self::Epsilon::from_f64(16.0);
+        self.relative_eq(other, tolerance)
+    }
+}
+
+impl<T: Float> ApproxEq for T {
+    type Epsilon = T;
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        self == other || (*self - *other).abs() <= epsilon
+    }
+
+    fn relative_eq(&self, other: &Self, tolerance: T) -> bool {
+        if self == other {
+            return true;
+        }
+        let scale = T::ONE.max(self.abs()).max(other.abs());
+        (*self - *other).abs() <= tolerance * scale
+    }
+
+    fn ulps_eq(&self, other: &Self, max_ulps: u32) -> bool {
+        if self == other {
+            return true;
+        }
+        if self.is_nan() || other.is_nan() || self.signum() != other.signum() {
+            return false;
+        }
+        self.ulps(*other) <= u64::from(max_ulps)
+    }
+}
+
+macro_rules! impl_approx_eq {
+    ($($ty:ident => $components:ident),*) => {$(
+        impl<T: Float> ApproxEq for $ty<T> {
+            type Epsilon = T;
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+                let (a, b) = (self.$components(), other.$components());
+                a.iter().zip(&b).all(|(a, b)| a.abs_diff_eq(b, epsilon))
+            }
+
+            fn relative_eq(&self, other: &Self, tolerance: T) -> bool {
+                let (a, b) = (self.$components(), other.$components());
+                a.iter().zip(&b).all(|(a, b)| a.relative_eq(b, tolerance))
+            }
+
+            fn ulps_eq(&self, other: &Self, max_ulps: u32) -> bool {
+                let (a, b) = (self.$components(), other.$components());
+                a.iter().zip(&b).all(|(a, b)| a.ulps_eq(b, max_ulps))
+            }
+        }
+    )*};
+}
+
+impl_approx_eq!(
+    Vec2 => data,
+    Vec3 => data,
+    Vec4 => data,
+    Quat => data,
+    Mat3 => cols,
+    Mat4 => cols
+);
+
+impl<T: Float> ApproxEq for Transform<T> {
+    type Epsilon = T;
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.translation.abs_diff_eq(&other.translation, epsilon)
+            && self.rotation.abs_diff_eq(&other.rotation, epsilon)
+            && self.scale.abs_diff_eq(&other.scale, epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, tolerance: T) -> bool {
+        self.translation.relative_eq(&other.translation, tolerance)
+            && self.rotation.relative_eq(&other.rotation, tolerance)
+            && self.scale.relative_eq(&other.scale, tolerance)
+    }
+
+    fn ulps_eq(&self, other: &Self, max_ulps: u32) -> bool {
+        self.translation.ulps_eq(&other.translation, max_ulps)
+            && self.rotation.ulps_eq(&other.rotation, max_ulps)
+            && self.scale.ulps_eq(&other.scale, max_ulps)
+    }
+}
+
+/// Compares two [`ApproxEq`] values: `approx_eq!(a, b)` with the default
+/// tolerance, or with one of `abs = epsilon`, `rel = tolerance` or
+/// `ulps = max_ulps`.
+#[macro_export]
+macro_rules! approx_eq {
+    ($a:expr, $b:expr $(,)?) => {
+        $crate::approx::ApproxEq::approx_eq(&$a, &$b)
+    };
+    ($a:expr, $b:expr, abs = $epsilon:expr $(,)?) => {
+        $crate::approx::ApproxEq::abs_diff_eq(&$a, &$b, $epsilon)
+    };
+    ($a:expr, $b:expr, rel = $tolerance:expr $(,)?) => {
+        $crate::approx::ApproxEq::relative_eq(&$a, &$b, $tolerance)
+    };
+    ($a:expr, $b:expr, ulps = $max_ulps:expr $(,)?) => {
+        $crate::approx::ApproxEq::ulps_eq(&$a, &$b, $max_ulps)
+    };
+}
+
+/// Asserts [`approx_eq!`], printing both values on failure.
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($a:expr, $b:expr $(, $kind:ident = $tolerance:expr)? $(,)?) => {
+        match (&$a, &$b) {
+            (a, b) => assert!(
+                $crate::approx_eq!(*a, *b $(, $kind = $tolerance)?),
+                "assertion `left ≈ right` failed\n  left: {a:?}\n right: {b:?}"
+            ),
+        }
+    };
+}
+
+/// Asserts that [`approx_eq!`] does not hold, printing both values on failure.
+#[macro_export]
+macro_rules! assert_approx_ne {
+    ($a:expr, $b:expr $(, $kind:ident = $tolerance:expr)? $(,)?) => {
+        match (&$a, &$b) {
+            (a, b) => assert!(
+                !$crate::approx_eq!(*a, *b $(, $kind = $tolerance)?),
+                "assertion `left ≉ right` failed\n  left: {a:?}\n right: {b:?}"
+            ),
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_approx_float() {
+        assert!(1.0f32.abs_diff_eq(&1.05, 0.1));
+        assert!(!1.0f32.abs_diff_eq(&1.2, 0.1));
+        assert!(1000.0f32.relative_eq(&1000.5, 1e-3));
+        assert!(!1.0f32.relative_eq(&1.5, 1e-3));
+        assert!(0.0f32.relative_eq(&1e-9, 1e-6));
+        assert!(1.0f32.ulps_eq(&(1.0 + 2.0 * f32::EPSILON), 2));
+        assert!(!1.0f32.ulps_eq(&(1.0 + 2.0 * f32::EPSILON), 1));
+        assert!(0.0f32.ulps_eq(&-0.0, 0));
+        assert!(!f32::NAN.approx_eq(&f32::NAN));
+        assert!(f64::INFINITY.approx_eq(&f64::INFINITY));
+    }
+
+    #[test]
+    fn test_approx_vectors() {
+        let a = Vec3::new(0.1f32, 0.2, 0.3);
+        let b = Vec3::new(0.1 + 1e-8, 0.2, 0.3 - 1e-8);
+        assert_approx_eq!(a, b);
+        assert_approx_eq!(a, b, ulps = 4);
+        assert_approx_ne!(a, Vec3::new(0.1, 0.2, 0.31));
+        assert_approx_eq!(a, Vec3::new(0.1, 0.2, 0.31), abs = 0.02);
+        assert_approx_eq!(
+            Vec2::new(1e6f32, 1.0),
+            Vec2::new(1e6 + 1.0, 1.0),
+            rel = 1e-5
+        );
+        assert_approx_eq!(
+            Vec4::new(1.0f64, 2.0, 3.0, 4.0),
+            Vec4::new(1.0, 2.0, 3.0, 4.0)
+        );
+    }
+
+    #[test]
+    fn test_approx_matrices() {
+        let m = Mat4::<f32>::rotation(35.0, &Vec3::new(1.0, -2.0, 0.5))
+            * Mat4::translation(&Vec3::new(3.0, -1.0, 2.0));
+        assert_approx_eq!(m * m.invert().unwrap(), Mat4::identity());
+        assert_approx_ne!(m, Mat4::identity(), abs = 1e-3);
+        assert_approx_eq!(Mat3::<f64>::identity(), Mat3::identity(), ulps = 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "left ≈ right")]
+    fn test_approx_assert_fails() {
+        assert_approx_eq!(Quat::<f32>::identity(), Quat::new(0.0, 0.0, 0.1, 1.0));
+    }
+}
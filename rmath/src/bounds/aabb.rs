@@ -0,0 +1,216 @@
+use crate::{Scalar, float::Float, mat4::Mat4, vec3::Vec3};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Aabb<T: Copy> {
+    pub min: Vec3<T>,
+    pub max: Vec3<T>,
+}
+
+impl<T: Copy> Aabb<T> {
+    #[inline]
+    pub const fn new(min: Vec3<T>, max: Vec3<T>) -> Self {
+        Self { min, max }
+    }
+}
+
+impl<T: Float> Aabb<T> {
+    /// An inverted box that any point extends; the identity for [`Aabb::union`].
+    pub const fn empty() -> Self {
+        Self::new(Vec3::scalar(T::MAX), Vec3::scalar(T::MIN))
+    }
+
+    pub fn from_points<I>(points: I) -> Self
+    where
+        I: IntoIterator<Item = Vec3<T>>,
+    {
+        let mut aabb = Self::empty();
+        for p in points {
+            aabb.extend(&p);
+        }
+        aabb
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y || self.min.z > self.max.z
+    }
+
+    pub fn extend(&mut self, p: &Vec3<T>) {
+        (self.min, self.max) = (Vec3::min(self.min, *p), Vec3::max(self.max, *p))
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::new(
+            Vec3::min(self.min, other.min),
+            Vec3::max(self.max, other.max),
+        )
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let aabb = Self::new(
+            Vec3::max(self.min, other.min),
+            Vec3::min(self.max, other.max),
+        );
+        if aabb.is_empty() { None } else { Some(aabb) }
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min.x <= other.max.x
+            && self.max.x >= other.min.x
+            && self.min.y <= other.max.y
+            && self.max.y >= other.min.y
+            && self.min.z <= other.max.z
+            && self.max.z >= other.min.z
+    }
+
+    pub fn contains_point(&self, p: &Vec3<T>) -> bool {
+        p.x >= self.min.x
+            && p.x <= self.max.x
+            && p.y >= self.min.y
+            && p.y <= self.max.y
+            && p.z >= self.min.z
+            && p.z <= self.max.z
+    }
+
+    pub fn contains(&self, other: &Self) -> bool {
+        self.contains_point(&other.min) && self.contains_point(&other.max)
+    }
+
+    pub fn center(&self) -> Vec3<T> {
+        Scalar(T::HALF) * (self.min + self.max)
+    }
+
+    /// Half the size of the box along each axis.
+    pub fn extent(&self) -> Vec3<T> {
+        Scalar(T::HALF) * self.diagonal()
+    }
+
+    pub fn diagonal(&self) -> Vec3<T> {
+        self.max - self.min
+    }
+
+    pub fn surface_area(&self) -> T {
+        let Vec3 { x, y, z } = self.diagonal();
+        T::TWO * (x * y + y * z + z * x)
+    }
+
+    pub fn corners(&self) -> [Vec3<T>; 8] {
+        let (lo, hi) = (self.min, self.max);
+        [
+            Vec3::new(lo.x, lo.y, lo.z),
+            Vec3::new(hi.x, lo.y, lo.z),
+            Vec3::new(lo.x, hi.y, lo.z),
+            Vec3::new(hi.x, hi.y, lo.z),
+            Vec3::new(lo.x, lo.y, hi.z),
+            Vec3::new(hi.x, lo.y, hi.z),
+            Vec3::new(lo.x, hi.y, hi.z),
+            Vec3::new(hi.x, hi.y, hi.z),
+        ]
+    }
+
+    /// Bounds of the transformed box; grows under rotation so it always
+    /// encloses the transformed corners.
+    pub fn transform(&self, m: &Mat4<T>) -> Self {
+        if self.is_empty() {
+            return *self;
+        }
+
+        let center = m.transform_point(&self.center());
+        let Vec3 { x, y, z } = self.extent();
+        let [c0, c1, c2, _] = m.columns();
+        let abs = |c: Vec3<T>| c.map(T::abs);
+        let extent = abs(c0.truncate()) * Scalar(x)
+            + abs(c1.truncate()) * Scalar(y)
+            + abs(c2.truncate()) * Scalar(z);
+
+        Self::new(center - extent, center + extent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit() -> Aabb<f32> {
+        Aabb::new(Vec3::zero(), Vec3::one())
+    }
+
+    #[test]
+    fn test_aabb_from_points() {
+        let aabb = Aabb::from_points([
+            Vec3::new(1.0, -2.0, 0.5),
+            Vec3::new(-1.0, 3.0, 0.0),
+            Vec3::new(0.0, 0.0, 4.0),
+        ]);
+        assert_eq!(aabb.min, Vec3::new(-1.0, -2.0, 0.0));
+        assert_eq!(aabb.max, Vec3::new(1.0, 3.0, 4.0));
+    }
+
+    #[test]
+    fn test_aabb_empty() {
+        let aabb = Aabb::<f32>::from_points([]);
+        assert!(aabb.is_empty());
+        assert_eq!(aabb.union(&unit()), unit());
+    }
+
+    #[test]
+    fn test_aabb_union() {
+        let other = Aabb::new(Vec3::scalar(-1.0), Vec3::scalar(0.5));
+        assert_eq!(
+            unit().union(&other),
+            Aabb::new(Vec3::scalar(-1.0), Vec3::one())
+        );
+    }
+
+    #[test]
+    fn test_aabb_intersection() {
+        let other = Aabb::new(Vec3::scalar(0.5), Vec3::scalar(2.0));
+        assert_eq!(
+            unit().intersection(&other),
+            Some(Aabb::new(Vec3::scalar(0.5), Vec3::one()))
+        );
+        assert!(unit().intersects(&other));
+
+        let apart = Aabb::new(Vec3::scalar(2.0), Vec3::scalar(3.0));
+        assert_eq!(unit().intersection(&apart), None);
+        assert!(!unit().intersects(&apart));
+    }
+
+    #[test]
+    fn test_aabb_contains() {
+        assert!(unit().contains_point(&Vec3::scalar(0.5)));
+        assert!(!unit().contains_point(&Vec3::new(0.5, 1.5, 0.5)));
+        assert!(unit().contains(&Aabb::new(Vec3::scalar(0.25), Vec3::scalar(0.75))));
+        assert!(!unit().contains(&Aabb::new(Vec3::scalar(0.25), Vec3::scalar(1.75))));
+    }
+
+    #[test]
+    fn test_aabb_measures() {
+        let aabb = Aabb::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(2.0, 4.0, 6.0));
+        assert_eq!(aabb.center(), Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(aabb.extent(), Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(aabb.diagonal(), Vec3::new(2.0, 4.0, 6.0));
+        assert_eq!(aabb.surface_area(), 88.0);
+    }
+
+    #[test]
+    fn test_aabb_transform_translate_scale() {
+        let m = Mat4::translation(&Vec3::new(1.0, 2.0, 3.0)) * Mat4::scale(&Vec3::scalar(2.0));
+        assert_eq!(
+            unit().transform(&m),
+            Aabb::new(Vec3::new(1.0, 2.0, 3.0), Vec3::new(3.0, 4.0, 5.0))
+        );
+    }
+
+    #[test]
+    fn test_aabb_transform_encloses_corners() {
+        let m = Mat4::translation(&Vec3::new(-3.0, 0.5, 2.0))
+            * Mat4::rotation(37.0, &Vec3::new(1.0, 1.0, 0.0));
+        let aabb = unit().transform(&m);
+        for corner in unit().corners() {
+            let p = m.transform_point(&corner);
+            let grown = Aabb::new(aabb.min - Vec3::scalar(1e-5), aabb.max + Vec3::scalar(1e-5));
+            assert!(grown.contains_point(&p), "{p:?} outside {aabb:?}");
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod aabb;
+pub mod sphere;
+
+pub use aabb::Aabb;
+pub use sphere::Sphere;
@@ -0,0 +1,105 @@
+use crate::{bounds::Aabb, float::Float, mat4::Mat4, vec3::Vec3};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Sphere<T: Copy> {
+    pub center: Vec3<T>,
+    pub radius: T,
+}
+
+impl<T: Copy> Sphere<T> {
+    #[inline]
+    pub const fn new(center: Vec3<T>, radius: T) -> Self {
+        Self { center, radius }
+    }
+}
+
+impl<T: Float> Sphere<T> {
+    pub fn from_aabb(aabb: &Aabb<T>) -> Self {
+        Self::new(aabb.center(), aabb.extent().length())
+    }
+
+    /// Sphere centered on the bounds of `points` that encloses all of them.
+    pub fn from_points<I>(points: I) -> Self
+    where
+        I: IntoIterator<Item = Vec3<T>>,
+        I::IntoIter: Clone,
+    {
+        let points = points.into_iter();
+        let center = Aabb::from_points(points.clone()).center();
+        let radius = points.map(|p| (p - center).length()).fold(T::ZERO, T::max);
+        Self::new(center, radius)
+    }
+
+    pub fn contains_point(&self, p: &Vec3<T>) -> bool {
+        (*p - self.center).length() <= self.radius
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        (other.center - self.center).length() <= self.radius + other.radius
+    }
+
+    pub fn intersects_aabb(&self, aabb: &Aabb<T>) -> bool {
+        let closest = Vec3::min(Vec3::max(self.center, aabb.min), aabb.max);
+        (closest - self.center).length() <= self.radius
+    }
+
+    pub fn to_aabb(&self) -> Aabb<T> {
+        let r = Vec3::scalar(self.radius);
+        Aabb::new(self.center - r, self.center + r)
+    }
+
+    /// Radius is scaled by the largest axis scale so the result stays conservative.
+    pub fn transform(&self, m: &Mat4<T>) -> Self {
+        let [c0, c1, c2, _] = m.columns();
+        let scale = c0
+            .truncate()
+            .length()
+            .max(c1.truncate().length())
+            .max(c2.truncate().length());
+        Self::new(m.transform_point(&self.center), self.radius * scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sphere_from_aabb() {
+        let aabb = Aabb::new(Vec3::new(-1.0, -2.0, -2.0), Vec3::new(1.0, 2.0, 2.0));
+        let sphere = Sphere::from_aabb(&aabb);
+        assert_eq!(sphere, Sphere::new(Vec3::zero(), 3.0));
+    }
+
+    #[test]
+    fn test_sphere_from_points() {
+        let points = [Vec3::new(-1.0, 0.0, 0.0), Vec3::new(3.0, 0.0, 0.0)];
+        let sphere = Sphere::from_points(points);
+        assert_eq!(sphere, Sphere::new(Vec3::new(1.0, 0.0, 0.0), 2.0));
+        assert!(points.iter().all(|p| sphere.contains_point(p)));
+    }
+
+    #[test]
+    fn test_sphere_intersects() {
+        let a = Sphere::new(Vec3::zero(), 1.0);
+        assert!(a.intersects(&Sphere::new(Vec3::new(1.5, 0.0, 0.0), 1.0)));
+        assert!(!a.intersects(&Sphere::new(Vec3::new(2.5, 0.0, 0.0), 1.0)));
+    }
+
+    #[test]
+    fn test_sphere_intersects_aabb() {
+        let s = Sphere::new(Vec3::new(2.0, 0.5, 0.5), 1.0);
+        assert!(s.intersects_aabb(&Aabb::new(Vec3::zero(), Vec3::one())));
+        let far = Sphere::new(Vec3::new(2.0, 2.0, 2.0), 1.0);
+        assert!(!far.intersects_aabb(&Aabb::new(Vec3::zero(), Vec3::one())));
+    }
+
+    #[test]
+    fn test_sphere_transform() {
+        let s = Sphere::new(Vec3::new(1.0, 0.0, 0.0), 1.0);
+        let m =
+            Mat4::translation(&Vec3::new(0.0, 1.0, 0.0)) * Mat4::scale(&Vec3::new(1.0, 3.0, 2.0));
+        assert_eq!(s.transform(&m), Sphere::new(Vec3::new(1.0, 1.0, 0.0), 3.0));
+    }
+}
@@ -0,0 +1,458 @@
+//! Bounding volume hierarchy over indexed triangles, built with a binned
+//! surface area heuristic.
+
+use crate::{
+    bounds::Aabb,
+    float::Float,
+    ray::{Ray, TriangleHit},
+    vec3::Vec3,
+};
+
+const BINS: usize = 12;
+const MAX_LEAF_SIZE: u32 = 4;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Node<T: Copy> {
+    bounds: Aabb<T>,
+    /// First triangle of a leaf, or the left child of an interior node
+    /// whose right child directly follows it.
+    start: u32,
+    /// Zero for interior nodes.
+    count: u32,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Bvh<T: Copy> {
+    nodes: Vec<Node<T>>,
+    triangles: Vec<[Vec3<T>; 3]>,
+    /// Source face of each entry in `triangles`.
+    faces: Vec<u32>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BvhHit<T> {
+    /// Face index into the source index buffer, i.e. `indices[3 * face..][..3]`.
+    pub face: usize,
+    pub hit: TriangleHit<T>,
+}
+
+struct Builder<'a, T: Copy> {
+    nodes: Vec<Node<T>>,
+    faces: Vec<u32>,
+    bounds: &'a [Aabb<T>],
+    centroids: &'a [Vec3<T>],
+}
+
+impl<T: Float> Builder<'_, T> {
+    fn subdivide(&mut self, node: usize) {
+        let Node { start, count, .. } = self.nodes[node];
+        let range = start as usize..(start + count) as usize;
+        let bounds = self.faces[range.clone()]
+            .iter()
+            .fold(Aabb::empty(), |b, &f| b.union(&self.bounds[f as usize]));
+        self.nodes[node].bounds = bounds;
+        if count <= MAX_LEAF_SIZE {
+            return;
+        }
+
+        let Some((axis, split, min, scale)) = self.best_split(range.clone(), &bounds) else {
+            return;
+        };
+        let bin = |f: u32| bin_of(self.centroids[f as usize].data()[axis], min, scale);
+
+        // Partition the faces so those left of the split come first.
+        let faces = &mut self.faces[range];
+        let mut mid = 0;
+        for i in 0..faces.len() {
+            if bin(faces[i]) < split {
+                faces.swap(i, mid);
+                mid += 1;
+            }
+        }
+        let mid = mid as u32;
+
+        let left = self.nodes.len();
+        self.nodes.push(Node {
+            bounds: Aabb::empty(),
+            start,
+            count: mid,
+        });
+        self.nodes.push(Node {
+            bounds: Aabb::empty(),
+            start: start + mid,
+            count: count - mid,
+        });
+        self.nodes[node].start = left as u32;
+        self.nodes[node].count = 0;
+
+        self.subdivide(left);
+        self.subdivide(left + 1);
+    }
+
+    /// Axis, bin boundary and binning parameters of the cheapest split, or
+    /// `None` when no split beats keeping the faces in one leaf.
+    fn best_split(
+        &self,
+        range: std::ops::Range<usize>,
+        bounds: &Aabb<T>,
+    ) -> Option<(usize, usize, T, T)> {
+        let faces = &self.faces[range];
+        let centroid_bounds = Aabb::from_points(faces.iter().map(|&f| self.centroids[f as usize]));
+        let leaf_cost = T::from_f64(faces.len() as f64) * bounds.surface_area();
+
+        let mut best: Option<(T, (usize, usize, T, T))> = None;
+        for axis in 0..3 {
+            let min = centroid_bounds.min.data()[axis];
+            let max = centroid_bounds.max.data()[axis];
+            if max <= min {
+                continue;
+            }
+            let scale = T::from_f64(BINS as f64) / (max - min);
+
+            let mut bins = [(Aabb::empty(), 0u32); BINS];
+            for &f in faces {
+                let (b, n) = &mut bins[bin_of(self.centroids[f as usize].data()[axis], min, scale)];
+                *b = b.union(&self.bounds[f as usize]);
+                *n += 1;
+            }
+
+            // Sweep from the right to get the cost of every right side, then
+            // from the left to combine it with every left side.
+            let mut right = [T::ZERO; BINS];
+            let (mut b, mut n) = (Aabb::empty(), 0u32);
+            for i in (1..BINS).rev() {
+                b = b.union(&bins[i].0);
+                n += bins[i].1;
+                right[i] = if n == 0 {
+                    T::ZERO
+                } else {
+                    T::from_f64(n as f64) * b.surface_area()
+                };
+            }
+
+            let (mut b, mut n) = (Aabb::empty(), 0u32);
+            for split in 1..BINS {
+                b = b.union(&bins[split - 1].0);
+                n += bins[split - 1].1;
+                if n == 0 {
+                    continue;
+                }
+                let cost = T::from_f64(n as f64) * b.surface_area() + right[split];
+                if best.is_none_or(|(c, _)| cost < c) {
+                    best = Some((cost, (axis, split, min, scale)));
+                }
+            }
+        }
+
+        best.filter(|&(cost, _)| cost < leaf_cost)
+            .map(|(_, split)| split)
+    }
+}
+
+fn bin_of<T: Float>(c: T, min: T, scale: T) -> usize {
+    (((c - min) * scale).to_f64() as usize).min(BINS - 1)
+}
+
+impl<T: Float> Bvh<T> {
+    /// Builds over the triangles of `indices`; a trailing partial face is ignored.
+    pub fn new(positions: &[Vec3<T>], indices: &[u32]) -> Self {
+        let triangles: Vec<[Vec3<T>; 3]> = indices
+            .chunks_exact(3)
+            .map(|f| [f[0], f[1], f[2]].map(|i| positions[i as usize]))
+            .collect();
+        let bounds: Vec<_> = triangles.iter().map(|t| Aabb::from_points(*t)).collect();
+        let centroids: Vec<_> = bounds.iter().map(Aabb::center).collect();
+
+        let mut builder = Builder {
+            nodes: Vec::with_capacity(2 * triangles.len()),
+            faces: (0..triangles.len() as u32).collect(),
+            bounds: &bounds,
+            centroids: &centroids,
+        };
+        if !triangles.is_empty() {
+            builder.nodes.push(Node {
+                bounds: Aabb::empty(),
+                start: 0,
+                count: triangles.len() as u32,
+            });
+            builder.subdivide(0);
+        }
+
+        let Builder { nodes, faces, .. } = builder;
+        Self {
+            nodes,
+            triangles: faces.iter().map(|&f| triangles[f as usize]).collect(),
+            faces,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.triangles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.triangles.is_empty()
+    }
+
+    pub fn bounds(&self) -> Aabb<T> {
+        self.nodes.first().map_or(Aabb::empty(), |n| n.bounds)
+    }
+
+    /// Visits leaves whose bounds the ray enters before `t_max`, nearest
+    /// child first. `visit` returns the new `t_max`, or `None` to stop.
+    fn traverse<F>(&self, ray: &Ray<T>, mut t_max: T, mut visit: F)
+    where
+        F: FnMut(std::ops::Range<usize>, T) -> Option<T>,
+    {
+        let entry = |node: usize, t_max: T| {
+            ray.intersect_aabb(&self.nodes[node].bounds)
+                .map(|(t0, _)| t0)
+                .filter(|&t0| t0 <= t_max)
+        };
+
+        let mut stack = Vec::with_capacity(64);
+        if let Some(t) = self.nodes.first().and_then(|_| entry(0, t_max)) {
+            stack.push((0, t));
+        }
+        while let Some((node, t)) = stack.pop() {
+            if t > t_max {
+                continue;
+            }
+            let Node { start, count, .. } = self.nodes[node];
+            let start = start as usize;
+            if count > 0 {
+                match visit(start..start + count as usize, t_max) {
+                    Some(t) => t_max = t,
+                    None => return,
+                }
+                continue;
+            }
+
+            let children = [start, start + 1].map(|c| (c, entry(c, t_max)));
+            let [near, far] = match children {
+                [(_, Some(a)), (_, Some(b))] if b < a => [children[1], children[0]],
+                _ => children,
+            };
+            for (child, t) in [far, near] {
+                if let Some(t) = t {
+                    stack.push((child, t));
+                }
+            }
+        }
+    }
+
+    pub fn closest_hit(&self, ray: &Ray<T>) -> Option<BvhHit<T>> {
+        let mut closest = None;
+        self.traverse(ray, T::INFINITY, |range, mut t_max| {
+            for i in range {
+                let [a, b, c] = &self.triangles[i];
+                if let Some(hit) = ray.intersect_triangle(a, b, c).filter(|h| h.t < t_max) {
+                    t_max = hit.t;
+                    closest = Some(BvhHit {
+                        face: self.faces[i] as usize,
+                        hit,
+                    });
+                }
+            }
+            Some(t_max)
+        });
+        closest
+    }
+
+    /// Whether any triangle is hit within `t_max`; cheaper than
+    /// [`Bvh::closest_hit`] for occlusion and shadow rays.
+    pub fn any_hit(&self, ray: &Ray<T>, t_max: T) -> bool {
+        let mut found = false;
+        self.traverse(ray, t_max, |range, t_max| {
+            found = range.into_iter().any(|i| {
+                let [a, b, c] = &self.triangles[i];
+                ray.intersect_triangle(a, b, c)
+                    .is_some_and(|h| h.t <= t_max)
+            });
+            (!found).then_some(t_max)
+        });
+        found
+    }
+
+    /// Faces whose bounds overlap `aabb`.
+    pub fn query_aabb(&self, aabb: &Aabb<T>) -> Vec<usize> {
+        let mut faces = Vec::new();
+        let mut stack = Vec::with_capacity(64);
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(node) = stack.pop() {
+            let Node {
+                bounds,
+                start,
+                count,
+            } = self.nodes[node];
+            if !bounds.intersects(aabb) {
+                continue;
+            }
+            let start = start as usize;
+            if count == 0 {
+                stack.extend([start, start + 1]);
+                continue;
+            }
+            for i in start..start + count as usize {
+                if Aabb::from_points(self.triangles[i]).intersects(aabb) {
+                    faces.push(self.faces[i] as usize);
+                }
+            }
+        }
+        faces
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Pcg32;
+
+    fn random_soup(rng: &mut Pcg32, n: usize) -> (Vec<Vec3<f32>>, Vec<u32>) {
+        let mut positions = Vec::with_capacity(3 * n);
+        for _ in 0..n {
+            let center = Vec3::new(
+                rng.next_range(-10.0, 10.0),
+                rng.next_range(-10.0, 10.0),
+                rng.next_range(-10.0, 10.0),
+            );
+            for _ in 0..3 {
+                let offset = Vec3::new(
+                    rng.next_range(-1.0, 1.0),
+                    rng.next_range(-1.0, 1.0),
+                    rng.next_range(-1.0, 1.0),
+                );
+                positions.push(center + offset);
+            }
+        }
+        let indices = (0..3 * n as u32).collect();
+        (positions, indices)
+    }
+
+    fn brute_closest(
+        positions: &[Vec3<f32>],
+        indices: &[u32],
+        ray: &Ray<f32>,
+    ) -> Option<BvhHit<f32>> {
+        indices
+            .chunks_exact(3)
+            .enumerate()
+            .filter_map(|(face, f)| {
+                let [a, b, c] = [f[0], f[1], f[2]].map(|i| positions[i as usize]);
+                ray.intersect_triangle(&a, &b, &c)
+                    .map(|hit| BvhHit { face, hit })
+            })
+            .min_by(|a, b| a.hit.t.total_cmp(&b.hit.t))
+    }
+
+    fn random_ray(rng: &mut Pcg32) -> Ray<f32> {
+        let origin = Vec3::new(
+            rng.next_range(-15.0, 15.0),
+            rng.next_range(-15.0, 15.0),
+            rng.next_range(-15.0, 15.0),
+        );
+        let target = Vec3::new(
+            rng.next_range(-8.0, 8.0),
+            rng.next_range(-8.0, 8.0),
+            rng.next_range(-8.0, 8.0),
+        );
+        Ray::from_points(&origin, &target)
+    }
+
+    #[test]
+    fn test_bvh_empty() {
+        let bvh = Bvh::<f32>::new(&[], &[]);
+        let ray = Ray::new(Vec3::zero(), Vec3::new(0.0, 0.0, 1.0));
+        assert!(bvh.is_empty());
+        assert_eq!(bvh.closest_hit(&ray), None);
+        assert!(!bvh.any_hit(&ray, f32::INFINITY));
+        assert!(
+            bvh.query_aabb(&Aabb::new(Vec3::scalar(-1.0), Vec3::one()))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_bvh_matches_brute_force() {
+        let mut rng = Pcg32::from_seed(18);
+        let (positions, indices) = random_soup(&mut rng, 2000);
+        let bvh = Bvh::new(&positions, &indices);
+        assert_eq!(bvh.len(), 2000);
+        assert!(bvh.nodes.len() > 1);
+
+        let mut hits = 0;
+        for _ in 0..500 {
+            let ray = random_ray(&mut rng);
+            let expected = brute_closest(&positions, &indices, &ray);
+            let actual = bvh.closest_hit(&ray);
+            assert_eq!(actual.map(|h| h.hit.t), expected.map(|h| h.hit.t));
+            assert_eq!(actual.map(|h| h.face), expected.map(|h| h.face));
+
+            assert_eq!(bvh.any_hit(&ray, f32::INFINITY), expected.is_some());
+            if let Some(e) = expected {
+                hits += 1;
+                assert!(bvh.any_hit(&ray, e.hit.t));
+                assert!(!bvh.any_hit(&ray, e.hit.t * 0.999));
+            }
+        }
+        assert!(hits > 100, "{hits}");
+    }
+
+    #[test]
+    fn test_bvh_query_aabb() {
+        let mut rng = Pcg32::from_seed(7);
+        let (positions, indices) = random_soup(&mut rng, 1000);
+        let bvh = Bvh::new(&positions, &indices);
+
+        for _ in 0..50 {
+            let center = Vec3::new(
+                rng.next_range(-10.0, 10.0),
+                rng.next_range(-10.0, 10.0),
+                rng.next_range(-10.0, 10.0),
+            );
+            let half = Vec3::scalar(rng.next_range(0.1, 4.0));
+            let query = Aabb::new(center - half, center + half);
+
+            let mut expected: Vec<_> = indices
+                .chunks_exact(3)
+                .enumerate()
+                .filter(|(_, f)| {
+                    Aabb::from_points(f.iter().map(|&i| positions[i as usize])).intersects(&query)
+                })
+                .map(|(face, _)| face)
+                .collect();
+            let mut actual = bvh.query_aabb(&query);
+            expected.sort();
+            actual.sort();
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_bvh_shared_vertices() {
+        // A unit quad of two faces sharing an edge.
+        let positions = [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        ];
+        let bvh = Bvh::new(&positions, &[0, 1, 2, 0, 2, 3]);
+        assert_eq!(
+            bvh.bounds(),
+            Aabb::new(Vec3::zero(), Vec3::new(1.0, 1.0, 0.0))
+        );
+
+        let down = Vec3::new(0.0, 0.0, -1.0);
+        let hit = |x, y| bvh.closest_hit(&Ray::new(Vec3::new(x, y, 1.0), down));
+        assert_eq!(hit(0.75, 0.25).map(|h| h.face), Some(0));
+        assert_eq!(hit(0.25, 0.75).map(|h| h.face), Some(1));
+        assert_eq!(hit(1.5, 0.5), None);
+
+        let ray = Ray::new(Vec3::new(0.5, 0.25, 1.0), down);
+        let point = ray.at(hit(0.5, 0.25).unwrap().hit.t);
+        assert_eq!(point, Vec3::new(0.5, 0.25, 0.0));
+    }
+}
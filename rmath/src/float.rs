@@ -0,0 +1,269 @@
+use std::{
+    fmt::Debug,
+    iter::Sum,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign},
+};
+
+use crate::simd::Kernels;
+
+pub trait Float:
+    Kernels
+    + Copy
+    + Debug
+    + Default
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + Sum
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const TWO: Self;
+    const HALF: Self;
+    const PI: Self;
+    const EPSILON: Self;
+    const MIN: Self;
+    const MAX: Self;
+    const INFINITY: Self;
+    const NEG_INFINITY: Self;
+
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
+
+    fn sqrt(self) -> Self;
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn fract(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn clamp(self, min: Self, max: Self) -> Self;
+    fn to_radians(self) -> Self;
+    fn to_degrees(self) -> Self;
+    fn is_finite(self) -> bool;
+    fn is_nan(self) -> bool;
+    /// Number of representable values between `self` and `other`.
+    fn ulps(self, other: Self) -> u64;
+
+    #[inline]
+    fn cast<U: Float>(self) -> U {
+        U::from_f64(self.to_f64())
+    }
+}
+
+macro_rules! impl_float {
+    ($($t:ident: $bits:ident),*) => {$(
+        impl Float for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const TWO: Self = 2.0;
+            const HALF: Self = 0.5;
+            const PI: Self = std::$t::consts::PI;
+            const EPSILON: Self = $t::EPSILON;
+            const MIN: Self = $t::MIN;
+            const MAX: Self = $t::MAX;
+            const INFINITY: Self = $t::INFINITY;
+            const NEG_INFINITY: Self = $t::NEG_INFINITY;
+
+            #[inline]
+            fn from_f64(value: f64) -> Self {
+                value as $t
+            }
+
+            #[inline]
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            #[inline]
+            fn sqrt(self) -> Self {
+                $t::sqrt(self)
+            }
+
+            #[inline]
+            fn abs(self) -> Self {
+                $t::abs(self)
+            }
+
+            #[inline]
+            fn signum(self) -> Self {
+                $t::signum(self)
+            }
+
+            #[inline]
+            fn floor(self) -> Self {
+                $t::floor(self)
+            }
+
+            #[inline]
+            fn ceil(self) -> Self {
+                $t::ceil(self)
+            }
+
+            #[inline]
+            fn round(self) -> Self {
+                $t::round(self)
+            }
+
+            #[inline]
+            fn fract(self) -> Self {
+                $t::fract(self)
+            }
+
+            #[inline]
+            fn sin(self) -> Self {
+                $t::sin(self)
+            }
+
+            #[inline]
+            fn cos(self) -> Self {
+                $t::cos(self)
+            }
+
+            #[inline]
+            fn tan(self) -> Self {
+                $t::tan(self)
+            }
+
+            #[inline]
+            fn asin(self) -> Self {
+                $t::asin(self)
+            }
+
+            #[inline]
+            fn acos(self) -> Self {
+                $t::acos(self)
+            }
+
+            #[inline]
+            fn atan2(self, other: Self) -> Self {
+                $t::atan2(self, other)
+            }
+
+            #[inline]
+            fn exp(self) -> Self {
+                $t::exp(self)
+            }
+
+            #[inline]
+            fn ln(self) -> Self {
+                $t::ln(self)
+            }
+
+            #[inline]
+            fn powf(self, n: Self) -> Self {
+                $t::powf(self, n)
+            }
+
+            #[inline]
+            fn powi(self, n: i32) -> Self {
+                $t::powi(self, n)
+            }
+
+            #[inline]
+            fn min(self, other: Self) -> Self {
+                $t::min(self, other)
+            }
+
+            #[inline]
+            fn max(self, other: Self) -> Self {
+                $t::max(self, other)
+            }
+
+            #[inline]
+            fn clamp(self, min: Self, max: Self) -> Self {
+                $t::clamp(self, min, max)
+            }
+
+            #[inline]
+            fn to_radians(self) -> Self {
+                $t::to_radians(self)
+            }
+
+            #[inline]
+            fn to_degrees(self) -> Self {
+                $t::to_degrees(self)
+            }
+
+            #[inline]
+            fn is_finite(self) -> bool {
+                $t::is_finite(self)
+            }
+
+            #[inline]
+            fn is_nan(self) -> bool {
+                $t::is_nan(self)
+            }
+
+            #[inline]
+            fn ulps(self, other: Self) -> u64 {
+                // Remap the sign-magnitude bit patterns onto a monotonic
+                // integer line, with -0.0 and 0.0 both at zero.
+                let ordered = |f: Self| {
+                    let bits = f.to_bits() as $bits;
+                    if bits < 0 { $bits::MIN - bits } else { bits }
+                };
+                ordered(self).abs_diff(ordered(other)) as u64
+            }
+        }
+    )*};
+}
+
+impl_float!(f32: i32, f64: i64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hypot<T: Float>(a: T, b: T) -> T {
+        (a * a + b * b).sqrt()
+    }
+
+    #[test]
+    fn test_float_generic() {
+        assert_eq!(hypot(3.0f32, 4.0), 5.0);
+        assert_eq!(hypot(3.0f64, 4.0), 5.0);
+    }
+
+    #[test]
+    fn test_float_cast() {
+        assert_eq!(1.5f32.cast::<f64>(), 1.5f64);
+        assert_eq!(0.25f64.cast::<f32>(), 0.25f32);
+    }
+
+    #[test]
+    fn test_float_consts() {
+        assert_eq!(<f32 as Float>::PI, std::f32::consts::PI);
+        assert_eq!(<f64 as Float>::HALF * <f64 as Float>::TWO, 1.0);
+    }
+
+    #[test]
+    fn test_float_ulps() {
+        assert_eq!(1.0f32.ulps(1.0), 0);
+        assert_eq!(1.0f32.ulps(1.0 + f32::EPSILON), 1);
+        assert_eq!(0.0f32.ulps(-0.0), 0);
+        assert_eq!(f32::MIN_POSITIVE.ulps(-f32::MIN_POSITIVE), 2 * 0x0080_0000);
+        assert_eq!(1.0f64.ulps(1.0 - f64::EPSILON / 2.0), 1);
+    }
+}
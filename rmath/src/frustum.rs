@@ -0,0 +1,152 @@
+use crate::{bounds::Aabb, bounds::Sphere, float::Float, mat4::Mat4, plane::Plane, vec3::Vec3};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Containment {
+    Inside,
+    Intersecting,
+    Outside,
+}
+
+/// Six inward facing planes: left, right, bottom, top, near, far.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Frustum<T: Copy> {
+    pub planes: [Plane<T>; 6],
+}
+
+impl<T: Float> Frustum<T> {
+    /// Gribb/Hartmann extraction from an OpenGL style (`[-1, 1]` depth)
+    /// projection or view-projection matrix.
+    pub fn from_matrix(m: &Mat4<T>) -> Self {
+        let [r0, r1, r2, r3] = m.transpose().columns();
+        let planes = [r3 + r0, r3 - r0, r3 + r1, r3 - r1, r3 + r2, r3 - r2]
+            .map(|v| Plane::from(v).normalize());
+        Self { planes }
+    }
+
+    pub fn contains_point(&self, p: &Vec3<T>) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(p) >= T::ZERO)
+    }
+
+    pub fn classify_sphere(&self, sphere: &Sphere<T>) -> Containment {
+        let mut result = Containment::Inside;
+        for plane in &self.planes {
+            let distance = plane.signed_distance(&sphere.center);
+            if distance < -sphere.radius {
+                return Containment::Outside;
+            }
+            if distance < sphere.radius {
+                result = Containment::Intersecting;
+            }
+        }
+        result
+    }
+
+    pub fn classify_aabb(&self, aabb: &Aabb<T>) -> Containment {
+        let mut result = Containment::Inside;
+        for plane in &self.planes {
+            let n = plane.normal;
+            let pick = |a: T, lo: T, hi: T| if a >= T::ZERO { (hi, lo) } else { (lo, hi) };
+            let (px, nx) = pick(n.x, aabb.min.x, aabb.max.x);
+            let (py, ny) = pick(n.y, aabb.min.y, aabb.max.y);
+            let (pz, nz) = pick(n.z, aabb.min.z, aabb.max.z);
+
+            if plane.signed_distance(&Vec3::new(px, py, pz)) < T::ZERO {
+                return Containment::Outside;
+            }
+            if plane.signed_distance(&Vec3::new(nx, ny, nz)) < T::ZERO {
+                result = Containment::Intersecting;
+            }
+        }
+        result
+    }
+
+    pub fn is_visible(&self, aabb: &Aabb<T>) -> bool {
+        self.classify_aabb(aabb) != Containment::Outside
+    }
+}
+
+impl<T: Float> From<Mat4<T>> for Frustum<T> {
+    #[inline]
+    fn from(m: Mat4<T>) -> Self {
+        Self::from_matrix(&m)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frustum() -> Frustum<f32> {
+        let projection = Mat4::perspective(90.0, 1.0, 1.0, 100.0);
+        let view = Mat4::look_at(
+            &Vec3::zero(),
+            &Vec3::new(0.0, 0.0, -1.0),
+            &Vec3::new(0.0, 1.0, 0.0),
+        );
+        Frustum::from_matrix(&(projection * view))
+    }
+
+    #[test]
+    fn test_frustum_planes() {
+        let near = frustum().planes[4];
+        assert!((near.normal - Vec3::new(0.0, 0.0, -1.0)).length() < 1e-5);
+        assert!((near.d + 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_frustum_point() {
+        let f = frustum();
+        assert!(f.contains_point(&Vec3::new(0.0, 0.0, -10.0)));
+        assert!(f.contains_point(&Vec3::new(9.0, -9.0, -10.0)));
+        assert!(!f.contains_point(&Vec3::new(11.0, 0.0, -10.0)));
+        assert!(!f.contains_point(&Vec3::new(0.0, 0.0, 10.0)));
+        assert!(!f.contains_point(&Vec3::new(0.0, 0.0, -0.5)));
+        assert!(!f.contains_point(&Vec3::new(0.0, 0.0, -101.0)));
+    }
+
+    #[test]
+    fn test_frustum_sphere() {
+        let f = frustum();
+        let classify = |c: Vec3<f32>, r: f32| f.classify_sphere(&Sphere::new(c, r));
+        assert_eq!(
+            classify(Vec3::new(0.0, 0.0, -50.0), 1.0),
+            Containment::Inside
+        );
+        assert_eq!(
+            classify(Vec3::new(50.0, 0.0, -50.0), 1.0),
+            Containment::Intersecting
+        );
+        assert_eq!(
+            classify(Vec3::new(0.0, 0.0, 50.0), 1.0),
+            Containment::Outside
+        );
+        assert_eq!(
+            classify(Vec3::new(0.0, 0.0, -100.0), 5.0),
+            Containment::Intersecting
+        );
+    }
+
+    #[test]
+    fn test_frustum_aabb() {
+        let f = frustum();
+        let classify = |min: Vec3<f32>, max: Vec3<f32>| f.classify_aabb(&Aabb::new(min, max));
+        assert_eq!(
+            classify(Vec3::new(-1.0, -1.0, -11.0), Vec3::new(1.0, 1.0, -9.0)),
+            Containment::Inside
+        );
+        assert_eq!(
+            classify(Vec3::new(-1.0, -1.0, -2.0), Vec3::new(1.0, 1.0, 2.0)),
+            Containment::Intersecting
+        );
+        assert_eq!(
+            classify(Vec3::new(20.0, -1.0, -11.0), Vec3::new(22.0, 1.0, -9.0)),
+            Containment::Outside
+        );
+        assert!(!f.is_visible(&Aabb::new(
+            Vec3::new(-1.0, -1.0, 1.0),
+            Vec3::new(1.0, 1.0, 3.0)
+        )));
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{
+    float::Float,
+    interp::{Curve, Interpolate, inverse_lerp, lerp},
+};
+
+/// Cumulative chord lengths of a curve sampled at evenly spaced `t`, used
+/// to map a travelled distance back to the curve parameter for constant
+/// speed traversal.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArcLength<T> {
+    lengths: Vec<T>,
+}
+
+impl<T: Float> ArcLength<T> {
+    /// More `samples` trade build time for accuracy on tight bends.
+    pub fn new<V, C>(curve: &C, samples: usize) -> Self
+    where
+        V: Interpolate<T>,
+        C: Curve<T, V>,
+    {
+        let samples = samples.max(1);
+        let step = T::ONE / T::from_f64(samples as f64);
+
+        let mut lengths = Vec::with_capacity(samples + 1);
+        let mut total = T::ZERO;
+        let mut previous = curve.point(T::ZERO);
+        lengths.push(total);
+        for i in 1..=samples {
+            let point = curve.point(step * T::from_f64(i as f64));
+            total += previous.distance(point);
+            lengths.push(total);
+            previous = point;
+        }
+
+        Self { lengths }
+    }
+
+    pub fn length(&self) -> T {
+        *self.lengths.last().unwrap_or(&T::ZERO)
+    }
+
+    /// Curve parameter at `distance` along the curve, clamped to its ends.
+    pub fn param_at(&self, distance: T) -> T {
+        let total = self.length();
+        if distance <= T::ZERO || total <= T::ZERO {
+            return T::ZERO;
+        }
+        if distance >= total {
+            return T::ONE;
+        }
+
+        let i = self
+            .lengths
+            .partition_point(|&l| l <= distance)
+            .clamp(1, self.lengths.len() - 1);
+        let (l0, l1) = (self.lengths[i - 1], self.lengths[i]);
+        let segment = if l1 > l0 {
+            inverse_lerp(l0, l1, distance)
+        } else {
+            T::ZERO
+        };
+
+        let samples = T::from_f64((self.lengths.len() - 1) as f64);
+        lerp(T::from_f64((i - 1) as f64), T::from_f64(i as f64), segment) / samples
+    }
+
+    /// Curve parameter at `fraction` of the total length.
+    pub fn param_at_fraction(&self, fraction: T) -> T {
+        self.param_at(fraction * self.length())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        interp::{CatmullRom, CubicBezier},
+        vec2::Vec2,
+    };
+
+    #[test]
+    fn test_arc_length_line() {
+        let line = CubicBezier::new(
+            Vec2::new(0.0f64, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(10.0, 0.0),
+        );
+        let table = ArcLength::new(&line, 512);
+        assert!((table.length() - 10.0).abs() < 1e-9);
+
+        for d in [0.0, 2.5, 5.0, 7.5, 10.0] {
+            let p = line.point(table.param_at(d));
+            assert!((p.x - d).abs() < 1e-2, "{d}: {p:?}");
+        }
+        assert_eq!(table.param_at(-1.0), 0.0);
+        assert_eq!(table.param_at(11.0), 1.0);
+    }
+
+    #[test]
+    fn test_arc_length_constant_speed() {
+        let path = CatmullRom::new(vec![
+            Vec2::new(0.0f64, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.5, 3.0),
+            Vec2::new(6.0, 3.0),
+        ]);
+        let table = ArcLength::new(&path, 1024);
+
+        let travelled = |end: f64| {
+            let steps = 4096;
+            (0..steps)
+                .map(|i| {
+                    let t0 = end * i as f64 / steps as f64;
+                    let t1 = end * (i + 1) as f64 / steps as f64;
+                    path.point(t0).distance(path.point(t1))
+                })
+                .sum::<f64>()
+        };
+        for i in 0..=10 {
+            let fraction = i as f64 / 10.0;
+            let expected = fraction * table.length();
+            let actual = travelled(table.param_at_fraction(fraction));
+            assert!((actual - expected).abs() < 1e-2, "{actual} vs {expected}");
+        }
+    }
+}
@@ -0,0 +1,184 @@
+//! Easing curves mapping `t` in `[0, 1]` onto `[0, 1]`, with `f(0) == 0`
+//! and `f(1) == 1`. `back` and `elastic` overshoot in between.
+
+use crate::float::Float;
+
+#[inline]
+fn c<T: Float>(value: f64) -> T {
+    T::from_f64(value)
+}
+
+/// Builds the `out` variant of an `in` curve.
+#[inline]
+fn out<T: Float>(ease_in: fn(T) -> T, t: T) -> T {
+    T::ONE - ease_in(T::ONE - t)
+}
+
+/// Builds the `in_out` variant of an `in` curve.
+#[inline]
+fn in_out<T: Float>(ease_in: fn(T) -> T, t: T) -> T {
+    if t < T::HALF {
+        ease_in(t * T::TWO) * T::HALF
+    } else {
+        T::ONE - ease_in((T::ONE - t) * T::TWO) * T::HALF
+    }
+}
+
+macro_rules! easing {
+    ($($name:ident, $name_out:ident, $name_in_out:ident;)*) => {$(
+        pub fn $name_out<T: Float>(t: T) -> T {
+            out($name, t)
+        }
+
+        pub fn $name_in_out<T: Float>(t: T) -> T {
+            in_out($name, t)
+        }
+    )*};
+}
+
+#[inline]
+pub fn linear<T: Float>(t: T) -> T {
+    t
+}
+
+pub fn quad_in<T: Float>(t: T) -> T {
+    t * t
+}
+
+pub fn cubic_in<T: Float>(t: T) -> T {
+    t * t * t
+}
+
+pub fn quart_in<T: Float>(t: T) -> T {
+    t.powi(4)
+}
+
+pub fn quint_in<T: Float>(t: T) -> T {
+    t.powi(5)
+}
+
+pub fn sine_in<T: Float>(t: T) -> T {
+    T::ONE - (t * T::PI * T::HALF).cos()
+}
+
+pub fn expo_in<T: Float>(t: T) -> T {
+    if t <= T::ZERO {
+        T::ZERO
+    } else {
+        T::TWO.powf(c::<T>(10.0) * (t - T::ONE))
+    }
+}
+
+pub fn circ_in<T: Float>(t: T) -> T {
+    T::ONE - (T::ONE - t * t).max(T::ZERO).sqrt()
+}
+
+/// Pulls back slightly below zero before accelerating.
+pub fn back_in<T: Float>(t: T) -> T {
+    let s = c::<T>(1.70158);
+    t * t * ((s + T::ONE) * t - s)
+}
+
+pub fn elastic_in<T: Float>(t: T) -> T {
+    if t <= T::ZERO || t >= T::ONE {
+        return t.clamp(T::ZERO, T::ONE);
+    }
+    let period = T::TWO * T::PI / c(0.3);
+    -(T::TWO.powf(c::<T>(10.0) * (t - T::ONE))) * ((t - c(1.075)) * period).sin()
+}
+
+pub fn bounce_in<T: Float>(t: T) -> T {
+    T::ONE - bounce_out(T::ONE - t)
+}
+
+pub fn bounce_out<T: Float>(t: T) -> T {
+    let n = c::<T>(7.5625);
+    let d = c::<T>(2.75);
+    if t < T::ONE / d {
+        n * t * t
+    } else if t < T::TWO / d {
+        let t = t - c::<T>(1.5) / d;
+        n * t * t + c(0.75)
+    } else if t < c::<T>(2.5) / d {
+        let t = t - c::<T>(2.25) / d;
+        n * t * t + c(0.9375)
+    } else {
+        let t = t - c::<T>(2.625) / d;
+        n * t * t + c(0.984375)
+    }
+}
+
+pub fn bounce_in_out<T: Float>(t: T) -> T {
+    in_out(bounce_in, t)
+}
+
+easing! {
+    quad_in, quad_out, quad_in_out;
+    cubic_in, cubic_out, cubic_in_out;
+    quart_in, quart_out, quart_in_out;
+    quint_in, quint_out, quint_in_out;
+    sine_in, sine_out, sine_in_out;
+    expo_in, expo_out, expo_in_out;
+    circ_in, circ_out, circ_in_out;
+    back_in, back_out, back_in_out;
+    elastic_in, elastic_out, elastic_in_out;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CURVES: &[fn(f64) -> f64] = &[
+        linear,
+        quad_in,
+        quad_out,
+        quad_in_out,
+        cubic_in,
+        cubic_out,
+        cubic_in_out,
+        quart_in,
+        quart_out,
+        quart_in_out,
+        quint_in,
+        quint_out,
+        quint_in_out,
+        sine_in,
+        sine_out,
+        sine_in_out,
+        expo_in,
+        expo_out,
+        expo_in_out,
+        circ_in,
+        circ_out,
+        circ_in_out,
+        back_in,
+        back_out,
+        back_in_out,
+        elastic_in,
+        elastic_out,
+        elastic_in_out,
+        bounce_in,
+        bounce_out,
+        bounce_in_out,
+    ];
+
+    #[test]
+    fn test_easing_endpoints() {
+        for (i, ease) in CURVES.iter().enumerate() {
+            assert!(ease(0.0).abs() < 1e-3, "curve {i} at 0: {}", ease(0.0));
+            assert!(
+                (ease(1.0) - 1.0).abs() < 1e-3,
+                "curve {i} at 1: {}",
+                ease(1.0)
+            );
+        }
+    }
+
+    #[test]
+    fn test_easing_symmetry() {
+        assert!((quad_in_out(0.5f64) - 0.5).abs() < 1e-12);
+        assert!((sine_in_out(0.25f64) + sine_in_out(0.75) - 1.0).abs() < 1e-12);
+        assert!((cubic_out(0.3f32) - (1.0 - cubic_in(0.7f32))).abs() < 1e-6);
+        assert!(back_in(0.2f32) < 0.0);
+    }
+}
@@ -0,0 +1,133 @@
+pub mod arc_length;
+pub mod easing;
+pub mod spline;
+
+use std::ops::{Add, Sub};
+
+pub use arc_length::ArcLength;
+pub use spline::{CatmullRom, CubicBezier, Curve, Hermite};
+
+use crate::{Scalar, float::Float, vec2::Vec2, vec3::Vec3, vec4::Vec4};
+
+/// Values that can be blended with weights of type `T`: the scalars
+/// themselves and the vector types.
+pub trait Interpolate<T: Float>: Copy + Add<Output = Self> + Sub<Output = Self> {
+    fn scale(self, s: T) -> Self;
+
+    fn dot(self, other: Self) -> T;
+
+    #[inline]
+    fn distance(self, other: Self) -> T {
+        let d = other - self;
+        d.dot(d).sqrt()
+    }
+}
+
+impl<T: Float> Interpolate<T> for T {
+    #[inline]
+    fn scale(self, s: T) -> Self {
+        self * s
+    }
+
+    #[inline]
+    fn dot(self, other: Self) -> T {
+        self * other
+    }
+}
+
+impl<T: Float> Interpolate<T> for Vec2<T> {
+    #[inline]
+    fn scale(self, s: T) -> Self {
+        self * Scalar(s)
+    }
+
+    #[inline]
+    fn dot(self, other: Self) -> T {
+        Vec2::dot(&self, &other)
+    }
+}
+
+impl<T: Float> Interpolate<T> for Vec3<T> {
+    #[inline]
+    fn scale(self, s: T) -> Self {
+        self * Scalar(s)
+    }
+
+    #[inline]
+    fn dot(self, other: Self) -> T {
+        Vec3::dot(&self, &other)
+    }
+}
+
+impl<T: Float> Interpolate<T> for Vec4<T> {
+    #[inline]
+    fn scale(self, s: T) -> Self {
+        self * Scalar(s)
+    }
+
+    #[inline]
+    fn dot(self, other: Self) -> T {
+        Vec4::dot(&self, &other)
+    }
+}
+
+#[inline]
+pub fn lerp<T: Float, V: Interpolate<T>>(a: V, b: V, t: T) -> V {
+    a + (b - a).scale(t)
+}
+
+/// Position of `value` between `a` and `b`, `0` at `a` and `1` at `b`.
+#[inline]
+pub fn inverse_lerp<T: Float>(a: T, b: T, value: T) -> T {
+    (value - a) / (b - a)
+}
+
+/// Maps `value` from `[from_min, from_max]` onto `[to_min, to_max]` without clamping.
+#[inline]
+pub fn remap<T: Float>(value: T, from_min: T, from_max: T, to_min: T, to_max: T) -> T {
+    lerp(to_min, to_max, inverse_lerp(from_min, from_max, value))
+}
+
+pub fn smoothstep<T: Float>(edge0: T, edge1: T, x: T) -> T {
+    let t = inverse_lerp(edge0, edge1, x).clamp(T::ZERO, T::ONE);
+    t * t * (T::from_f64(3.0) - T::TWO * t)
+}
+
+pub fn smootherstep<T: Float>(edge0: T, edge1: T, x: T) -> T {
+    let t = inverse_lerp(edge0, edge1, x).clamp(T::ZERO, T::ONE);
+    t * t * t * (t * (t * T::from_f64(6.0) - T::from_f64(15.0)) + T::from_f64(10.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interp_lerp() {
+        assert_eq!(lerp(2.0f32, 6.0, 0.25), 3.0);
+        assert_eq!(
+            lerp(Vec3::new(0.0, 2.0, 4.0), Vec3::new(4.0, 2.0, 0.0), 0.5),
+            Vec3::new(2.0, 2.0, 2.0)
+        );
+        assert_eq!(
+            lerp(Vec2::new(1.0f64, 1.0), Vec2::new(3.0, -1.0), 1.0),
+            Vec2::new(3.0, -1.0)
+        );
+    }
+
+    #[test]
+    fn test_interp_remap() {
+        assert_eq!(inverse_lerp(10.0f32, 20.0, 15.0), 0.5);
+        assert_eq!(remap(5.0f32, 0.0, 10.0, 100.0, 200.0), 150.0);
+        assert_eq!(remap(20.0f32, 0.0, 10.0, 0.0, -1.0), -2.0);
+    }
+
+    #[test]
+    fn test_interp_smoothstep() {
+        assert_eq!(smoothstep(0.0f32, 1.0, -1.0), 0.0);
+        assert_eq!(smoothstep(0.0f32, 1.0, 0.5), 0.5);
+        assert_eq!(smoothstep(0.0f32, 1.0, 2.0), 1.0);
+        assert_eq!(smootherstep(2.0f64, 4.0, 3.0), 0.5);
+        assert_eq!(smootherstep(2.0f64, 4.0, 4.0), 1.0);
+    }
+}
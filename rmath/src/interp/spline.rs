@@ -0,0 +1,197 @@
+use std::marker::PhantomData;
+
+use crate::{float::Float, interp::Interpolate};
+
+/// A parametric curve over `t` in `[0, 1]`.
+pub trait Curve<T: Float, V: Interpolate<T>> {
+    fn point(&self, t: T) -> V;
+
+    /// Derivative of [`Curve::point`] with respect to `t`.
+    fn tangent(&self, t: T) -> V;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CubicBezier<V> {
+    pub p0: V,
+    pub p1: V,
+    pub p2: V,
+    pub p3: V,
+}
+
+impl<V> CubicBezier<V> {
+    #[inline]
+    pub const fn new(p0: V, p1: V, p2: V, p3: V) -> Self {
+        Self { p0, p1, p2, p3 }
+    }
+}
+
+impl<T: Float, V: Interpolate<T>> Curve<T, V> for CubicBezier<V> {
+    fn point(&self, t: T) -> V {
+        let s = T::ONE - t;
+        let three = T::from_f64(3.0);
+        self.p0.scale(s * s * s)
+            + self.p1.scale(three * s * s * t)
+            + self.p2.scale(three * s * t * t)
+            + self.p3.scale(t * t * t)
+    }
+
+    fn tangent(&self, t: T) -> V {
+        let s = T::ONE - t;
+        let three = T::from_f64(3.0);
+        (self.p1 - self.p0).scale(three * s * s)
+            + (self.p2 - self.p1).scale(T::from_f64(6.0) * s * t)
+            + (self.p3 - self.p2).scale(three * t * t)
+    }
+}
+
+/// Cubic through `p0` and `p1` with tangents `m0` and `m1`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hermite<V> {
+    pub p0: V,
+    pub m0: V,
+    pub p1: V,
+    pub m1: V,
+}
+
+impl<V> Hermite<V> {
+    #[inline]
+    pub const fn new(p0: V, m0: V, p1: V, m1: V) -> Self {
+        Self { p0, m0, p1, m1 }
+    }
+}
+
+impl<T: Float, V: Interpolate<T>> Curve<T, V> for Hermite<V> {
+    fn point(&self, t: T) -> V {
+        let (t2, t3) = (t * t, t * t * t);
+        let (two, three) = (T::TWO, T::from_f64(3.0));
+        self.p0.scale(two * t3 - three * t2 + T::ONE)
+            + self.m0.scale(t3 - two * t2 + t)
+            + self.p1.scale(three * t2 - two * t3)
+            + self.m1.scale(t3 - t2)
+    }
+
+    fn tangent(&self, t: T) -> V {
+        let t2 = t * t;
+        let (two, three, four, six) =
+            (T::TWO, T::from_f64(3.0), T::from_f64(4.0), T::from_f64(6.0));
+        self.p0.scale(six * t2 - six * t)
+            + self.m0.scale(three * t2 - four * t + T::ONE)
+            + self.p1.scale(six * t - six * t2)
+            + self.m1.scale(three * t2 - two * t)
+    }
+}
+
+/// Uniform Catmull-Rom spline passing through every control point, with
+/// `t` spread evenly over the segments.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CatmullRom<T, V> {
+    pub points: Vec<V>,
+    _float: PhantomData<T>,
+}
+
+impl<T: Float, V: Interpolate<T>> CatmullRom<T, V> {
+    pub fn new(points: Vec<V>) -> Self {
+        Self {
+            points,
+            _float: PhantomData,
+        }
+    }
+
+    pub fn segments(&self) -> usize {
+        self.points.len().saturating_sub(1)
+    }
+
+    /// Hermite form of segment `i`; the end points act as their own neighbours.
+    pub fn segment(&self, i: usize) -> Hermite<V> {
+        let last = self.points.len() - 1;
+        let p = |j: usize| self.points[j.min(last)];
+        let (p0, p1, p2, p3) = (p(i.saturating_sub(1)), p(i), p(i + 1), p(i + 2));
+        Hermite::new(p1, (p2 - p0).scale(T::HALF), p2, (p3 - p1).scale(T::HALF))
+    }
+
+    /// Segment index and local parameter for global `t`.
+    fn locate(&self, t: T) -> (usize, T) {
+        let n = self.segments();
+        let x = t.clamp(T::ZERO, T::ONE) * T::from_f64(n as f64);
+        let i = (x.floor().to_f64() as usize).min(n - 1);
+        (i, x - T::from_f64(i as f64))
+    }
+}
+
+impl<T: Float, V: Interpolate<T>> Curve<T, V> for CatmullRom<T, V> {
+    /// Panics if there are no control points.
+    fn point(&self, t: T) -> V {
+        if self.segments() == 0 {
+            return self.points[0];
+        }
+        let (i, u) = self.locate(t);
+        self.segment(i).point(u)
+    }
+
+    fn tangent(&self, t: T) -> V {
+        if self.segments() == 0 {
+            return self.points[0].scale(T::ZERO);
+        }
+        let (i, u) = self.locate(t);
+        let n = T::from_f64(self.segments() as f64);
+        self.segment(i).tangent(u).scale(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vec3::Vec3;
+
+    fn assert_near(a: Vec3<f64>, b: Vec3<f64>) {
+        assert!((a - b).length() < 1e-9, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn test_spline_bezier() {
+        let curve = CubicBezier::new(
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+        );
+        assert_near(curve.point(0.0), curve.p0);
+        assert_near(curve.point(1.0), curve.p3);
+        assert_near(curve.point(0.5), Vec3::new(0.5, 0.75, 0.0));
+        assert_near(curve.tangent(0.0), Vec3::new(0.0, 3.0, 0.0));
+    }
+
+    #[test]
+    fn test_spline_hermite() {
+        let curve = Hermite::new(
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        );
+        assert_near(curve.point(0.0), curve.p0);
+        assert_near(curve.point(1.0), curve.p1);
+        assert_near(curve.tangent(0.0), curve.m0);
+        assert_near(curve.tangent(1.0), curve.m1);
+    }
+
+    #[test]
+    fn test_spline_catmull_rom() {
+        let points = vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 2.0, 0.0),
+            Vec3::new(3.0, 2.0, 1.0),
+            Vec3::new(4.0, 0.0, 1.0),
+        ];
+        let curve = CatmullRom::new(points.clone());
+        assert_eq!(curve.segments(), 3);
+        for (i, p) in points.iter().enumerate() {
+            assert_near(curve.point(i as f64 / 3.0), *p);
+        }
+
+        let h = 1e-6;
+        let t = 0.4;
+        let numeric = (curve.point(t + h) - curve.point(t - h)) / (2.0 * h);
+        assert!((numeric - curve.tangent(t)).length() < 1e-4);
+    }
+}
@@ -2,14 +2,33 @@ use std::ops::{
     Add, AddAssign, Deref, Div, DivAssign, Mul, MulAssign, Neg, Not, Shl, Shr, Sub, SubAssign,
 };
 
+pub mod approx;
+pub mod bounds;
+pub mod bvh;
+pub mod float;
+pub mod frustum;
+pub mod interp;
 pub mod mat3;
 pub mod mat4;
+pub mod noise;
+pub mod plane;
+#[cfg(feature = "bytemuck")]
+mod pod;
+pub mod quat;
+pub mod random;
+pub mod ray;
+pub mod simd;
+#[cfg(feature = "gl")]
+mod std140;
+mod swizzle;
+pub mod transform;
 pub mod vec2;
 pub mod vec3;
 pub mod vec4;
 
 #[repr(transparent)]
 #[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Scalar<T>(pub T);
 
 impl<T> Deref for Scalar<T> {
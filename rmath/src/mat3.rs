@@ -1,13 +1,11 @@
-use std::{
-    iter::Sum,
-    ops::{Add, Mul, Sub},
-};
+use std::ops::{Add, Mul, Sub};
 
 const RANK: usize = 3;
-use crate::vec3::Vec3;
+use crate::{Scalar, float::Float, mat4::Mat4, vec3::Vec3};
 
 #[repr(C)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Mat3<T: Copy> {
     cols: [Vec3<T>; RANK],
 }
@@ -17,6 +15,11 @@ impl<T: Copy> Mat3<T> {
         Self { cols: [c0, c1, c2] }
     }
 
+    #[inline]
+    pub const fn columns(&self) -> [Vec3<T>; RANK] {
+        self.cols
+    }
+
     pub const fn cols(&self) -> [T; RANK * RANK] {
         [
             self.cols[0].x,
@@ -40,6 +43,53 @@ impl<T: Copy> Mat3<T> {
     }
 }
 
+impl<T: Float> Mat3<T> {
+    #[inline]
+    pub const fn identity() -> Self {
+        Self::new(
+            Vec3::new(T::ONE, T::ZERO, T::ZERO),
+            Vec3::new(T::ZERO, T::ONE, T::ZERO),
+            Vec3::new(T::ZERO, T::ZERO, T::ONE),
+        )
+    }
+
+    pub fn determinant(&self) -> T {
+        let [a, b, c] = self.cols;
+        a.dot(&b.cross(&c))
+    }
+
+    pub fn invert(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det == T::ZERO {
+            return None;
+        }
+
+        let inv_det = Scalar(T::ONE / det);
+        let Self { cols: [r0, r1, r2] } = self.cofactor().transpose();
+
+        Some(Self::new(r0 * inv_det, r1 * inv_det, r2 * inv_det))
+    }
+
+    /// Transpose of the adjugate, i.e. the inverse transpose times the
+    /// determinant. Unlike the inverse it exists for singular matrices.
+    pub fn cofactor(&self) -> Self {
+        let [a, b, c] = self.cols;
+        Self::new(b.cross(&c), c.cross(&a), a.cross(&b))
+    }
+
+    pub fn cast<U: Float>(&self) -> Mat3<U> {
+        let [c0, c1, c2] = self.cols;
+        Mat3::new(c0.cast(), c1.cast(), c2.cast())
+    }
+}
+
+impl<T: Copy> From<Mat4<T>> for Mat3<T> {
+    fn from(m: Mat4<T>) -> Self {
+        let [c0, c1, c2, _] = m.columns();
+        Self::new(c0.truncate(), c1.truncate(), c2.truncate())
+    }
+}
+
 impl<T: Copy + Add<Output = T>> Add for Mat3<T> {
     type Output = Self;
 
@@ -68,27 +118,107 @@ impl<T: Copy + Sub<Output = T>> Sub for Mat3<T> {
     }
 }
 
-impl<T: Copy + Mul<Output = T> + Default + Add<Output = T> + Sum> Mul for Mat3<T> {
+impl<T: Copy + Mul<Output = T> + Add<Output = T>> Mul<Vec3<T>> for Mat3<T> {
+    type Output = Vec3<T>;
+
+    fn mul(self, Vec3 { x, y, z }: Vec3<T>) -> Vec3<T> {
+        let [c0, c1, c2] = self.cols;
+        c0 * Scalar(x) + c1 * Scalar(y) + c2 * Scalar(z)
+    }
+}
+
+impl<T: Copy + Mul<Output = T> + Add<Output = T>> Mul for Mat3<T> {
     type Output = Self;
 
     fn mul(self, other: Self) -> Self {
-        let result = Mat3::new(
-            Vec3::new(T::default(), T::default(), T::default()),
-            Vec3::new(T::default(), T::default(), T::default()),
-            Vec3::new(T::default(), T::default(), T::default()),
+        let [c0, c1, c2] = other.cols;
+        Self::new(self * c0, self * c1, self * c2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_approx_eq;
+
+    #[test]
+    fn test_mat3_transpose() {
+        let a = Mat3::new(
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(4.0, 5.0, 6.0),
+            Vec3::new(7.0, 8.0, 9.0),
+        );
+        assert_eq!(
+            a.transpose().cols(),
+            [1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0]
         );
+        assert_eq!(Mat3::<f32>::identity().transpose(), Mat3::identity());
+    }
 
-        for i in 0..RANK {
-            for j in 0..RANK {
-                result.cols[i].data()[j] = self.cols[i]
-                    .data()
-                    .iter()
-                    .zip(other.cols[j].data())
-                    .map(|(a, b)| a.clone() * b)
-                    .sum();
-            }
-        }
+    #[test]
+    fn test_mat3_determinant() {
+        assert_eq!(Mat3::<f32>::identity().determinant(), 1.0);
+        let a = Mat3::new(
+            Vec3::new(2.0, 0.0, 1.0),
+            Vec3::new(1.0, 3.0, 2.0),
+            Vec3::new(1.0, 1.0, 2.0),
+        );
+        assert_eq!(a.determinant(), 6.0);
+    }
 
-        result
+    #[test]
+    fn test_mat3_invert() {
+        let a = Mat3::new(
+            Vec3::new(2.0, 0.0, 1.0),
+            Vec3::new(1.0, 3.0, 2.0),
+            Vec3::new(1.0, 1.0, 2.0),
+        );
+        let inv = a.invert().unwrap();
+        assert_approx_eq!(a * inv, Mat3::identity(), abs = 1e-5);
+        assert_approx_eq!(inv * a, Mat3::identity(), abs = 1e-5);
+    }
+
+    #[test]
+    fn test_mat3_invert_f64() {
+        let a = Mat3::new(
+            Vec3::new(2.0f64, 0.0, 1.0),
+            Vec3::new(1.0, 3.0, 2.0),
+            Vec3::new(1.0, 1.0, 2.0),
+        );
+        let inv = a.invert().unwrap();
+        assert_eq!(a.determinant(), 6.0);
+        assert!((a * inv * Vec3::new(1.0, 2.0, 3.0) - Vec3::new(1.0, 2.0, 3.0)).length() < 1e-12);
+    }
+
+    #[test]
+    fn test_mat3_invert_singular() {
+        let a = Mat3::new(
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(2.0, 4.0, 6.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        );
+        assert_eq!(a.invert(), None);
+    }
+
+    #[test]
+    fn test_mat3_mul_vec3() {
+        let a = Mat3::new(
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 2.0, 0.0),
+            Vec3::new(1.0, 0.0, 3.0),
+        );
+        assert_eq!(a * Vec3::new(1.0, 1.0, 1.0), Vec3::new(2.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn test_mat3_from_mat4() {
+        let m =
+            Mat4::translation(&Vec3::new(4.0, 5.0, 6.0)) * Mat4::scale(&Vec3::new(1.0, 2.0, 3.0));
+        let expected = Mat3::new(
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 2.0, 0.0),
+            Vec3::new(0.0, 0.0, 3.0),
+        );
+        assert_eq!(Mat3::from(m), expected);
     }
 }
@@ -1,14 +1,12 @@
-use std::{
-    iter::Sum,
-    ops::{Add, Mul, Sub},
-};
+use std::ops::{Add, Mul, Sub};
 
 const RANK: usize = 4;
 
-use crate::vec4::Vec4;
+use crate::{float::Float, mat3::Mat3, quat::Quat, simd::Kernels, vec3::Vec3, vec4::Vec4};
 
 #[repr(C)]
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Mat4<T: Copy> {
     cols: [Vec4<T>; RANK],
 }
@@ -21,6 +19,11 @@ impl<T: Copy> Mat4<T> {
         }
     }
 
+    #[inline]
+    pub const fn columns(&self) -> [Vec4<T>; RANK] {
+        self.cols
+    }
+
     pub const fn cols(&self) -> [T; RANK * RANK] {
         [
             self.cols[0].x,
@@ -41,146 +44,173 @@ impl<T: Copy> Mat4<T> {
             self.cols[3].w,
         ]
     }
+}
 
+impl<T: Kernels> Mat4<T> {
+    #[inline]
     pub fn transpose(&self) -> Self {
+        T::mat4_transpose(self)
+    }
+}
+
+impl<T: Float> Mat4<T> {
+    #[inline]
+    pub const fn identity() -> Self {
         Self::new(
+            Vec4::new(T::ONE, T::ZERO, T::ZERO, T::ZERO),
+            Vec4::new(T::ZERO, T::ONE, T::ZERO, T::ZERO),
+            Vec4::new(T::ZERO, T::ZERO, T::ONE, T::ZERO),
+            Vec4::new(T::ZERO, T::ZERO, T::ZERO, T::ONE),
+        )
+    }
+
+    #[inline]
+    pub fn invert(&self) -> Option<Self> {
+        T::mat4_invert(self)
+    }
+
+    pub fn translation(Vec3 { x, y, z }: &Vec3<T>) -> Self {
+        Self::new(
+            Vec4::new(T::ONE, T::ZERO, T::ZERO, T::ZERO),
+            Vec4::new(T::ZERO, T::ONE, T::ZERO, T::ZERO),
+            Vec4::new(T::ZERO, T::ZERO, T::ONE, T::ZERO),
+            Vec4::new(*x, *y, *z, T::ONE),
+        )
+    }
+
+    /// Rotation of `angle` degrees around `axis`, matching [`Vec3::rotate`].
+    pub fn rotation(angle: T, axis: &Vec3<T>) -> Self {
+        Quat::from_axis_angle(angle, axis).to_mat4()
+    }
+
+    pub fn scale(Vec3 { x, y, z }: &Vec3<T>) -> Self {
+        Self::new(
+            Vec4::new(*x, T::ZERO, T::ZERO, T::ZERO),
+            Vec4::new(T::ZERO, *y, T::ZERO, T::ZERO),
+            Vec4::new(T::ZERO, T::ZERO, *z, T::ZERO),
+            Vec4::new(T::ZERO, T::ZERO, T::ZERO, T::ONE),
+        )
+    }
+
+    pub fn look_at(eye: &Vec3<T>, center: &Vec3<T>, up: &Vec3<T>) -> Self {
+        let f = (*center - *eye).normalize();
+        let s = f.cross(up).normalize();
+        let u = s.cross(&f);
+
+        Self::new(
+            Vec4::new(s.x, u.x, -f.x, T::ZERO),
+            Vec4::new(s.y, u.y, -f.y, T::ZERO),
+            Vec4::new(s.z, u.z, -f.z, T::ZERO),
+            Vec4::new(-s.dot(eye), -u.dot(eye), f.dot(eye), T::ONE),
+        )
+    }
+
+    /// OpenGL style projection mapping `[-near, -far]` to `[-1, 1]`.
+    pub fn perspective(fov_deg: T, aspect_ratio: T, near_plane: T, far_plane: T) -> Self {
+        let f = T::ONE / (fov_deg.to_radians() * T::HALF).tan();
+        let range_inv = T::ONE / (far_plane - near_plane);
+
+        Self::new(
+            Vec4::new(f / aspect_ratio, T::ZERO, T::ZERO, T::ZERO),
+            Vec4::new(T::ZERO, f, T::ZERO, T::ZERO),
             Vec4::new(
-                self.cols[0].x,
-                self.cols[1].x,
-                self.cols[2].x,
-                self.cols[3].x,
-            ),
-            Vec4::new(
-                self.cols[0].y,
-                self.cols[1].y,
-                self.cols[2].y,
-                self.cols[3].y,
+                T::ZERO,
+                T::ZERO,
+                -(far_plane + near_plane) * range_inv,
+                -T::ONE,
             ),
             Vec4::new(
-                self.cols[0].z,
-                self.cols[1].z,
-                self.cols[2].z,
-                self.cols[3].z,
+                T::ZERO,
+                T::ZERO,
+                -T::TWO * far_plane * near_plane * range_inv,
+                T::ZERO,
             ),
+        )
+    }
+
+    /// Maps `[-near, -far]` to `[1, 0]`, for use with a `[0, 1]` clip range.
+    pub fn perspective_reversed_z(
+        fov_deg: T,
+        aspect_ratio: T,
+        near_plane: T,
+        far_plane: T,
+    ) -> Self {
+        let f = T::ONE / (fov_deg.to_radians() * T::HALF).tan();
+        let range_inv = T::ONE / (far_plane - near_plane);
+
+        Self::new(
+            Vec4::new(f / aspect_ratio, T::ZERO, T::ZERO, T::ZERO),
+            Vec4::new(T::ZERO, f, T::ZERO, T::ZERO),
+            Vec4::new(T::ZERO, T::ZERO, near_plane * range_inv, -T::ONE),
             Vec4::new(
-                self.cols[0].w,
-                self.cols[1].w,
-                self.cols[2].w,
-                self.cols[3].w,
+                T::ZERO,
+                T::ZERO,
+                far_plane * near_plane * range_inv,
+                T::ZERO,
             ),
         )
     }
-}
 
-impl Mat4<f32> {
-    #[inline]
-    pub const fn identity() -> Self {
+    /// Reversed-Z projection with the far plane at infinity.
+    pub fn perspective_infinite_reversed_z(fov_deg: T, aspect_ratio: T, near_plane: T) -> Self {
+        let f = T::ONE / (fov_deg.to_radians() * T::HALF).tan();
+
         Self::new(
-            Vec4::new(1.0, 0.0, 0.0, 0.0),
-            Vec4::new(0.0, 1.0, 0.0, 0.0),
-            Vec4::new(0.0, 0.0, 1.0, 0.0),
-            Vec4::new(0.0, 0.0, 0.0, 1.0),
+            Vec4::new(f / aspect_ratio, T::ZERO, T::ZERO, T::ZERO),
+            Vec4::new(T::ZERO, f, T::ZERO, T::ZERO),
+            Vec4::new(T::ZERO, T::ZERO, T::ZERO, -T::ONE),
+            Vec4::new(T::ZERO, T::ZERO, near_plane, T::ZERO),
         )
     }
 
-    pub fn invert(&self) -> Option<Self> {
-        let mut inv = [0.0; 16];
-        let m = self.cols();
-
-        inv[0] = m[5] * m[10] * m[15] - m[5] * m[11] * m[14] - m[9] * m[6] * m[15]
-            + m[9] * m[7] * m[14]
-            + m[13] * m[6] * m[11]
-            - m[13] * m[7] * m[10];
-
-        inv[4] = -m[4] * m[10] * m[15] + m[4] * m[11] * m[14] + m[8] * m[6] * m[15]
-            - m[8] * m[7] * m[14]
-            - m[12] * m[6] * m[11]
-            + m[12] * m[7] * m[10];
-
-        inv[8] = m[4] * m[9] * m[15] - m[4] * m[11] * m[13] - m[8] * m[5] * m[15]
-            + m[8] * m[7] * m[13]
-            + m[12] * m[5] * m[11]
-            - m[12] * m[7] * m[9];
-
-        inv[12] = -m[4] * m[9] * m[14] + m[4] * m[10] * m[13] + m[8] * m[5] * m[14]
-            - m[8] * m[6] * m[13]
-            - m[12] * m[5] * m[10]
-            + m[12] * m[6] * m[9];
-
-        inv[1] = -m[1] * m[10] * m[15] + m[1] * m[11] * m[14] + m[9] * m[2] * m[15]
-            - m[9] * m[3] * m[14]
-            - m[13] * m[2] * m[11]
-            + m[13] * m[3] * m[10];
-
-        inv[5] = m[0] * m[10] * m[15] - m[0] * m[11] * m[14] - m[8] * m[2] * m[15]
-            + m[8] * m[3] * m[14]
-            + m[12] * m[2] * m[11]
-            - m[12] * m[3] * m[10];
-
-        inv[9] = -m[0] * m[9] * m[15] + m[0] * m[11] * m[13] + m[8] * m[1] * m[15]
-            - m[8] * m[3] * m[13]
-            - m[12] * m[1] * m[11]
-            + m[12] * m[3] * m[9];
-
-        inv[13] = m[0] * m[9] * m[14] - m[0] * m[10] * m[13] - m[8] * m[1] * m[14]
-            + m[8] * m[2] * m[13]
-            + m[12] * m[1] * m[10]
-            - m[12] * m[2] * m[9];
-
-        inv[2] = m[1] * m[6] * m[15] - m[1] * m[7] * m[14] - m[5] * m[2] * m[15]
-            + m[5] * m[3] * m[14]
-            + m[13] * m[2] * m[7]
-            - m[13] * m[3] * m[6];
-
-        inv[6] = -m[0] * m[6] * m[15] + m[0] * m[7] * m[14] + m[4] * m[2] * m[15]
-            - m[4] * m[3] * m[14]
-            - m[12] * m[2] * m[7]
-            + m[12] * m[3] * m[6];
-
-        inv[10] = m[0] * m[5] * m[15] - m[0] * m[7] * m[13] - m[4] * m[1] * m[15]
-            + m[4] * m[3] * m[13]
-            + m[12] * m[1] * m[7]
-            - m[12] * m[3] * m[5];
-
-        inv[14] = -m[0] * m[5] * m[14] + m[0] * m[6] * m[13] + m[4] * m[1] * m[14]
-            - m[4] * m[2] * m[13]
-            - m[12] * m[1] * m[6]
-            + m[12] * m[2] * m[5];
-
-        inv[3] = -m[1] * m[6] * m[11] + m[1] * m[7] * m[10] + m[5] * m[2] * m[11]
-            - m[5] * m[3] * m[10]
-            - m[9] * m[2] * m[7]
-            + m[9] * m[3] * m[6];
-
-        inv[7] = m[0] * m[6] * m[11] - m[0] * m[7] * m[10] - m[4] * m[2] * m[11]
-            + m[4] * m[3] * m[10]
-            + m[8] * m[2] * m[7]
-            - m[8] * m[3] * m[6];
-
-        inv[11] = -m[0] * m[5] * m[11] + m[0] * m[7] * m[9] + m[4] * m[1] * m[11]
-            - m[4] * m[3] * m[9]
-            - m[8] * m[1] * m[7]
-            + m[8] * m[3] * m[5];
-
-        inv[15] = m[0] * m[5] * m[10] - m[0] * m[6] * m[9] - m[4] * m[1] * m[10]
-            + m[4] * m[2] * m[9]
-            + m[8] * m[1] * m[6]
-            - m[8] * m[2] * m[5];
-
-        let mut det = m[0] * inv[0] + m[1] * inv[4] + m[2] * inv[8] + m[3] * inv[12];
-
-        if det == 0.0 {
-            return None;
+    pub fn ortho(left: T, right: T, bottom: T, top: T, near_plane: T, far_plane: T) -> Self {
+        let w_inv = T::ONE / (right - left);
+        let h_inv = T::ONE / (top - bottom);
+        let d_inv = T::ONE / (far_plane - near_plane);
+
+        Self::new(
+            Vec4::new(T::TWO * w_inv, T::ZERO, T::ZERO, T::ZERO),
+            Vec4::new(T::ZERO, T::TWO * h_inv, T::ZERO, T::ZERO),
+            Vec4::new(T::ZERO, T::ZERO, -T::TWO * d_inv, T::ZERO),
+            Vec4::new(
+                -(right + left) * w_inv,
+                -(top + bottom) * h_inv,
+                -(far_plane + near_plane) * d_inv,
+                T::ONE,
+            ),
+        )
+    }
+
+    pub fn transform_point(&self, p: &Vec3<T>) -> Vec3<T> {
+        let v = *self * p.expand(T::ONE);
+        if v.w == T::ZERO || v.w == T::ONE {
+            v.truncate()
+        } else {
+            v.truncate() / v.w
         }
+    }
+
+    pub fn transform_vector(&self, v: &Vec3<T>) -> Vec3<T> {
+        (*self * v.expand(T::ZERO)).truncate()
+    }
 
-        det = 1.0 / det;
+    pub fn cast<U: Float>(&self) -> Mat4<U> {
+        let [c0, c1, c2, c3] = self.cols;
+        Mat4::new(c0.cast(), c1.cast(), c2.cast(), c3.cast())
+    }
 
-        return Some(Self::from(inv.map(|x| x * det)));
+    /// Inverse transpose of the upper-left 3x3, for transforming normals.
+    /// Falls back to the cofactor matrix when the 3x3 is singular, e.g. with
+    /// a zero scale axis, so normals need renormalizing afterwards.
+    pub fn normal_matrix(&self) -> Mat3<T> {
+        let m = Mat3::from(*self);
+        m.invert()
+            .map_or_else(|| m.cofactor(), |inv| inv.transpose())
     }
 }
 
-impl From<[f32; RANK * RANK]> for Mat4<f32> {
-    fn from(data: [f32; RANK * RANK]) -> Self {
+impl<T: Copy> From<[T; RANK * RANK]> for Mat4<T> {
+    fn from(data: [T; RANK * RANK]) -> Self {
         Self::new(
             Vec4::new(data[0], data[1], data[2], data[3]),
             Vec4::new(data[4], data[5], data[6], data[7]),
@@ -190,7 +220,20 @@ impl From<[f32; RANK * RANK]> for Mat4<f32> {
     }
 }
 
-impl<T: Copy + Add<Output = T>> Add for Mat4<T> {
+/// Assimp stores matrices row major.
+#[cfg(feature = "assimp")]
+impl From<assimp::Matrix4x4> for Mat4<f32> {
+    fn from(m: assimp::Matrix4x4) -> Self {
+        Self::new(
+            Vec4::new(m.a1, m.b1, m.c1, m.d1),
+            Vec4::new(m.a2, m.b2, m.c2, m.d2),
+            Vec4::new(m.a3, m.b3, m.c3, m.d3),
+            Vec4::new(m.a4, m.b4, m.c4, m.d4),
+        )
+    }
+}
+
+impl<T: Kernels + Add<Output = T>> Add for Mat4<T> {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
@@ -205,7 +248,7 @@ impl<T: Copy + Add<Output = T>> Add for Mat4<T> {
     }
 }
 
-impl<T: Copy + Sub<Output = T>> Sub for Mat4<T> {
+impl<T: Kernels + Sub<Output = T>> Sub for Mat4<T> {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
@@ -220,40 +263,203 @@ impl<T: Copy + Sub<Output = T>> Sub for Mat4<T> {
     }
 }
 
-impl<T: Copy + Mul<Output = T> + Default + Add<Output = T> + Sum> Mul for Mat4<T> {
+impl<T: Kernels + Add<Output = T> + Mul<Output = T>> Mul<Vec4<T>> for Mat4<T> {
+    type Output = Vec4<T>;
+
+    #[inline]
+    fn mul(self, v: Vec4<T>) -> Vec4<T> {
+        T::mat4_mul_vec4(&self, &v)
+    }
+}
+
+impl<T: Kernels + Add<Output = T> + Mul<Output = T>> Mul for Mat4<T> {
     type Output = Self;
 
+    #[inline]
     fn mul(self, other: Self) -> Self {
-        let result = Mat4::default();
-
-        for i in 0..RANK {
-            for j in 0..RANK {
-                result.cols[i].data()[j] = self.cols[i]
-                    .data()
-                    .iter()
-                    .zip(other.cols[j].data())
-                    .map(|(a, b)| a.clone() * b)
-                    .sum();
-            }
-        }
-
-        result
+        T::mat4_mul(&self, &other)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::assert_approx_eq;
 
     #[test]
     fn test_mat4_transpose() {
-        let a = Mat4::identity();
+        let a = Mat4::<f32>::identity();
         assert_eq!(a.transpose(), Mat4::identity());
     }
 
+    #[test]
+    fn test_mat4_integer() {
+        let a = Mat4::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+        assert_eq!(
+            a.transpose().cols(),
+            [1, 5, 9, 13, 2, 6, 10, 14, 3, 7, 11, 15, 4, 8, 12, 16]
+        );
+        assert_eq!(a * Vec4::new(1, 0, 0, 1), Vec4::new(14, 16, 18, 20));
+
+        let id = Mat4::<u32>::from([1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1]);
+        let b = Mat4::<u32>::from([2; 16]);
+        assert_eq!(b * id, b);
+    }
+
     #[test]
     fn test_mat4_inverse() {
-        let a = Mat4::identity();
+        let a = Mat4::<f32>::identity();
         assert_eq!(a.invert(), Some(a));
+
+        let m = Mat4::translation(&Vec3::new(1.0, -2.0, 3.0))
+            * Mat4::rotation(30.0, &Vec3::new(0.0, 1.0, 1.0).normalize())
+            * Mat4::scale(&Vec3::new(2.0, 0.5, 4.0));
+        let inv = m.invert().unwrap();
+        assert_approx_eq!(m * inv, Mat4::identity());
+        assert_approx_eq!(inv * m, Mat4::identity());
+        assert_approx_eq!(inv.invert().unwrap(), m);
+    }
+
+    #[test]
+    fn test_mat4_mul_identity() {
+        let a = Mat4::translation(&Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(a * Mat4::identity(), a);
+        assert_eq!(Mat4::identity() * a, a);
+    }
+
+    #[test]
+    fn test_mat4_mul_order() {
+        let t = Mat4::translation(&Vec3::new(1.0, 0.0, 0.0));
+        let s = Mat4::scale(&Vec3::new(2.0, 2.0, 2.0));
+        let p = Vec3::new(1.0, 1.0, 1.0);
+        assert_eq!((t * s).transform_point(&p), Vec3::new(3.0, 2.0, 2.0));
+        assert_eq!((s * t).transform_point(&p), Vec3::new(4.0, 2.0, 2.0));
+    }
+
+    #[test]
+    fn test_mat4_mul_vec4() {
+        let t = Mat4::translation(&Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(
+            t * Vec4::new(1.0, 1.0, 1.0, 1.0),
+            Vec4::new(2.0, 3.0, 4.0, 1.0)
+        );
+        assert_eq!(
+            t * Vec4::new(1.0, 1.0, 1.0, 0.0),
+            Vec4::new(1.0, 1.0, 1.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_mat4_transform_vector_ignores_translation() {
+        let t = Mat4::translation(&Vec3::new(5.0, 5.0, 5.0));
+        let v = Vec3::new(1.0, 0.0, 0.0);
+        assert_eq!(t.transform_vector(&v), v);
+    }
+
+    #[test]
+    fn test_mat4_rotation() {
+        let r = Mat4::rotation(90.0, &Vec3::new(0.0, 0.0, 1.0));
+        assert_approx_eq!(
+            r.transform_point(&Vec3::new(1.0, 0.0, 0.0)),
+            Vec3::new(0.0, 1.0, 0.0),
+            abs = 1e-5
+        );
+    }
+
+    #[test]
+    fn test_mat4_translation_inverse() {
+        let t = Mat4::translation(&Vec3::new(1.0, -2.0, 4.0));
+        let inv = t.invert().unwrap();
+        assert_eq!(inv, Mat4::translation(&Vec3::new(-1.0, 2.0, -4.0)));
+    }
+
+    #[test]
+    fn test_mat4_look_at() {
+        let eye = Vec3::new(1.0, 2.0, 3.0);
+        let view = Mat4::look_at(&eye, &Vec3::new(1.0, 2.0, 0.0), &Vec3::new(0.0, 1.0, 0.0));
+        assert_approx_eq!(view.transform_point(&eye), Vec3::zero(), abs = 1e-5);
+        assert_approx_eq!(
+            view.transform_point(&Vec3::new(1.0, 2.0, 0.0)),
+            Vec3::new(0.0, 0.0, -3.0),
+            abs = 1e-5
+        );
+    }
+
+    #[test]
+    fn test_mat4_perspective_depth_range() {
+        let p = Mat4::perspective(60.0, 1.5, 0.1, 100.0);
+        assert!((p.transform_point(&Vec3::new(0.0, 0.0, -0.1)).z + 1.0).abs() < 1e-5);
+        assert!((p.transform_point(&Vec3::new(0.0, 0.0, -100.0)).z - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_mat4_perspective_reversed_z() {
+        let p = Mat4::perspective_reversed_z(60.0, 1.5, 0.1, 100.0);
+        assert!((p.transform_point(&Vec3::new(0.0, 0.0, -0.1)).z - 1.0).abs() < 1e-5);
+        assert!(p.transform_point(&Vec3::new(0.0, 0.0, -100.0)).z.abs() < 1e-5);
+
+        let inf = Mat4::perspective_infinite_reversed_z(60.0, 1.5, 0.1);
+        assert!((inf.transform_point(&Vec3::new(0.0, 0.0, -0.1)).z - 1.0).abs() < 1e-5);
+        assert!(inf.transform_point(&Vec3::new(0.0, 0.0, -1e7)).z.abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_mat4_normal_matrix() {
+        let m =
+            Mat4::translation(&Vec3::new(3.0, 1.0, 2.0)) * Mat4::scale(&Vec3::new(2.0, 1.0, 1.0));
+        let n = m.normal_matrix() * Vec3::new(1.0, 1.0, 0.0);
+        assert_eq!(n, Vec3::new(0.5, 1.0, 0.0));
+        assert_eq!(Mat4::<f32>::identity().normal_matrix(), Mat3::identity());
+    }
+
+    #[test]
+    fn test_mat4_normal_matrix_zero_scale() {
+        // Flattened onto the xz plane; normals along y survive, others
+        // collapse onto it instead of being left untransformed.
+        let m = Mat4::rotation(90.0, &Vec3::new(0.0, 1.0, 0.0))
+            * Mat4::scale(&Vec3::new(1.0, 0.0, 1.0));
+        let n = m.normal_matrix();
+        assert_approx_eq!(
+            (n * Vec3::new(0.0, 1.0, 0.0)).normalize(),
+            Vec3::new(0.0, 1.0, 0.0)
+        );
+        assert_approx_eq!(n * Vec3::new(1.0, 0.0, 0.0), Vec3::zero(), abs = 1e-6);
+        assert_ne!(n, Mat3::identity());
+    }
+
+    #[test]
+    fn test_mat4_f64() {
+        let m = Mat4::<f64>::translation(&Vec3::new(1.0, 2.0, 3.0))
+            * Mat4::rotation(30.0, &Vec3::new(0.0, 1.0, 0.0));
+        let inv = m.invert().unwrap();
+        let p = Vec3::new(4.0, 5.0, 6.0);
+        assert!((inv.transform_point(&m.transform_point(&p)) - p).length() < 1e-12);
+    }
+
+    #[test]
+    fn test_mat4_cast() {
+        let m = Mat4::perspective(60.0f32, 1.5, 0.1, 100.0);
+        assert_eq!(m.cast::<f64>().cast::<f32>(), m);
+    }
+
+    #[test]
+    fn test_mat4_ortho() {
+        let o = Mat4::ortho(-2.0, 2.0, -1.0, 1.0, 0.0, 10.0);
+        assert_eq!(
+            o.transform_point(&Vec3::new(2.0, 1.0, 0.0)),
+            Vec3::new(1.0, 1.0, -1.0)
+        );
+        assert_eq!(
+            o.transform_point(&Vec3::new(-2.0, -1.0, -10.0)),
+            Vec3::new(-1.0, -1.0, 1.0)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_mat4_serde() {
+        let m = Mat4::translation(&Vec3::new(1.0f32, 2.0, 3.0));
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(serde_json::from_str::<Mat4<f32>>(&json).unwrap(), m);
     }
 }
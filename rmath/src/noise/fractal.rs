@@ -0,0 +1,129 @@
+use std::ops::Mul;
+
+use crate::{Scalar, noise::Noise};
+
+macro_rules! fractal {
+    ($($name:ident),*) => {$(
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct $name<N> {
+            pub noise: N,
+            pub octaves: u32,
+            /// Frequency multiplier between octaves.
+            pub lacunarity: f32,
+            /// Amplitude multiplier between octaves.
+            pub gain: f32,
+        }
+
+        impl<N> $name<N> {
+            pub const fn new(noise: N) -> Self {
+                Self {
+                    noise,
+                    octaves: 6,
+                    lacunarity: 2.0,
+                    gain: 0.5,
+                }
+            }
+
+            pub fn with_octaves(self, octaves: u32) -> Self {
+                Self { octaves, ..self }
+            }
+
+            pub fn with_lacunarity(self, lacunarity: f32) -> Self {
+                Self { lacunarity, ..self }
+            }
+
+            pub fn with_gain(self, gain: f32) -> Self {
+                Self { gain, ..self }
+            }
+
+            /// Sums `shape(noise)` over the octaves, normalized by the total
+            /// amplitude so the output keeps the range of `shape`.
+            fn octaves<P>(&self, p: P, shape: impl Fn(f32) -> f32) -> f32
+            where
+                N: Noise<P>,
+                P: Copy + Mul<Scalar<f32>, Output = P>,
+            {
+                let mut frequency = 1.0;
+                let mut amplitude = 1.0;
+                let mut sum = 0.0;
+                let mut total = 0.0;
+                for _ in 0..self.octaves {
+                    sum += shape(self.noise.sample(p * Scalar(frequency))) * amplitude;
+                    total += amplitude;
+                    frequency *= self.lacunarity;
+                    amplitude *= self.gain;
+                }
+                if total == 0.0 { 0.0 } else { sum / total }
+            }
+        }
+    )*};
+}
+
+fractal!(Fbm, Ridged, Turbulence);
+
+/// Fractal Brownian motion: layered octaves of the inner noise, in the
+/// inner noise's range.
+impl<N: Noise<P>, P: Copy + Mul<Scalar<f32>, Output = P>> Noise<P> for Fbm<N> {
+    fn sample(&self, p: P) -> f32 {
+        self.octaves(p, |n| n)
+    }
+}
+
+/// Sharp crests where the inner noise crosses zero, in `[0, 1]`.
+impl<N: Noise<P>, P: Copy + Mul<Scalar<f32>, Output = P>> Noise<P> for Ridged<N> {
+    fn sample(&self, p: P) -> f32 {
+        self.octaves(p, |n| {
+            let ridge = 1.0 - n.abs().min(1.0);
+            ridge * ridge
+        })
+    }
+}
+
+/// Layered absolute values of the inner noise, in `[0, 1]`.
+impl<N: Noise<P>, P: Copy + Mul<Scalar<f32>, Output = P>> Noise<P> for Turbulence<N> {
+    fn sample(&self, p: P) -> f32 {
+        self.octaves(p, |n| n.abs().min(1.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        noise::{Perlin, Simplex, Value},
+        vec2::Vec2,
+        vec3::Vec3,
+    };
+
+    #[test]
+    fn test_fractal_single_octave() {
+        let p = Vec3::new(0.3, 0.6, 0.9);
+        let perlin = Perlin::new(1);
+        let fbm = Fbm::new(perlin.clone()).with_octaves(1);
+        assert_eq!(fbm.sample(p), perlin.sample(p));
+
+        let turbulence = Turbulence::new(perlin.clone()).with_octaves(1);
+        assert_eq!(turbulence.sample(p), perlin.sample(p).abs());
+    }
+
+    #[test]
+    fn test_fractal_ranges() {
+        let fbm = Fbm::new(Simplex::new(3));
+        let ridged = Ridged::new(Perlin::new(3)).with_gain(0.6);
+        let turbulence = Turbulence::new(Value::new(3)).with_lacunarity(2.5);
+        for i in 0..1000 {
+            let p = Vec2::new(i as f32 * 0.31, i as f32 * -0.17);
+            assert!(fbm.sample(p).abs() <= 1.0);
+            assert!((0.0..=1.0).contains(&ridged.sample(p)));
+            assert!((0.0..=1.0).contains(&turbulence.sample(p)));
+        }
+    }
+
+    #[test]
+    fn test_fractal_nested() {
+        let p = Vec2::new(1.5, 2.5);
+        let nested = Ridged::new(Fbm::new(Perlin::new(0)).with_octaves(3)).with_octaves(2);
+        assert_eq!(nested.sample(p), nested.clone().sample(p));
+        assert_eq!(Fbm::new(Perlin::new(0)).with_octaves(0).sample(p), 0.0);
+    }
+}
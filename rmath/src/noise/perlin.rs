@@ -0,0 +1,116 @@
+use crate::{
+    noise::{Noise, Permutation, blend_corners, cell, grad4},
+    vec2::Vec2,
+    vec3::Vec3,
+    vec4::Vec4,
+};
+
+/// Gradient (Perlin) noise in roughly `[-1, 1]`, zero on every lattice point.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Perlin {
+    perm: Permutation,
+}
+
+impl Perlin {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            perm: Permutation::new(seed),
+        }
+    }
+
+    fn noise<const N: usize>(&self, p: [f32; N], grad: fn(u8, [f32; N]) -> f32) -> f32 {
+        let (base, offset) = cell(p);
+        blend_corners(offset, |step, local| {
+            let hash = self
+                .perm
+                .hash::<N>(std::array::from_fn(|i| base[i] + step[i]));
+            grad(hash, local)
+        })
+    }
+}
+
+fn grad2(hash: u8, [x, y]: [f32; 2]) -> f32 {
+    match hash & 7 {
+        0 => x + y,
+        1 => -x + y,
+        2 => x - y,
+        3 => -x - y,
+        4 => x,
+        5 => -x,
+        6 => y,
+        _ => -y,
+    }
+}
+
+/// The twelve cube edge directions of improved Perlin noise.
+fn grad3(hash: u8, [x, y, z]: [f32; 3]) -> f32 {
+    let h = hash & 15;
+    let u = if h < 8 { x } else { y };
+    let v = match h {
+        0..4 => y,
+        12 | 14 => x,
+        _ => z,
+    };
+    (if h & 1 == 0 { u } else { -u }) + (if h & 2 == 0 { v } else { -v })
+}
+
+impl Noise<Vec2<f32>> for Perlin {
+    fn sample(&self, p: Vec2<f32>) -> f32 {
+        self.noise(p.data(), grad2)
+    }
+}
+
+impl Noise<Vec3<f32>> for Perlin {
+    fn sample(&self, p: Vec3<f32>) -> f32 {
+        self.noise(p.data(), grad3)
+    }
+}
+
+/// Scaled down so the longer 4D gradients stay within `[-1, 1]`.
+impl Noise<Vec4<f32>> for Perlin {
+    fn sample(&self, p: Vec4<f32>) -> f32 {
+        self.noise(p.data(), grad4) * 0.75
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_perlin_lattice_zero() {
+        let noise = Perlin::new(3);
+        assert_eq!(noise.sample(Vec2::new(4.0, -7.0)), 0.0);
+        assert_eq!(noise.sample(Vec3::new(1.0, 2.0, -3.0)), 0.0);
+        assert_eq!(noise.sample(Vec4::new(0.0, 5.0, 9.0, -1.0)), 0.0);
+    }
+
+    #[test]
+    fn test_perlin_range() {
+        let noise = Perlin::new(11);
+        for i in 0..4000 {
+            let t = i as f32 * 0.137;
+            let values = [
+                noise.sample(Vec2::new(t, t * 0.71)),
+                noise.sample(Vec3::new(t, -t * 0.53, t * 0.29)),
+                noise.sample(Vec4::new(t * 0.91, t * 0.37, -t, t * 0.61)),
+            ];
+            assert!(values.iter().all(|v| v.abs() <= 1.0), "{values:?}");
+        }
+    }
+
+    #[test]
+    fn test_perlin_seeded() {
+        let p = Vec3::new(0.3, 1.7, -2.2);
+        assert_eq!(Perlin::new(5).sample(p), Perlin::new(5).sample(p));
+        assert_ne!(Perlin::new(5).sample(p), Perlin::new(6).sample(p));
+    }
+
+    #[test]
+    fn test_perlin_continuous() {
+        let noise = Perlin::new(0);
+        let p = Vec2::new(2.999, 0.5);
+        let q = Vec2::new(3.001, 0.5);
+        assert!((noise.sample(p) - noise.sample(q)).abs() < 0.01);
+    }
+}
@@ -0,0 +1,152 @@
+use crate::{
+    noise::{Noise, Permutation, grad4},
+    vec2::Vec2,
+    vec3::Vec3,
+    vec4::Vec4,
+};
+
+// Skew/unskew factors: (sqrt(n + 1) - 1) / n and (n + 1 - sqrt(n + 1)) / (n (n + 1)).
+const F2: f32 = 0.366_025_4;
+const G2: f32 = 0.211_324_87;
+const F3: f32 = 1.0 / 3.0;
+const G3: f32 = 1.0 / 6.0;
+const F4: f32 = 0.309_017;
+const G4: f32 = 0.138_196_6;
+
+const GRAD3: [[f32; 3]; 12] = [
+    [1.0, 1.0, 0.0],
+    [-1.0, 1.0, 0.0],
+    [1.0, -1.0, 0.0],
+    [-1.0, -1.0, 0.0],
+    [1.0, 0.0, 1.0],
+    [-1.0, 0.0, 1.0],
+    [1.0, 0.0, -1.0],
+    [-1.0, 0.0, -1.0],
+    [0.0, 1.0, 1.0],
+    [0.0, -1.0, 1.0],
+    [0.0, 1.0, -1.0],
+    [0.0, -1.0, -1.0],
+];
+
+/// Simplex noise in roughly `[-1, 1]` (Gustavson's formulation).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Simplex {
+    perm: Permutation,
+}
+
+impl Simplex {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            perm: Permutation::new(seed),
+        }
+    }
+
+    /// Skews `p` onto the simplex lattice and sums the falloff weighted
+    /// gradient contribution of the `N + 1` corners of its simplex.
+    fn noise<const N: usize>(
+        &self,
+        p: [f32; N],
+        skew: f32,
+        unskew: f32,
+        radius: f32,
+        grad: fn(u8, [f32; N]) -> f32,
+    ) -> f32 {
+        let s = p.iter().sum::<f32>() * skew;
+        let base = p.map(|c| (c + s).floor());
+        let t = base.iter().sum::<f32>() * unskew;
+        let offset: [f32; N] = std::array::from_fn(|i| p[i] - (base[i] - t));
+        let base = base.map(|b| b as i32);
+
+        // Rank of each axis by offset; the simplex is walked from the
+        // largest offset to the smallest.
+        let rank: [usize; N] = std::array::from_fn(|i| {
+            (0..N)
+                .filter(|&j| offset[j] > offset[i] || (offset[j] == offset[i] && j < i))
+                .count()
+        });
+
+        (0..=N)
+            .map(|k| {
+                let step: [i32; N] = std::array::from_fn(|i| (rank[i] < k) as i32);
+                let corner: [f32; N] =
+                    std::array::from_fn(|i| offset[i] - step[i] as f32 + k as f32 * unskew);
+                let falloff = radius - corner.iter().map(|c| c * c).sum::<f32>();
+                if falloff <= 0.0 {
+                    return 0.0;
+                }
+                let hash = self
+                    .perm
+                    .hash::<N>(std::array::from_fn(|i| base[i] + step[i]));
+                let falloff = falloff * falloff;
+                falloff * falloff * grad(hash, corner)
+            })
+            .sum()
+    }
+}
+
+fn grad2(hash: u8, [x, y]: [f32; 2]) -> f32 {
+    let [gx, gy, _] = GRAD3[hash as usize % 12];
+    gx * x + gy * y
+}
+
+fn grad3(hash: u8, [x, y, z]: [f32; 3]) -> f32 {
+    let [gx, gy, gz] = GRAD3[hash as usize % 12];
+    gx * x + gy * y + gz * z
+}
+
+impl Noise<Vec2<f32>> for Simplex {
+    fn sample(&self, p: Vec2<f32>) -> f32 {
+        70.0 * self.noise(p.data(), F2, G2, 0.5, grad2)
+    }
+}
+
+impl Noise<Vec3<f32>> for Simplex {
+    fn sample(&self, p: Vec3<f32>) -> f32 {
+        32.0 * self.noise(p.data(), F3, G3, 0.6, grad3)
+    }
+}
+
+impl Noise<Vec4<f32>> for Simplex {
+    fn sample(&self, p: Vec4<f32>) -> f32 {
+        27.0 * self.noise(p.data(), F4, G4, 0.6, grad4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simplex_range() {
+        let noise = Simplex::new(4);
+        let mut extremes = [0.0f32; 3];
+        for i in 0..6000 {
+            let t = i as f32 * 0.0917;
+            let values = [
+                noise.sample(Vec2::new(t, t * 0.61)),
+                noise.sample(Vec3::new(-t * 0.4, t, t * 0.77)),
+                noise.sample(Vec4::new(t * 0.3, t * 0.8, -t, t * 0.45)),
+            ];
+            assert!(values.iter().all(|v| v.abs() <= 1.0), "{values:?}");
+            for (e, v) in extremes.iter_mut().zip(values) {
+                *e = e.max(v.abs());
+            }
+        }
+        assert!(extremes.iter().all(|&e| e > 0.5), "{extremes:?}");
+    }
+
+    #[test]
+    fn test_simplex_seeded() {
+        let p = Vec4::new(0.1, -0.4, 2.5, 7.3);
+        assert_eq!(Simplex::new(1).sample(p), Simplex::new(1).sample(p));
+        assert_ne!(Simplex::new(1).sample(p), Simplex::new(2).sample(p));
+    }
+
+    #[test]
+    fn test_simplex_continuous() {
+        let noise = Simplex::new(0);
+        let p = Vec3::new(1.25, -0.5, 3.75);
+        let q = p + Vec3::new(1e-3, 0.0, 1e-3);
+        assert!((noise.sample(p) - noise.sample(q)).abs() < 0.02);
+    }
+}
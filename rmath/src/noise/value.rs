@@ -0,0 +1,78 @@
+use crate::{
+    noise::{Noise, Permutation, blend_corners, cell},
+    vec2::Vec2,
+    vec3::Vec3,
+    vec4::Vec4,
+};
+
+/// Smoothly interpolated random lattice values in `[-1, 1]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Value {
+    perm: Permutation,
+}
+
+impl Value {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            perm: Permutation::new(seed),
+        }
+    }
+
+    fn noise<const N: usize>(&self, p: [f32; N]) -> f32 {
+        let (base, offset) = cell(p);
+        blend_corners(offset, |step, _| {
+            let hash = self
+                .perm
+                .hash::<N>(std::array::from_fn(|i| base[i] + step[i]));
+            hash as f32 / 127.5 - 1.0
+        })
+    }
+}
+
+impl Noise<Vec2<f32>> for Value {
+    fn sample(&self, p: Vec2<f32>) -> f32 {
+        self.noise(p.data())
+    }
+}
+
+impl Noise<Vec3<f32>> for Value {
+    fn sample(&self, p: Vec3<f32>) -> f32 {
+        self.noise(p.data())
+    }
+}
+
+impl Noise<Vec4<f32>> for Value {
+    fn sample(&self, p: Vec4<f32>) -> f32 {
+        self.noise(p.data())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_lattice() {
+        let noise = Value::new(9);
+        let at = |x: i32, y: i32| noise.perm.hash([x, y]) as f32 / 127.5 - 1.0;
+        assert_eq!(noise.sample(Vec2::new(2.0, -3.0)), at(2, -3));
+        assert_eq!(
+            noise.sample(Vec2::new(2.5, -3.0)),
+            (at(2, -3) + at(3, -3)) * 0.5
+        );
+    }
+
+    #[test]
+    fn test_value_range() {
+        let noise = Value::new(2);
+        for i in 0..2000 {
+            let t = i as f32 * 0.173;
+            let values = [
+                noise.sample(Vec2::new(t, -t * 0.3)),
+                noise.sample(Vec3::new(t * 0.7, t, t * 0.11)),
+                noise.sample(Vec4::new(-t, t * 0.5, t * 0.25, t * 0.9)),
+            ];
+            assert!(values.iter().all(|v| v.abs() <= 1.0), "{values:?}");
+        }
+    }
+}
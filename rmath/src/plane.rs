@@ -0,0 +1,76 @@
+use crate::{float::Float, vec3::Vec3, vec4::Vec4};
+
+/// Plane of all points `p` with `normal.dot(p) + d == 0`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Plane<T: Copy> {
+    pub normal: Vec3<T>,
+    pub d: T,
+}
+
+impl<T: Copy> Plane<T> {
+    #[inline]
+    pub const fn new(normal: Vec3<T>, d: T) -> Self {
+        Self { normal, d }
+    }
+}
+
+impl<T: Float> Plane<T> {
+    pub fn from_point_normal(point: &Vec3<T>, normal: &Vec3<T>) -> Self {
+        let normal = normal.normalize();
+        Self::new(normal, -normal.dot(point))
+    }
+
+    /// Counter-clockwise winding of `a`, `b`, `c` faces the normal.
+    pub fn from_points(a: &Vec3<T>, b: &Vec3<T>, c: &Vec3<T>) -> Self {
+        Self::from_point_normal(a, &(*b - *a).cross(&(*c - *a)))
+    }
+
+    pub fn signed_distance(&self, p: &Vec3<T>) -> T {
+        self.normal.dot(p) + self.d
+    }
+
+    pub fn normalize(&self) -> Self {
+        let length = self.normal.length();
+        if length == T::ZERO {
+            *self
+        } else {
+            Self::new(self.normal / length, self.d / length)
+        }
+    }
+}
+
+impl<T: Copy> From<Vec4<T>> for Plane<T> {
+    #[inline]
+    fn from(Vec4 { x, y, z, w }: Vec4<T>) -> Self {
+        Self::new(Vec3::new(x, y, z), w)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plane_from_points() {
+        let plane = Plane::from_points(
+            &Vec3::new(0.0, 1.0, 0.0),
+            &Vec3::new(0.0, 1.0, 1.0),
+            &Vec3::new(1.0, 1.0, 0.0),
+        );
+        assert_eq!(plane, Plane::new(Vec3::new(0.0, 1.0, 0.0), -1.0));
+    }
+
+    #[test]
+    fn test_plane_signed_distance() {
+        let plane = Plane::from_point_normal(&Vec3::new(0.0, 0.0, 2.0), &Vec3::new(0.0, 0.0, 3.0));
+        assert_eq!(plane.signed_distance(&Vec3::new(5.0, 5.0, 3.0)), 1.0);
+        assert_eq!(plane.signed_distance(&Vec3::new(5.0, 5.0, 0.0)), -2.0);
+    }
+
+    #[test]
+    fn test_plane_normalize() {
+        let plane = Plane::new(Vec3::new(0.0, 2.0, 0.0), 4.0).normalize();
+        assert_eq!(plane, Plane::new(Vec3::new(0.0, 1.0, 0.0), 2.0));
+    }
+}
@@ -0,0 +1,43 @@
+//! `bytemuck` support, so slices of vectors and matrices can be viewed as
+//! raw bytes (e.g. for GPU uploads) without unchecked pointer casts.
+//!
+//! SAFETY: every type here is `#[repr(C)]` (or `#[repr(transparent)]`)
+//! over fields of a single type `T`, so it has no padding, and any bit
+//! pattern is valid whenever it is valid for `T`.
+
+use bytemuck::{Pod, Zeroable};
+
+use crate::{Scalar, mat3::Mat3, mat4::Mat4, quat::Quat, vec2::Vec2, vec3::Vec3, vec4::Vec4};
+
+macro_rules! impl_pod {
+    ($($t:ident),*) => {$(
+        unsafe impl<T: Copy + Zeroable> Zeroable for $t<T> {}
+        unsafe impl<T: Pod> Pod for $t<T> {}
+    )*};
+}
+
+impl_pod!(Vec2, Vec3, Vec4, Mat3, Mat4, Quat);
+
+unsafe impl<T: Zeroable> Zeroable for Scalar<T> {}
+unsafe impl<T: Pod> Pod for Scalar<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pod_cast_slice() {
+        let points = [Vec3::new(1.0f32, 2.0, 3.0), Vec3::new(4.0, 5.0, 6.0)];
+        let floats: &[f32] = bytemuck::cast_slice(&points);
+        assert_eq!(floats, [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(bytemuck::cast_slice::<_, u8>(&points).len(), 24);
+    }
+
+    #[test]
+    fn test_pod_matrix_layout() {
+        let m = Mat4::<f32>::identity();
+        let floats: &[f32; 16] = bytemuck::cast_ref(&m);
+        assert_eq!(*floats, m.cols());
+        assert_eq!(Vec4::<f32>::zeroed(), Vec4::default());
+    }
+}
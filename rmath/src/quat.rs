@@ -0,0 +1,435 @@
+use std::ops::{Mul, Neg};
+
+use crate::{Scalar, float::Float, mat3::Mat3, mat4::Mat4, vec3::Vec3, vec4::Vec4};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Quat<T: Copy> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
+}
+
+impl<T: Copy> Quat<T> {
+    #[inline]
+    pub const fn new(x: T, y: T, z: T, w: T) -> Self {
+        Self { x, y, z, w }
+    }
+
+    #[inline]
+    pub const fn data(&self) -> [T; 4] {
+        [self.x, self.y, self.z, self.w]
+    }
+
+    #[inline]
+    pub const fn vector(&self) -> Vec3<T> {
+        Vec3::new(self.x, self.y, self.z)
+    }
+}
+
+impl<T: Float> Quat<T> {
+    #[inline]
+    pub const fn identity() -> Self {
+        Self::new(T::ZERO, T::ZERO, T::ZERO, T::ONE)
+    }
+
+    /// Rotation of `angle` degrees around `axis`, matching [`Vec3::rotate`].
+    pub fn from_axis_angle(angle: T, axis: &Vec3<T>) -> Self {
+        let half = angle.to_radians() * T::HALF;
+        let Vec3 { x, y, z } = axis.normalize() * Scalar(half.sin());
+        Self::new(x, y, z, half.cos())
+    }
+
+    /// Euler angles in degrees, applied as roll (z), then pitch (x), then yaw (y).
+    pub fn from_euler(pitch: T, yaw: T, roll: T) -> Self {
+        Self::from_axis_angle(yaw, &Vec3::new(T::ZERO, T::ONE, T::ZERO))
+            * Self::from_axis_angle(pitch, &Vec3::new(T::ONE, T::ZERO, T::ZERO))
+            * Self::from_axis_angle(roll, &Vec3::new(T::ZERO, T::ZERO, T::ONE))
+    }
+
+    /// Shortest rotation taking direction `from` onto direction `to`.
+    pub fn from_rotation_arc(from: &Vec3<T>, to: &Vec3<T>) -> Self {
+        let from = from.normalize();
+        let to = to.normalize();
+        let d = from.dot(&to);
+
+        if d < -T::ONE + T::from_f64(1e-6) {
+            let mut axis = Vec3::new(T::ONE, T::ZERO, T::ZERO).cross(&from);
+            if axis.length() < T::from_f64(1e-6) {
+                axis = Vec3::new(T::ZERO, T::ONE, T::ZERO).cross(&from);
+            }
+            return Self::from_axis_angle(T::from_f64(180.0), &axis);
+        }
+
+        let Vec3 { x, y, z } = from.cross(&to);
+        Self::new(x, y, z, T::ONE + d).normalize()
+    }
+
+    pub fn dot(&self, other: &Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    pub fn length(&self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    pub fn normalize(&self) -> Self {
+        let length = self.length();
+        if length == T::ZERO {
+            Self::identity()
+        } else {
+            let Self { x, y, z, w } = *self;
+            Self::new(x / length, y / length, z / length, w / length)
+        }
+    }
+
+    pub fn conjugate(&self) -> Self {
+        let Self { x, y, z, w } = *self;
+        Self::new(-x, -y, -z, w)
+    }
+
+    pub fn invert(&self) -> Option<Self> {
+        let len_sq = self.dot(self);
+        if len_sq == T::ZERO {
+            return None;
+        }
+        let Self { x, y, z, w } = self.conjugate();
+        Some(Self::new(x / len_sq, y / len_sq, z / len_sq, w / len_sq))
+    }
+
+    pub fn rotate(&self, v: &Vec3<T>) -> Vec3<T> {
+        let u = self.vector();
+        let t = u.cross(v) * Scalar(T::TWO);
+        *v + t * Scalar(self.w) + u.cross(&t)
+    }
+
+    pub fn nlerp(a: &Self, b: &Self, t: T) -> Self {
+        let b = if a.dot(b) < T::ZERO { -*b } else { *b };
+        Self::new(
+            a.x + (b.x - a.x) * t,
+            a.y + (b.y - a.y) * t,
+            a.z + (b.z - a.z) * t,
+            a.w + (b.w - a.w) * t,
+        )
+        .normalize()
+    }
+
+    pub fn slerp(a: &Self, b: &Self, t: T) -> Self {
+        let mut d = a.dot(b);
+        let b = if d < T::ZERO {
+            d = -d;
+            -*b
+        } else {
+            *b
+        };
+
+        if d > T::from_f64(0.9995) {
+            return Self::nlerp(a, &b, t);
+        }
+
+        let theta = d.acos();
+        let sin_theta = theta.sin();
+        let wa = ((T::ONE - t) * theta).sin() / sin_theta;
+        let wb = (t * theta).sin() / sin_theta;
+        Self::new(
+            a.x * wa + b.x * wb,
+            a.y * wa + b.y * wb,
+            a.z * wa + b.z * wb,
+            a.w * wa + b.w * wb,
+        )
+    }
+
+    pub fn cast<U: Float>(&self) -> Quat<U> {
+        let Self { x, y, z, w } = *self;
+        Quat::new(x.cast(), y.cast(), z.cast(), w.cast())
+    }
+
+    pub fn to_mat3(&self) -> Mat3<T> {
+        let Self { x, y, z, w } = self.normalize();
+        let (xx, yy, zz) = (x * x, y * y, z * z);
+        let (xy, xz, yz) = (x * y, x * z, y * z);
+        let (wx, wy, wz) = (w * x, w * y, w * z);
+
+        Mat3::new(
+            Vec3::new(
+                T::ONE - T::TWO * (yy + zz),
+                T::TWO * (xy + wz),
+                T::TWO * (xz - wy),
+            ),
+            Vec3::new(
+                T::TWO * (xy - wz),
+                T::ONE - T::TWO * (xx + zz),
+                T::TWO * (yz + wx),
+            ),
+            Vec3::new(
+                T::TWO * (xz + wy),
+                T::TWO * (yz - wx),
+                T::ONE - T::TWO * (xx + yy),
+            ),
+        )
+    }
+
+    pub fn to_mat4(&self) -> Mat4<T> {
+        let [c0, c1, c2] = self.to_mat3().columns();
+        Mat4::new(
+            c0.expand(T::ZERO),
+            c1.expand(T::ZERO),
+            c2.expand(T::ZERO),
+            Vec4::new(T::ZERO, T::ZERO, T::ZERO, T::ONE),
+        )
+    }
+
+    /// Expects an orthonormal rotation matrix in column-major order.
+    pub fn from_rotation_cols(m: [T; 9]) -> Self {
+        let [m00, m10, m20, m01, m11, m21, m02, m12, m22] = m;
+        let trace = m00 + m11 + m22;
+
+        let q = if trace > T::ZERO {
+            let s = (trace + T::ONE).sqrt() * T::TWO;
+            Self::new(
+                (m21 - m12) / s,
+                (m02 - m20) / s,
+                (m10 - m01) / s,
+                T::HALF * T::HALF * s,
+            )
+        } else if m00 > m11 && m00 > m22 {
+            let s = (T::ONE + m00 - m11 - m22).sqrt() * T::TWO;
+            Self::new(
+                T::HALF * T::HALF * s,
+                (m01 + m10) / s,
+                (m02 + m20) / s,
+                (m21 - m12) / s,
+            )
+        } else if m11 > m22 {
+            let s = (T::ONE + m11 - m00 - m22).sqrt() * T::TWO;
+            Self::new(
+                (m01 + m10) / s,
+                T::HALF * T::HALF * s,
+                (m12 + m21) / s,
+                (m02 - m20) / s,
+            )
+        } else {
+            let s = (T::ONE + m22 - m00 - m11).sqrt() * T::TWO;
+            Self::new(
+                (m02 + m20) / s,
+                (m12 + m21) / s,
+                T::HALF * T::HALF * s,
+                (m10 - m01) / s,
+            )
+        };
+
+        q.normalize()
+    }
+}
+
+impl<T> Neg for Quat<T>
+where
+    T: Copy + Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        let Self { x, y, z, w } = self;
+        Self::new(-x, -y, -z, -w)
+    }
+}
+
+impl<T: Float> Mul for Quat<T> {
+    type Output = Self;
+
+    fn mul(self, o: Self) -> Self {
+        let Self { x, y, z, w } = self;
+        Self::new(
+            w * o.x + x * o.w + y * o.z - z * o.y,
+            w * o.y - x * o.z + y * o.w + z * o.x,
+            w * o.z + x * o.y - y * o.x + z * o.w,
+            w * o.w - x * o.x - y * o.y - z * o.z,
+        )
+    }
+}
+
+impl<T: Float> Mul<Vec3<T>> for Quat<T> {
+    type Output = Vec3<T>;
+
+    fn mul(self, v: Vec3<T>) -> Vec3<T> {
+        self.rotate(&v)
+    }
+}
+
+impl<T: Copy> From<[T; 4]> for Quat<T> {
+    #[inline]
+    fn from([x, y, z, w]: [T; 4]) -> Self {
+        Self::new(x, y, z, w)
+    }
+}
+
+impl<T: Copy> From<Vec4<T>> for Quat<T> {
+    #[inline]
+    fn from(Vec4 { x, y, z, w }: Vec4<T>) -> Self {
+        Self::new(x, y, z, w)
+    }
+}
+
+impl<T: Copy> From<Quat<T>> for Vec4<T> {
+    #[inline]
+    fn from(Quat { x, y, z, w }: Quat<T>) -> Self {
+        Self::new(x, y, z, w)
+    }
+}
+
+impl<T: Float> From<Quat<T>> for Mat3<T> {
+    #[inline]
+    fn from(q: Quat<T>) -> Self {
+        q.to_mat3()
+    }
+}
+
+impl<T: Float> From<Quat<T>> for Mat4<T> {
+    #[inline]
+    fn from(q: Quat<T>) -> Self {
+        q.to_mat4()
+    }
+}
+
+impl<T: Float> From<Mat3<T>> for Quat<T> {
+    fn from(m: Mat3<T>) -> Self {
+        Self::from_rotation_cols(m.cols())
+    }
+}
+
+impl<T: Float> From<Mat4<T>> for Quat<T> {
+    fn from(m: Mat4<T>) -> Self {
+        let c = m.cols();
+        Self::from_rotation_cols([c[0], c[1], c[2], c[4], c[5], c[6], c[8], c[9], c[10]])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_approx_eq;
+
+    const EPS: f32 = 1e-5;
+
+    fn assert_quat_near(a: Quat<f32>, b: Quat<f32>) {
+        assert!(a.dot(&b).abs() > 1.0 - EPS, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn test_quat_identity_rotate() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(Quat::identity() * v, v);
+    }
+
+    #[test]
+    fn test_quat_axis_angle_matches_vec3_rotate() {
+        let axis = Vec3::new(1.0, 1.0, 0.0);
+        let v = Vec3::new(0.3, -2.0, 5.0);
+        let q = Quat::from_axis_angle(37.0, &axis);
+        assert_approx_eq!(q * v, v.rotate(37.0, &axis), abs = EPS);
+    }
+
+    #[test]
+    fn test_quat_mul_composes() {
+        let a = Quat::from_axis_angle(90.0, &Vec3::new(0.0, 1.0, 0.0));
+        let b = Quat::from_axis_angle(90.0, &Vec3::new(1.0, 0.0, 0.0));
+        let v = Vec3::new(0.0, 0.0, 1.0);
+        assert_approx_eq!((a * b) * v, a * (b * v), abs = EPS);
+    }
+
+    #[test]
+    fn test_quat_euler() {
+        let q = Quat::from_euler(0.0, 90.0, 0.0);
+        assert_approx_eq!(
+            q * Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 0.0, -1.0),
+            abs = EPS
+        );
+    }
+
+    #[test]
+    fn test_quat_invert() {
+        let q = Quat::new(1.0, 2.0, 3.0, 4.0);
+        let inv = q.invert().unwrap();
+        assert_quat_near(q * inv, Quat::identity());
+        assert_eq!(Quat::new(0.0, 0.0, 0.0, 0.0).invert(), None);
+    }
+
+    #[test]
+    fn test_quat_conjugate_undoes_rotation() {
+        let q = Quat::from_axis_angle(63.0, &Vec3::new(0.2, 1.0, -0.4));
+        let v = Vec3::new(4.0, -1.0, 0.5);
+        assert_approx_eq!(q.conjugate() * (q * v), v, abs = EPS);
+    }
+
+    #[test]
+    fn test_quat_slerp() {
+        let axis = Vec3::new(0.0, 0.0, 1.0);
+        let a = Quat::identity();
+        let b = Quat::from_axis_angle(90.0, &axis);
+        assert_quat_near(Quat::slerp(&a, &b, 0.0), a);
+        assert_quat_near(Quat::slerp(&a, &b, 1.0), b);
+        assert_quat_near(Quat::slerp(&a, &b, 0.5), Quat::from_axis_angle(45.0, &axis));
+    }
+
+    #[test]
+    fn test_quat_slerp_shortest_path() {
+        let axis = Vec3::new(0.0, 1.0, 0.0);
+        let a = Quat::from_axis_angle(10.0, &axis);
+        let b = -Quat::from_axis_angle(30.0, &axis);
+        assert_quat_near(Quat::slerp(&a, &b, 0.5), Quat::from_axis_angle(20.0, &axis));
+    }
+
+    #[test]
+    fn test_quat_nlerp_is_normalized() {
+        let a = Quat::from_axis_angle(10.0, &Vec3::new(1.0, 0.0, 0.0));
+        let b = Quat::from_axis_angle(80.0, &Vec3::new(0.0, 1.0, 0.0));
+        assert!((Quat::nlerp(&a, &b, 0.3).length() - 1.0).abs() < EPS);
+    }
+
+    #[test]
+    fn test_quat_mat3_roundtrip() {
+        for q in [
+            Quat::from_euler(10.0, 20.0, 30.0),
+            Quat::from_axis_angle(179.0, &Vec3::new(1.0, 0.0, 0.0)),
+            Quat::from_axis_angle(179.0, &Vec3::new(0.0, 1.0, 0.0)),
+            Quat::from_axis_angle(179.0, &Vec3::new(0.0, 0.0, 1.0)),
+        ] {
+            assert_quat_near(Quat::from(q.to_mat3()), q);
+            assert_quat_near(Quat::from(q.to_mat4()), q);
+        }
+    }
+
+    #[test]
+    fn test_quat_mat3_rotates_like_quat() {
+        let q = Quat::from_euler(15.0, -40.0, 75.0);
+        let [c0, c1, c2] = q.to_mat3().columns();
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        let by_matrix = c0 * Scalar(v.x) + c1 * Scalar(v.y) + c2 * Scalar(v.z);
+        assert_approx_eq!(by_matrix, q * v, abs = EPS);
+    }
+
+    #[test]
+    fn test_quat_f64_matches_f32() {
+        let axis = Vec3::new(0.2, 1.0, -0.4);
+        let q32 = Quat::from_axis_angle(63.0f32, &axis);
+        let q64 = Quat::from_axis_angle(63.0f64, &axis.cast());
+        assert_quat_near(q64.cast(), q32);
+        let v = Vec3::new(1.0f64, 2.0, 3.0);
+        assert!((q64.conjugate() * (q64 * v) - v).length() < 1e-12);
+    }
+
+    #[test]
+    fn test_quat_rotation_arc() {
+        let from = Vec3::new(1.0, 0.0, 0.0);
+        for to in [
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(0.0, 0.0, -1.0),
+            Vec3::new(-1.0, 0.0, 0.0),
+        ] {
+            assert_approx_eq!(Quat::from_rotation_arc(&from, &to) * from, to, abs = EPS);
+        }
+    }
+}
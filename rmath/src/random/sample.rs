@@ -0,0 +1,126 @@
+//! Mappings from the unit square onto common sampling domains. Each takes
+//! a point `u` in `[0, 1)²` so it works with [`Pcg32::next_vec2`] as well
+//! as with the low-discrepancy points of [`crate::random::sequence`].
+
+use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+use crate::{Scalar, random::Pcg32, vec2::Vec2, vec3::Vec3};
+
+/// Concentric (Shirley-Chiu) mapping onto the unit disk, uniform by area.
+pub fn disk_from_square(u: Vec2<f32>) -> Vec2<f32> {
+    let Vec2 { x, y } = u * Scalar(2.0) - Vec2::one();
+    if x == 0.0 && y == 0.0 {
+        return Vec2::zero();
+    }
+
+    let (r, theta) = if x.abs() > y.abs() {
+        (x, FRAC_PI_4 * (y / x))
+    } else {
+        (y, FRAC_PI_2 - FRAC_PI_4 * (x / y))
+    };
+    Vec2::new(theta.cos(), theta.sin()) * Scalar(r)
+}
+
+/// Uniform direction on the unit sphere.
+pub fn sphere_from_square(Vec2 { x, y }: Vec2<f32>) -> Vec3<f32> {
+    let z = 1.0 - 2.0 * x;
+    let r = (1.0 - z * z).max(0.0).sqrt();
+    let phi = 2.0 * PI * y;
+    Vec3::new(r * phi.cos(), r * phi.sin(), z)
+}
+
+/// Cosine weighted direction in the hemisphere around the unit `normal`.
+pub fn cosine_hemisphere_from_square(u: Vec2<f32>, normal: &Vec3<f32>) -> Vec3<f32> {
+    let Vec2 { x, y } = disk_from_square(u);
+    let z = (1.0 - x * x - y * y).max(0.0).sqrt();
+    let (tangent, bitangent) = orthonormal_basis(normal);
+    tangent * Scalar(x) + bitangent * Scalar(y) + *normal * Scalar(z)
+}
+
+/// Tangent and bitangent completing the unit `normal` to a right-handed
+/// basis (Duff et al. 2017).
+pub fn orthonormal_basis(n: &Vec3<f32>) -> (Vec3<f32>, Vec3<f32>) {
+    let sign = 1.0f32.copysign(n.z);
+    let a = -1.0 / (sign + n.z);
+    let b = n.x * n.y * a;
+    (
+        Vec3::new(1.0 + sign * n.x * n.x * a, sign * b, -sign * n.x),
+        Vec3::new(b, sign + n.y * n.y * a, -n.y),
+    )
+}
+
+pub fn in_unit_disk(rng: &mut Pcg32) -> Vec2<f32> {
+    disk_from_square(rng.next_vec2())
+}
+
+pub fn on_unit_sphere(rng: &mut Pcg32) -> Vec3<f32> {
+    sphere_from_square(rng.next_vec2())
+}
+
+pub fn cosine_hemisphere(rng: &mut Pcg32, normal: &Vec3<f32>) -> Vec3<f32> {
+    cosine_hemisphere_from_square(rng.next_vec2(), normal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const N: usize = 20_000;
+
+    #[test]
+    fn test_sample_disk() {
+        let mut rng = Pcg32::from_seed(1);
+        let samples: Vec<_> = (0..N).map(|_| in_unit_disk(&mut rng)).collect();
+        assert!(samples.iter().all(|p| p.length() <= 1.0 + 1e-6));
+
+        let mean = samples.iter().copied().sum::<Vec2<f32>>() / Scalar(N as f32);
+        assert!(mean.length() < 0.02, "{mean:?}");
+
+        // Uniform by area: a quarter of the points fall within radius 1/2.
+        let inner = samples.iter().filter(|p| p.length() < 0.5).count();
+        assert!((inner as f32 / N as f32 - 0.25).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_sample_sphere() {
+        let mut rng = Pcg32::from_seed(2);
+        let samples: Vec<_> = (0..N).map(|_| on_unit_sphere(&mut rng)).collect();
+        assert!(samples.iter().all(|p| (p.length() - 1.0).abs() < 1e-5));
+
+        let mean = samples.iter().copied().sum::<Vec3<f32>>() / N as f32;
+        assert!(mean.length() < 0.02, "{mean:?}");
+
+        let upper = samples.iter().filter(|p| p.y > 0.0).count();
+        assert!((upper as f32 / N as f32 - 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_sample_cosine_hemisphere() {
+        let normal = Vec3::new(0.3, -0.5, 0.8).normalize();
+        let mut rng = Pcg32::from_seed(3);
+        let cosines: Vec<_> = (0..N)
+            .map(|_| cosine_hemisphere(&mut rng, &normal))
+            .inspect(|d| assert!((d.length() - 1.0).abs() < 1e-5))
+            .map(|d| d.dot(&normal))
+            .collect();
+        assert!(cosines.iter().all(|&c| c >= -1e-6));
+
+        // E[cos θ] = 2/3 for a cosine weighted hemisphere.
+        let mean = cosines.iter().sum::<f32>() / N as f32;
+        assert!((mean - 2.0 / 3.0).abs() < 0.01, "{mean}");
+    }
+
+    #[test]
+    fn test_sample_orthonormal_basis() {
+        for n in [
+            Vec3::new(0.0, 0.0, 1.0),
+            Vec3::new(0.0, 0.0, -1.0),
+            Vec3::new(1.0, 2.0, -3.0).normalize(),
+        ] {
+            let (t, b) = orthonormal_basis(&n);
+            assert!((t.length() - 1.0).abs() < 1e-6 && (b.length() - 1.0).abs() < 1e-6);
+            assert!(t.dot(&b).abs() < 1e-6 && t.dot(&n).abs() < 1e-6);
+            assert!((t.cross(&b) - n).length() < 1e-6);
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! Low-discrepancy point sets: more even coverage than random samples for
+//! the same count, at the cost of correlation between dimensions.
+
+use crate::vec2::Vec2;
+
+const PRIMES: [u32; 16] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53];
+
+/// `index` with its base `base` digits mirrored around the radix point.
+/// Panics if `base` is below 2.
+pub fn radical_inverse(base: u32, mut index: u32) -> f32 {
+    assert!(
+        base >= 2,
+        "radical_inverse base must be at least 2, got {base}"
+    );
+    if base == 2 {
+        return (index.reverse_bits() >> 8) as f32 * (1.0 / (1u32 << 24) as f32);
+    }
+
+    let inv_base = 1.0 / base as f64;
+    let mut reversed = 0u64;
+    let mut inv_base_n = 1.0;
+    while index > 0 {
+        reversed = reversed * base as u64 + (index % base) as u64;
+        inv_base_n *= inv_base;
+        index /= base;
+    }
+    ((reversed as f64 * inv_base_n) as f32).min(1.0 - f32::EPSILON / 2.0)
+}
+
+/// Point `index` of the Halton sequence, one prime base per dimension.
+/// Supports up to 16 dimensions.
+pub fn halton<const N: usize>(index: u32) -> [f32; N] {
+    std::array::from_fn(|i| radical_inverse(PRIMES[i], index))
+}
+
+/// Point `index` of the `count` point Hammersley set in the unit square.
+pub fn hammersley(index: u32, count: u32) -> Vec2<f32> {
+    Vec2::new(index as f32 / count as f32, radical_inverse(2, index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequence_radical_inverse() {
+        let base2: Vec<_> = (0..5).map(|i| radical_inverse(2, i)).collect();
+        assert_eq!(base2, [0.0, 0.5, 0.25, 0.75, 0.125]);
+
+        let base3: Vec<_> = (1..5).map(|i| radical_inverse(3, i)).collect();
+        let expected = [1.0 / 3.0, 2.0 / 3.0, 1.0 / 9.0, 4.0 / 9.0];
+        for (a, b) in base3.iter().zip(expected) {
+            assert!((a - b).abs() < 1e-6);
+        }
+        assert!(radical_inverse(5, u32::MAX) < 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "base must be at least 2")]
+    fn test_sequence_radical_inverse_base_one() {
+        radical_inverse(1, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "base must be at least 2")]
+    fn test_sequence_radical_inverse_base_zero() {
+        radical_inverse(0, 3);
+    }
+
+    #[test]
+    fn test_sequence_halton() {
+        assert_eq!(halton::<3>(1), [0.5, 1.0 / 3.0, 0.2]);
+        assert_eq!(halton::<2>(0), [0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_sequence_stratified() {
+        // Every 1/n x 1 column and 1 x 1/n row of the unit square holds
+        // exactly one of n Hammersley points when n is a power of two.
+        let n = 64;
+        let mut columns = vec![0; n as usize];
+        let mut rows = vec![0; n as usize];
+        for i in 0..n {
+            let Vec2 { x, y } = hammersley(i, n);
+            columns[(x * n as f32) as usize] += 1;
+            rows[(y * n as f32) as usize] += 1;
+        }
+        assert!(columns.iter().chain(&rows).all(|&c| c == 1));
+    }
+}
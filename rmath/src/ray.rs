@@ -0,0 +1,281 @@
+use crate::{
+    Scalar,
+    bounds::{Aabb, Sphere},
+    float::Float,
+    mat4::Mat4,
+    plane::Plane,
+    vec3::Vec3,
+};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Ray<T: Copy> {
+    pub origin: Vec3<T>,
+    pub direction: Vec3<T>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TriangleHit<T> {
+    pub t: T,
+    /// Barycentric weight of the second vertex.
+    pub u: T,
+    /// Barycentric weight of the third vertex.
+    pub v: T,
+}
+
+impl<T: Copy> Ray<T> {
+    #[inline]
+    pub const fn new(origin: Vec3<T>, direction: Vec3<T>) -> Self {
+        Self { origin, direction }
+    }
+}
+
+impl<T: Float> Ray<T> {
+    pub fn from_points(from: &Vec3<T>, to: &Vec3<T>) -> Self {
+        Self::new(*from, (*to - *from).normalize())
+    }
+
+    pub fn at(&self, t: T) -> Vec3<T> {
+        self.origin + self.direction * Scalar(t)
+    }
+
+    /// The direction is not renormalized, so hit distances along the
+    /// transformed ray match those along the original.
+    pub fn transform(&self, m: &Mat4<T>) -> Self {
+        Self::new(
+            m.transform_point(&self.origin),
+            m.transform_vector(&self.direction),
+        )
+    }
+
+    pub fn intersect_plane(&self, plane: &Plane<T>) -> Option<T> {
+        let denom = plane.normal.dot(&self.direction);
+        // Relative to the input lengths, as transformed rays are not unit length.
+        if denom.abs() <= T::EPSILON * plane.normal.length() * self.direction.length() {
+            return None;
+        }
+        let t = -plane.signed_distance(&self.origin) / denom;
+        (t >= T::ZERO).then_some(t)
+    }
+
+    /// Nearest non-negative hit; a ray starting inside hits the far side.
+    pub fn intersect_sphere(&self, sphere: &Sphere<T>) -> Option<T> {
+        let oc = self.origin - sphere.center;
+        let a = self.direction.dot(&self.direction);
+        let half_b = oc.dot(&self.direction);
+        let c = oc.dot(&oc) - sphere.radius * sphere.radius;
+        let discriminant = half_b * half_b - a * c;
+        if discriminant < T::ZERO || a == T::ZERO {
+            return None;
+        }
+
+        let sqrt_d = discriminant.sqrt();
+        let near = (-half_b - sqrt_d) / a;
+        let far = (-half_b + sqrt_d) / a;
+        if near >= T::ZERO {
+            Some(near)
+        } else if far >= T::ZERO {
+            Some(far)
+        } else {
+            None
+        }
+    }
+
+    /// Slab test returning the entry and exit distances, clamped to the ray start.
+    pub fn intersect_aabb(&self, aabb: &Aabb<T>) -> Option<(T, T)> {
+        let mut t_min = T::ZERO;
+        let mut t_max = T::INFINITY;
+
+        for axis in 0..3 {
+            let origin = self.origin.data()[axis];
+            let dir = self.direction.data()[axis];
+            let lo = aabb.min.data()[axis];
+            let hi = aabb.max.data()[axis];
+
+            if dir == T::ZERO {
+                if origin < lo || origin > hi {
+                    return None;
+                }
+                continue;
+            }
+
+            let inv = T::ONE / dir;
+            let (t0, t1) = ((lo - origin) * inv, (hi - origin) * inv);
+            let (t0, t1) = if t0 > t1 { (t1, t0) } else { (t0, t1) };
+            t_min = t_min.max(t0);
+            t_max = t_max.min(t1);
+            if t_min > t_max {
+                return None;
+            }
+        }
+
+        Some((t_min, t_max))
+    }
+
+    /// Möller–Trumbore; hits from either side of the triangle are reported.
+    pub fn intersect_triangle(
+        &self,
+        a: &Vec3<T>,
+        b: &Vec3<T>,
+        c: &Vec3<T>,
+    ) -> Option<TriangleHit<T>> {
+        let e1 = *b - *a;
+        let e2 = *c - *a;
+        let p = self.direction.cross(&e2);
+        let det = e1.dot(&p);
+        // Scaled so small triangles and unnormalized directions still hit.
+        if det.abs() <= T::EPSILON * e1.length() * e2.length() * self.direction.length() {
+            return None;
+        }
+
+        let inv_det = T::ONE / det;
+        let s = self.origin - *a;
+        let u = s.dot(&p) * inv_det;
+        if u < T::ZERO || u > T::ONE {
+            return None;
+        }
+
+        let q = s.cross(&e1);
+        let v = self.direction.dot(&q) * inv_det;
+        if v < T::ZERO || u + v > T::ONE {
+            return None;
+        }
+
+        let t = e2.dot(&q) * inv_det;
+        (t >= T::ZERO).then_some(TriangleHit { t, u, v })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_approx_eq;
+
+    fn ray_z() -> Ray<f32> {
+        Ray::new(Vec3::new(0.25, 0.25, -5.0), Vec3::new(0.0, 0.0, 1.0))
+    }
+
+    #[test]
+    fn test_ray_at() {
+        assert_eq!(ray_z().at(2.0), Vec3::new(0.25, 0.25, -3.0));
+    }
+
+    #[test]
+    fn test_ray_transform() {
+        let to_local =
+            Mat4::scale(&Vec3::scalar(0.5)) * Mat4::translation(&Vec3::new(-1.0, 0.0, 0.0));
+        let a = Vec3::new(1.0, 0.0, 0.0);
+        let b = Vec3::new(3.0, 0.0, 0.0);
+        let c = Vec3::new(1.0, 2.0, 0.0);
+        let world = Ray::new(Vec3::new(1.5, 0.5, -4.0), Vec3::new(0.0, 0.0, 1.0));
+        let local = world.transform(&to_local);
+        let [la, lb, lc] = [a, b, c].map(|p| to_local.transform_point(&p));
+        assert_eq!(
+            local.intersect_triangle(&la, &lb, &lc).map(|h| h.t),
+            world.intersect_triangle(&a, &b, &c).map(|h| h.t)
+        );
+    }
+
+    #[test]
+    fn test_ray_plane() {
+        let plane = Plane::from_point_normal(&Vec3::zero(), &Vec3::new(0.0, 0.0, 1.0));
+        assert_eq!(ray_z().intersect_plane(&plane), Some(5.0));
+
+        let away = Ray::new(Vec3::new(0.0, 0.0, -5.0), Vec3::new(0.0, 0.0, -1.0));
+        assert_eq!(away.intersect_plane(&plane), None);
+
+        let parallel = Ray::new(Vec3::new(0.0, 0.0, -5.0), Vec3::new(1.0, 0.0, 0.0));
+        assert_eq!(parallel.intersect_plane(&plane), None);
+
+        let scaled = Ray::new(Vec3::new(0.0, 0.0, -5e-4), Vec3::new(0.0, 0.0, 1e-4));
+        assert_approx_eq!(scaled.intersect_plane(&plane).unwrap(), 5.0);
+    }
+
+    #[test]
+    fn test_ray_sphere() {
+        let ray = Ray::new(Vec3::new(0.0, 0.0, -5.0), Vec3::new(0.0, 0.0, 1.0));
+        let sphere = Sphere::new(Vec3::zero(), 1.0);
+        assert_eq!(ray.intersect_sphere(&sphere), Some(4.0));
+
+        let inside = Ray::new(Vec3::zero(), Vec3::new(0.0, 0.0, 1.0));
+        assert_eq!(inside.intersect_sphere(&sphere), Some(1.0));
+
+        let miss = Ray::new(Vec3::new(0.0, 2.0, -5.0), Vec3::new(0.0, 0.0, 1.0));
+        assert_eq!(miss.intersect_sphere(&sphere), None);
+
+        let behind = Ray::new(Vec3::new(0.0, 0.0, 5.0), Vec3::new(0.0, 0.0, 1.0));
+        assert_eq!(behind.intersect_sphere(&sphere), None);
+    }
+
+    #[test]
+    fn test_ray_aabb() {
+        let aabb = Aabb::new(Vec3::zero(), Vec3::one());
+        assert_eq!(ray_z().intersect_aabb(&aabb), Some((5.0, 6.0)));
+
+        let inside = Ray::new(Vec3::scalar(0.5), Vec3::new(1.0, 0.0, 0.0));
+        assert_eq!(inside.intersect_aabb(&aabb), Some((0.0, 0.5)));
+
+        let miss = Ray::new(Vec3::new(2.0, 0.5, -5.0), Vec3::new(0.0, 0.0, 1.0));
+        assert_eq!(miss.intersect_aabb(&aabb), None);
+
+        let diagonal_miss = Ray::new(Vec3::new(-1.0, 3.5, 0.5), Vec3::new(1.0, -1.0, 0.0));
+        assert_eq!(diagonal_miss.intersect_aabb(&aabb), None);
+    }
+
+    #[test]
+    fn test_ray_triangle() {
+        let a = Vec3::new(0.0, 0.0, 0.0);
+        let b = Vec3::new(1.0, 0.0, 0.0);
+        let c = Vec3::new(0.0, 1.0, 0.0);
+        let hit = ray_z().intersect_triangle(&a, &b, &c).unwrap();
+        assert_eq!(
+            hit,
+            TriangleHit {
+                t: 5.0,
+                u: 0.25,
+                v: 0.25
+            }
+        );
+
+        let point = a * Scalar(1.0 - hit.u - hit.v) + b * Scalar(hit.u) + c * Scalar(hit.v);
+        assert_eq!(point, ray_z().at(hit.t));
+    }
+
+    #[test]
+    fn test_ray_triangle_miss() {
+        let a = Vec3::new(0.0, 0.0, 0.0);
+        let b = Vec3::new(1.0, 0.0, 0.0);
+        let c = Vec3::new(0.0, 1.0, 0.0);
+        let outside = Ray::new(Vec3::new(0.75, 0.75, -1.0), Vec3::new(0.0, 0.0, 1.0));
+        assert_eq!(outside.intersect_triangle(&a, &b, &c), None);
+
+        let parallel = Ray::new(Vec3::new(0.1, 0.1, -1.0), Vec3::new(1.0, 0.0, 0.0));
+        assert_eq!(parallel.intersect_triangle(&a, &b, &c), None);
+
+        let behind = Ray::new(Vec3::new(0.1, 0.1, 1.0), Vec3::new(0.0, 0.0, 1.0));
+        assert_eq!(behind.intersect_triangle(&a, &b, &c), None);
+    }
+
+    #[test]
+    fn test_ray_triangle_small() {
+        // det is ~1e-11 here, far below a fixed epsilon.
+        let a = Vec3::new(0.0, 0.0, 0.0);
+        let b = Vec3::new(3e-4, 0.0, 0.0);
+        let c = Vec3::new(0.0, 3e-4, 0.0);
+        let ray = Ray::new(Vec3::new(1e-4, 1e-4, -1e-3), Vec3::new(0.0, 0.0, 1e-4));
+        let hit = ray.intersect_triangle(&a, &b, &c).unwrap();
+        assert_approx_eq!(hit.t, 10.0);
+        assert_approx_eq!(ray.at(hit.t), Vec3::new(1e-4, 1e-4, 0.0), abs = 1e-9);
+
+        let to_local = Mat4::scale(&Vec3::scalar(1e-4));
+        let world = ray_z();
+        let [la, lb, lc] = [
+            Vec3::zero(),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        ]
+        .map(|p| to_local.transform_point(&p));
+        let local = world.transform(&to_local);
+        assert_approx_eq!(local.intersect_triangle(&la, &lb, &lc).unwrap().t, 5.0);
+    }
+}
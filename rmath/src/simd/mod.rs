@@ -0,0 +1,309 @@
+//! Per-type kernels behind the `Mat4`/`Vec4` arithmetic.
+//!
+//! `f32` picks an SSE2 or NEON implementation when the target supports it
+//! at compile time, every other primitive number uses the portable code in
+//! [`scalar`].
+
+use std::ops::{Add, Mul, Sub};
+
+use crate::{float::Float, mat4::Mat4, vec4::Vec4};
+
+pub mod scalar;
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+mod sse2;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+use sse2 as arch;
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+mod neon;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use neon as arch;
+
+pub trait Kernels: Copy {
+    #[inline]
+    fn mat4_mul(a: &Mat4<Self>, b: &Mat4<Self>) -> Mat4<Self>
+    where
+        Self: Add<Output = Self> + Mul<Output = Self>,
+    {
+        scalar::mat4_mul(a, b)
+    }
+
+    #[inline]
+    fn mat4_mul_vec4(m: &Mat4<Self>, v: &Vec4<Self>) -> Vec4<Self>
+    where
+        Self: Add<Output = Self> + Mul<Output = Self>,
+    {
+        scalar::mat4_mul_vec4(m, v)
+    }
+
+    #[inline]
+    fn mat4_transpose(m: &Mat4<Self>) -> Mat4<Self> {
+        scalar::mat4_transpose(m)
+    }
+
+    #[inline]
+    fn mat4_invert(m: &Mat4<Self>) -> Option<Mat4<Self>>
+    where
+        Self: Float,
+    {
+        scalar::mat4_invert(m)
+    }
+
+    #[inline]
+    fn vec4_dot(a: &Vec4<Self>, b: &Vec4<Self>) -> Self
+    where
+        Self: Float,
+    {
+        scalar::vec4_dot(a, b)
+    }
+
+    #[inline]
+    fn vec4_add(a: &Vec4<Self>, b: &Vec4<Self>) -> Vec4<Self>
+    where
+        Self: Add<Output = Self>,
+    {
+        scalar::vec4_add(a, b)
+    }
+
+    #[inline]
+    fn vec4_sub(a: &Vec4<Self>, b: &Vec4<Self>) -> Vec4<Self>
+    where
+        Self: Sub<Output = Self>,
+    {
+        scalar::vec4_sub(a, b)
+    }
+
+    #[inline]
+    fn vec4_mul(a: &Vec4<Self>, b: &Vec4<Self>) -> Vec4<Self>
+    where
+        Self: Mul<Output = Self>,
+    {
+        scalar::vec4_mul(a, b)
+    }
+
+    #[inline]
+    fn vec4_scale(v: &Vec4<Self>, s: Self) -> Vec4<Self>
+    where
+        Self: Mul<Output = Self>,
+    {
+        scalar::vec4_scale(v, s)
+    }
+}
+
+macro_rules! impl_scalar_kernels {
+    ($($t:ty),*) => {
+        $(impl Kernels for $t {})*
+    };
+}
+
+impl_scalar_kernels!(
+    f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+// SAFETY (for every `unsafe` block below): the `arch` module is only
+// compiled when its target feature is enabled for the whole build.
+impl Kernels for f32 {
+    #[cfg(any(
+        all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse2"
+        ),
+        all(target_arch = "aarch64", target_feature = "neon")
+    ))]
+    #[inline]
+    fn mat4_mul(a: &Mat4<Self>, b: &Mat4<Self>) -> Mat4<Self> {
+        unsafe { arch::mat4_mul(a, b) }
+    }
+
+    #[cfg(any(
+        all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse2"
+        ),
+        all(target_arch = "aarch64", target_feature = "neon")
+    ))]
+    #[inline]
+    fn mat4_mul_vec4(m: &Mat4<Self>, v: &Vec4<Self>) -> Vec4<Self> {
+        unsafe { arch::mat4_mul_vec4(m, v) }
+    }
+
+    #[cfg(any(
+        all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse2"
+        ),
+        all(target_arch = "aarch64", target_feature = "neon")
+    ))]
+    #[inline]
+    fn mat4_transpose(m: &Mat4<Self>) -> Mat4<Self> {
+        unsafe { arch::mat4_transpose(m) }
+    }
+
+    // NEON keeps the scalar inverse; the block-wise SSE2 version relies on
+    // arbitrary two-register shuffles.
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"
+    ))]
+    #[inline]
+    fn mat4_invert(m: &Mat4<Self>) -> Option<Mat4<Self>> {
+        unsafe { arch::mat4_invert(m) }
+    }
+
+    #[cfg(any(
+        all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse2"
+        ),
+        all(target_arch = "aarch64", target_feature = "neon")
+    ))]
+    #[inline]
+    fn vec4_dot(a: &Vec4<Self>, b: &Vec4<Self>) -> Self {
+        unsafe { arch::vec4_dot(a, b) }
+    }
+
+    #[cfg(any(
+        all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse2"
+        ),
+        all(target_arch = "aarch64", target_feature = "neon")
+    ))]
+    #[inline]
+    fn vec4_add(a: &Vec4<Self>, b: &Vec4<Self>) -> Vec4<Self> {
+        unsafe { arch::vec4_add(a, b) }
+    }
+
+    #[cfg(any(
+        all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse2"
+        ),
+        all(target_arch = "aarch64", target_feature = "neon")
+    ))]
+    #[inline]
+    fn vec4_sub(a: &Vec4<Self>, b: &Vec4<Self>) -> Vec4<Self> {
+        unsafe { arch::vec4_sub(a, b) }
+    }
+
+    #[cfg(any(
+        all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse2"
+        ),
+        all(target_arch = "aarch64", target_feature = "neon")
+    ))]
+    #[inline]
+    fn vec4_mul(a: &Vec4<Self>, b: &Vec4<Self>) -> Vec4<Self> {
+        unsafe { arch::vec4_mul(a, b) }
+    }
+
+    #[cfg(any(
+        all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse2"
+        ),
+        all(target_arch = "aarch64", target_feature = "neon")
+    ))]
+    #[inline]
+    fn vec4_scale(v: &Vec4<Self>, s: Self) -> Vec4<Self> {
+        unsafe { arch::vec4_scale(v, s) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vec3::Vec3;
+
+    fn matrices() -> Vec<Mat4<f32>> {
+        vec![
+            Mat4::identity(),
+            Mat4::translation(&Vec3::new(1.0, -2.0, 3.5)),
+            Mat4::rotation(33.0, &Vec3::new(0.3, 1.0, -0.7)),
+            Mat4::scale(&Vec3::new(2.0, 0.5, 4.0)),
+            Mat4::perspective(70.0, 16.0 / 9.0, 0.01, 1250.0),
+            Mat4::look_at(
+                &Vec3::new(4.0, 2.0, -1.0),
+                &Vec3::zero(),
+                &Vec3::new(0.0, 1.0, 0.0),
+            ),
+            Mat4::from(std::array::from_fn(|i| ((i * 7 + 3) % 11) as f32 - 5.0)),
+        ]
+    }
+
+    fn assert_near(a: &[f32], b: &[f32]) {
+        for (x, y) in a.iter().zip(b) {
+            let tolerance = 1e-4 * x.abs().max(y.abs()).max(1.0);
+            assert!((x - y).abs() <= tolerance, "{a:?} != {b:?}");
+        }
+    }
+
+    #[test]
+    fn test_kernels_mat4_mul() {
+        for a in matrices() {
+            for b in matrices() {
+                let simd = <f32 as Kernels>::mat4_mul(&a, &b);
+                assert_near(&simd.cols(), &scalar::mat4_mul(&a, &b).cols());
+            }
+        }
+    }
+
+    #[test]
+    fn test_kernels_mat4_mul_vec4() {
+        let v = Vec4::new(1.5, -2.0, 0.25, 1.0);
+        for m in matrices() {
+            let simd = <f32 as Kernels>::mat4_mul_vec4(&m, &v);
+            assert_near(&simd.data(), &scalar::mat4_mul_vec4(&m, &v).data());
+        }
+    }
+
+    #[test]
+    fn test_kernels_mat4_transpose() {
+        for m in matrices() {
+            assert_eq!(
+                <f32 as Kernels>::mat4_transpose(&m),
+                scalar::mat4_transpose(&m)
+            );
+        }
+    }
+
+    #[test]
+    fn test_kernels_mat4_invert() {
+        for m in matrices() {
+            let simd = <f32 as Kernels>::mat4_invert(&m).unwrap();
+            let scalar = scalar::mat4_invert(&m).unwrap();
+            assert_near(&simd.cols(), &scalar.cols());
+        }
+        let singular = Mat4::scale(&Vec3::new(1.0, 0.0, 1.0));
+        assert_eq!(<f32 as Kernels>::mat4_invert(&singular), None);
+        assert_eq!(scalar::mat4_invert(&singular), None);
+    }
+
+    #[test]
+    fn test_kernels_vec4_dot() {
+        let a = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        let b = Vec4::new(-0.5, 0.25, 8.0, 1.0);
+        assert_eq!(<f32 as Kernels>::vec4_dot(&a, &b), scalar::vec4_dot(&a, &b));
+    }
+
+    #[test]
+    fn test_kernels_vec4_arithmetic() {
+        let a = Vec4::new(1.0, -2.0, 3.5, 4.0);
+        let b = Vec4::new(-0.5, 0.25, 8.0, 1e-3);
+        assert_eq!(<f32 as Kernels>::vec4_add(&a, &b), scalar::vec4_add(&a, &b));
+        assert_eq!(<f32 as Kernels>::vec4_sub(&a, &b), scalar::vec4_sub(&a, &b));
+        assert_eq!(<f32 as Kernels>::vec4_mul(&a, &b), scalar::vec4_mul(&a, &b));
+        assert_eq!(
+            <f32 as Kernels>::vec4_scale(&a, -1.5),
+            scalar::vec4_scale(&a, -1.5)
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use std::arch::aarch64::*;
+
+use crate::{mat4::Mat4, vec4::Vec4};
+
+#[inline]
+#[target_feature(enable = "neon")]
+fn load(v: &Vec4<f32>) -> float32x4_t {
+    // SAFETY: `data` is four contiguous f32.
+    unsafe { vld1q_f32(v.data().as_ptr()) }
+}
+
+#[inline]
+#[target_feature(enable = "neon")]
+fn store(v: float32x4_t) -> Vec4<f32> {
+    let mut out = [0.0; 4];
+    // SAFETY: `out` has room for four f32.
+    unsafe { vst1q_f32(out.as_mut_ptr(), v) };
+    Vec4::from(out)
+}
+
+#[inline]
+#[target_feature(enable = "neon")]
+fn linear_combine([c0, c1, c2, c3]: &[float32x4_t; 4], v: float32x4_t) -> float32x4_t {
+    let r = vmulq_laneq_f32::<0>(*c0, v);
+    let r = vfmaq_laneq_f32::<1>(r, *c1, v);
+    let r = vfmaq_laneq_f32::<2>(r, *c2, v);
+    vfmaq_laneq_f32::<3>(r, *c3, v)
+}
+
+#[target_feature(enable = "neon")]
+pub fn mat4_mul_vec4(m: &Mat4<f32>, v: &Vec4<f32>) -> Vec4<f32> {
+    let cols = m.columns().map(|c| load(&c));
+    store(linear_combine(&cols, load(v)))
+}
+
+#[target_feature(enable = "neon")]
+pub fn mat4_mul(a: &Mat4<f32>, b: &Mat4<f32>) -> Mat4<f32> {
+    let a = a.columns().map(|c| load(&c));
+    let [c0, c1, c2, c3] = b.columns().map(|c| store(linear_combine(&a, load(&c))));
+    Mat4::new(c0, c1, c2, c3)
+}
+
+#[target_feature(enable = "neon")]
+pub fn mat4_transpose(m: &Mat4<f32>) -> Mat4<f32> {
+    let cols = m.cols();
+    // SAFETY: `cols` is sixteen contiguous f32; the de-interleaving load
+    // yields the rows of the column-major matrix.
+    let rows = unsafe { vld4q_f32(cols.as_ptr()) };
+    Mat4::new(store(rows.0), store(rows.1), store(rows.2), store(rows.3))
+}
+
+#[target_feature(enable = "neon")]
+pub fn vec4_dot(a: &Vec4<f32>, b: &Vec4<f32>) -> f32 {
+    vaddvq_f32(vmulq_f32(load(a), load(b)))
+}
+
+#[target_feature(enable = "neon")]
+pub fn vec4_add(a: &Vec4<f32>, b: &Vec4<f32>) -> Vec4<f32> {
+    store(vaddq_f32(load(a), load(b)))
+}
+
+#[target_feature(enable = "neon")]
+pub fn vec4_sub(a: &Vec4<f32>, b: &Vec4<f32>) -> Vec4<f32> {
+    store(vsubq_f32(load(a), load(b)))
+}
+
+#[target_feature(enable = "neon")]
+pub fn vec4_mul(a: &Vec4<f32>, b: &Vec4<f32>) -> Vec4<f32> {
+    store(vmulq_f32(load(a), load(b)))
+}
+
+#[target_feature(enable = "neon")]
+pub fn vec4_scale(v: &Vec4<f32>, s: f32) -> Vec4<f32> {
+    store(vmulq_n_f32(load(v), s))
+}
@@ -0,0 +1,154 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::{float::Float, mat4::Mat4, vec4::Vec4};
+
+pub fn mat4_mul_vec4<T>(m: &Mat4<T>, Vec4 { x, y, z, w }: &Vec4<T>) -> Vec4<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    let [c0, c1, c2, c3] = m.columns();
+    let xy = vec4_add(&vec4_scale(&c0, *x), &vec4_scale(&c1, *y));
+    let zw = vec4_add(&vec4_scale(&c2, *z), &vec4_scale(&c3, *w));
+    vec4_add(&xy, &zw)
+}
+
+pub fn mat4_mul<T>(a: &Mat4<T>, b: &Mat4<T>) -> Mat4<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    let [c0, c1, c2, c3] = b.columns();
+    Mat4::new(
+        mat4_mul_vec4(a, &c0),
+        mat4_mul_vec4(a, &c1),
+        mat4_mul_vec4(a, &c2),
+        mat4_mul_vec4(a, &c3),
+    )
+}
+
+pub fn mat4_transpose<T: Copy>(m: &Mat4<T>) -> Mat4<T> {
+    let [c0, c1, c2, c3] = m.columns();
+    Mat4::new(
+        Vec4::new(c0.x, c1.x, c2.x, c3.x),
+        Vec4::new(c0.y, c1.y, c2.y, c3.y),
+        Vec4::new(c0.z, c1.z, c2.z, c3.z),
+        Vec4::new(c0.w, c1.w, c2.w, c3.w),
+    )
+}
+
+pub fn mat4_invert<T: Float>(mat: &Mat4<T>) -> Option<Mat4<T>> {
+    let mut inv = [T::ZERO; 16];
+    let m = mat.cols();
+
+    inv[0] = m[5] * m[10] * m[15] - m[5] * m[11] * m[14] - m[9] * m[6] * m[15]
+        + m[9] * m[7] * m[14]
+        + m[13] * m[6] * m[11]
+        - m[13] * m[7] * m[10];
+
+    inv[4] = -m[4] * m[10] * m[15] + m[4] * m[11] * m[14] + m[8] * m[6] * m[15]
+        - m[8] * m[7] * m[14]
+        - m[12] * m[6] * m[11]
+        + m[12] * m[7] * m[10];
+
+    inv[8] = m[4] * m[9] * m[15] - m[4] * m[11] * m[13] - m[8] * m[5] * m[15]
+        + m[8] * m[7] * m[13]
+        + m[12] * m[5] * m[11]
+        - m[12] * m[7] * m[9];
+
+    inv[12] = -m[4] * m[9] * m[14] + m[4] * m[10] * m[13] + m[8] * m[5] * m[14]
+        - m[8] * m[6] * m[13]
+        - m[12] * m[5] * m[10]
+        + m[12] * m[6] * m[9];
+
+    inv[1] = -m[1] * m[10] * m[15] + m[1] * m[11] * m[14] + m[9] * m[2] * m[15]
+        - m[9] * m[3] * m[14]
+        - m[13] * m[2] * m[11]
+        + m[13] * m[3] * m[10];
+
+    inv[5] = m[0] * m[10] * m[15] - m[0] * m[11] * m[14] - m[8] * m[2] * m[15]
+        + m[8] * m[3] * m[14]
+        + m[12] * m[2] * m[11]
+        - m[12] * m[3] * m[10];
+
+    inv[9] = -m[0] * m[9] * m[15] + m[0] * m[11] * m[13] + m[8] * m[1] * m[15]
+        - m[8] * m[3] * m[13]
+        - m[12] * m[1] * m[11]
+        + m[12] * m[3] * m[9];
+
+    inv[13] = m[0] * m[9] * m[14] - m[0] * m[10] * m[13] - m[8] * m[1] * m[14]
+        + m[8] * m[2] * m[13]
+        + m[12] * m[1] * m[10]
+        - m[12] * m[2] * m[9];
+
+    inv[2] = m[1] * m[6] * m[15] - m[1] * m[7] * m[14] - m[5] * m[2] * m[15]
+        + m[5] * m[3] * m[14]
+        + m[13] * m[2] * m[7]
+        - m[13] * m[3] * m[6];
+
+    inv[6] = -m[0] * m[6] * m[15] + m[0] * m[7] * m[14] + m[4] * m[2] * m[15]
+        - m[4] * m[3] * m[14]
+        - m[12] * m[2] * m[7]
+        + m[12] * m[3] * m[6];
+
+    inv[10] = m[0] * m[5] * m[15] - m[0] * m[7] * m[13] - m[4] * m[1] * m[15]
+        + m[4] * m[3] * m[13]
+        + m[12] * m[1] * m[7]
+        - m[12] * m[3] * m[5];
+
+    inv[14] = -m[0] * m[5] * m[14] + m[0] * m[6] * m[13] + m[4] * m[1] * m[14]
+        - m[4] * m[2] * m[13]
+        - m[12] * m[1] * m[6]
+        + m[12] * m[2] * m[5];
+
+    inv[3] = -m[1] * m[6] * m[11] + m[1] * m[7] * m[10] + m[5] * m[2] * m[11]
+        - m[5] * m[3] * m[10]
+        - m[9] * m[2] * m[7]
+        + m[9] * m[3] * m[6];
+
+    inv[7] = m[0] * m[6] * m[11] - m[0] * m[7] * m[10] - m[4] * m[2] * m[11]
+        + m[4] * m[3] * m[10]
+        + m[8] * m[2] * m[7]
+        - m[8] * m[3] * m[6];
+
+    inv[11] = -m[0] * m[5] * m[11] + m[0] * m[7] * m[9] + m[4] * m[1] * m[11]
+        - m[4] * m[3] * m[9]
+        - m[8] * m[1] * m[7]
+        + m[8] * m[3] * m[5];
+
+    inv[15] = m[0] * m[5] * m[10] - m[0] * m[6] * m[9] - m[4] * m[1] * m[10]
+        + m[4] * m[2] * m[9]
+        + m[8] * m[1] * m[6]
+        - m[8] * m[2] * m[5];
+
+    let mut det = m[0] * inv[0] + m[1] * inv[4] + m[2] * inv[8] + m[3] * inv[12];
+
+    if det == T::ZERO {
+        return None;
+    }
+
+    det = T::ONE / det;
+
+    Some(Mat4::from(inv.map(|x| x * det)))
+}
+
+pub fn vec4_dot<T>(a: &Vec4<T>, b: &Vec4<T>) -> T
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w
+}
+
+pub fn vec4_add<T: Copy + Add<Output = T>>(a: &Vec4<T>, b: &Vec4<T>) -> Vec4<T> {
+    Vec4::new(a.x + b.x, a.y + b.y, a.z + b.z, a.w + b.w)
+}
+
+pub fn vec4_sub<T: Copy + Sub<Output = T>>(a: &Vec4<T>, b: &Vec4<T>) -> Vec4<T> {
+    Vec4::new(a.x - b.x, a.y - b.y, a.z - b.z, a.w - b.w)
+}
+
+pub fn vec4_mul<T: Copy + Mul<Output = T>>(a: &Vec4<T>, b: &Vec4<T>) -> Vec4<T> {
+    Vec4::new(a.x * b.x, a.y * b.y, a.z * b.z, a.w * b.w)
+}
+
+pub fn vec4_scale<T: Copy + Mul<Output = T>>(v: &Vec4<T>, s: T) -> Vec4<T> {
+    Vec4::new(v.x * s, v.y * s, v.z * s, v.w * s)
+}
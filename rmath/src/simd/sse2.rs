@@ -0,0 +1,199 @@
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::{mat4::Mat4, vec4::Vec4};
+
+const fn mask(x: i32, y: i32, z: i32, w: i32) -> i32 {
+    x | (y << 2) | (z << 4) | (w << 6)
+}
+
+macro_rules! shuffle {
+    ($a:expr, $b:expr, $x:literal, $y:literal, $z:literal, $w:literal) => {
+        _mm_shuffle_ps::<{ mask($x, $y, $z, $w) }>($a, $b)
+    };
+}
+
+macro_rules! swizzle {
+    ($v:expr, $x:literal, $y:literal, $z:literal, $w:literal) => {
+        shuffle!($v, $v, $x, $y, $z, $w)
+    };
+}
+
+#[inline]
+#[target_feature(enable = "sse2")]
+fn load(v: &Vec4<f32>) -> __m128 {
+    // SAFETY: `data` is four contiguous f32, unaligned loads are allowed.
+    unsafe { _mm_loadu_ps(v.data().as_ptr()) }
+}
+
+#[inline]
+#[target_feature(enable = "sse2")]
+fn store(v: __m128) -> Vec4<f32> {
+    let mut out = [0.0; 4];
+    // SAFETY: `out` has room for four f32, unaligned stores are allowed.
+    unsafe { _mm_storeu_ps(out.as_mut_ptr(), v) };
+    Vec4::from(out)
+}
+
+#[inline]
+#[target_feature(enable = "sse2")]
+fn load_mat4(m: &Mat4<f32>) -> [__m128; 4] {
+    m.columns().map(|c| load(&c))
+}
+
+#[inline]
+#[target_feature(enable = "sse2")]
+fn store_mat4([c0, c1, c2, c3]: [__m128; 4]) -> Mat4<f32> {
+    Mat4::new(store(c0), store(c1), store(c2), store(c3))
+}
+
+#[inline]
+#[target_feature(enable = "sse2")]
+fn linear_combine([c0, c1, c2, c3]: &[__m128; 4], v: __m128) -> __m128 {
+    let x = _mm_mul_ps(*c0, swizzle!(v, 0, 0, 0, 0));
+    let y = _mm_mul_ps(*c1, swizzle!(v, 1, 1, 1, 1));
+    let z = _mm_mul_ps(*c2, swizzle!(v, 2, 2, 2, 2));
+    let w = _mm_mul_ps(*c3, swizzle!(v, 3, 3, 3, 3));
+    _mm_add_ps(_mm_add_ps(x, y), _mm_add_ps(z, w))
+}
+
+/// Sum of all lanes, broadcast to every lane.
+#[inline]
+#[target_feature(enable = "sse2")]
+fn horizontal_sum(v: __m128) -> __m128 {
+    let v = _mm_add_ps(v, swizzle!(v, 1, 0, 3, 2));
+    _mm_add_ps(v, swizzle!(v, 2, 3, 0, 1))
+}
+
+#[target_feature(enable = "sse2")]
+pub fn mat4_mul_vec4(m: &Mat4<f32>, v: &Vec4<f32>) -> Vec4<f32> {
+    store(linear_combine(&load_mat4(m), load(v)))
+}
+
+#[target_feature(enable = "sse2")]
+pub fn mat4_mul(a: &Mat4<f32>, b: &Mat4<f32>) -> Mat4<f32> {
+    let a = load_mat4(a);
+    store_mat4(load_mat4(b).map(|c| linear_combine(&a, c)))
+}
+
+#[target_feature(enable = "sse2")]
+pub fn mat4_transpose(m: &Mat4<f32>) -> Mat4<f32> {
+    let [c0, c1, c2, c3] = load_mat4(m);
+    let t0 = _mm_unpacklo_ps(c0, c1);
+    let t1 = _mm_unpacklo_ps(c2, c3);
+    let t2 = _mm_unpackhi_ps(c0, c1);
+    let t3 = _mm_unpackhi_ps(c2, c3);
+    store_mat4([
+        _mm_movelh_ps(t0, t1),
+        _mm_movehl_ps(t1, t0),
+        _mm_movelh_ps(t2, t3),
+        _mm_movehl_ps(t3, t2),
+    ])
+}
+
+/// 2x2 matrix product `a * b`, each packed as `(m00, m01, m10, m11)`.
+#[inline]
+#[target_feature(enable = "sse2")]
+fn mat2_mul(a: __m128, b: __m128) -> __m128 {
+    _mm_add_ps(
+        _mm_mul_ps(a, swizzle!(b, 0, 3, 0, 3)),
+        _mm_mul_ps(swizzle!(a, 1, 0, 3, 2), swizzle!(b, 2, 1, 2, 1)),
+    )
+}
+
+/// `adj(a) * b`
+#[inline]
+#[target_feature(enable = "sse2")]
+fn mat2_adj_mul(a: __m128, b: __m128) -> __m128 {
+    _mm_sub_ps(
+        _mm_mul_ps(swizzle!(a, 3, 3, 0, 0), b),
+        _mm_mul_ps(swizzle!(a, 1, 1, 2, 2), swizzle!(b, 2, 3, 0, 1)),
+    )
+}
+
+/// `a * adj(b)`
+#[inline]
+#[target_feature(enable = "sse2")]
+fn mat2_mul_adj(a: __m128, b: __m128) -> __m128 {
+    _mm_sub_ps(
+        _mm_mul_ps(a, swizzle!(b, 3, 0, 3, 0)),
+        _mm_mul_ps(swizzle!(a, 1, 0, 3, 2), swizzle!(b, 2, 1, 2, 1)),
+    )
+}
+
+/// Block-wise inverse over 2x2 sub-matrices. Works on the transposed
+/// layout as well, since `inverse(transpose(m)) == transpose(inverse(m))`.
+#[target_feature(enable = "sse2")]
+pub fn mat4_invert(m: &Mat4<f32>) -> Option<Mat4<f32>> {
+    let [c0, c1, c2, c3] = load_mat4(m);
+
+    let a = _mm_movelh_ps(c0, c1);
+    let b = _mm_movehl_ps(c1, c0);
+    let c = _mm_movelh_ps(c2, c3);
+    let d = _mm_movehl_ps(c3, c2);
+
+    let det_sub = _mm_sub_ps(
+        _mm_mul_ps(shuffle!(c0, c2, 0, 2, 0, 2), shuffle!(c1, c3, 1, 3, 1, 3)),
+        _mm_mul_ps(shuffle!(c0, c2, 1, 3, 1, 3), shuffle!(c1, c3, 0, 2, 0, 2)),
+    );
+    let det_a = swizzle!(det_sub, 0, 0, 0, 0);
+    let det_b = swizzle!(det_sub, 1, 1, 1, 1);
+    let det_c = swizzle!(det_sub, 2, 2, 2, 2);
+    let det_d = swizzle!(det_sub, 3, 3, 3, 3);
+
+    let d_c = mat2_adj_mul(d, c);
+    let a_b = mat2_adj_mul(a, b);
+    let x = _mm_sub_ps(_mm_mul_ps(det_d, a), mat2_mul(b, d_c));
+    let w = _mm_sub_ps(_mm_mul_ps(det_a, d), mat2_mul(c, a_b));
+    let y = _mm_sub_ps(_mm_mul_ps(det_b, c), mat2_mul_adj(d, a_b));
+    let z = _mm_sub_ps(_mm_mul_ps(det_c, b), mat2_mul_adj(a, d_c));
+
+    let trace = horizontal_sum(_mm_mul_ps(a_b, swizzle!(d_c, 0, 2, 1, 3)));
+    let det = _mm_sub_ps(
+        _mm_add_ps(_mm_mul_ps(det_a, det_d), _mm_mul_ps(det_b, det_c)),
+        trace,
+    );
+    if _mm_cvtss_f32(det) == 0.0 {
+        return None;
+    }
+
+    let r_det = _mm_div_ps(_mm_setr_ps(1.0, -1.0, -1.0, 1.0), det);
+    let x = _mm_mul_ps(x, r_det);
+    let y = _mm_mul_ps(y, r_det);
+    let z = _mm_mul_ps(z, r_det);
+    let w = _mm_mul_ps(w, r_det);
+
+    Some(store_mat4([
+        shuffle!(x, y, 3, 1, 3, 1),
+        shuffle!(x, y, 2, 0, 2, 0),
+        shuffle!(z, w, 3, 1, 3, 1),
+        shuffle!(z, w, 2, 0, 2, 0),
+    ]))
+}
+
+#[target_feature(enable = "sse2")]
+pub fn vec4_dot(a: &Vec4<f32>, b: &Vec4<f32>) -> f32 {
+    _mm_cvtss_f32(horizontal_sum(_mm_mul_ps(load(a), load(b))))
+}
+
+#[target_feature(enable = "sse2")]
+pub fn vec4_add(a: &Vec4<f32>, b: &Vec4<f32>) -> Vec4<f32> {
+    store(_mm_add_ps(load(a), load(b)))
+}
+
+#[target_feature(enable = "sse2")]
+pub fn vec4_sub(a: &Vec4<f32>, b: &Vec4<f32>) -> Vec4<f32> {
+    store(_mm_sub_ps(load(a), load(b)))
+}
+
+#[target_feature(enable = "sse2")]
+pub fn vec4_mul(a: &Vec4<f32>, b: &Vec4<f32>) -> Vec4<f32> {
+    store(_mm_mul_ps(load(a), load(b)))
+}
+
+#[target_feature(enable = "sse2")]
+pub fn vec4_scale(v: &Vec4<f32>, s: f32) -> Vec4<f32> {
+    store(_mm_mul_ps(load(v), _mm_set1_ps(s)))
+}
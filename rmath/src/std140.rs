@@ -0,0 +1,64 @@
+//! std140 layout of the `f32` vectors and matrices, so they can be used as
+//! members of uniform blocks declared with `gl_sys::std140!`.
+//!
+//! Matrices are stored as columns, each padded to a `vec4`.
+
+use gl_sys::ubo::Std140;
+
+use crate::{mat3::Mat3, mat4::Mat4, vec2::Vec2, vec3::Vec3, vec4::Vec4};
+
+macro_rules! impl_std140 {
+    ($($ty:ident => $components:ident, align $align:expr, size $size:expr, column $rows:expr, stride $stride:expr);*) => {$(
+        impl Std140 for $ty<f32> {
+            const ALIGN: usize = $align;
+            const SIZE: usize = $size;
+
+            fn write_std140(&self, out: &mut [u8]) {
+                let floats = self.$components();
+                for (column, out) in floats.chunks_exact($rows).zip(out.chunks_mut($stride)) {
+                    for (f, out) in column.iter().zip(out.chunks_exact_mut(4)) {
+                        out.copy_from_slice(&f.to_ne_bytes());
+                    }
+                }
+            }
+        }
+    )*};
+}
+
+impl_std140!(
+    Vec2 => data, align 8, size 8, column 2, stride 8;
+    Vec3 => data, align 16, size 12, column 3, stride 12;
+    Vec4 => data, align 16, size 16, column 4, stride 16;
+    Mat3 => cols, align 16, size 48, column 3, stride 16;
+    Mat4 => cols, align 16, size 64, column 4, stride 16
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn floats<T: Std140>(value: &T) -> Vec<f32> {
+        let mut out = vec![0; T::SIZE];
+        value.write_std140(&mut out);
+        out.chunks_exact(4)
+            .map(|b| f32::from_ne_bytes(b.try_into().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn test_std140_vectors() {
+        assert_eq!(floats(&Vec3::new(1.0, 2.0, 3.0)), [1.0, 2.0, 3.0]);
+        assert_eq!(floats(&Vec4::new(1.0, 2.0, 3.0, 4.0)), [1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_std140_mat3_padded() {
+        let m = Mat3::<f32>::identity();
+        assert_eq!(
+            floats(&m),
+            [1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0]
+        );
+        let m = Mat4::<f32>::translation(&Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(floats(&m), m.cols());
+    }
+}
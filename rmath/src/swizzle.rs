@@ -0,0 +1,372 @@
+//! GLSL style swizzles: every two, three and four component combination
+//! (repeats included) of a vector's components, once with `xyzw` and once
+//! with `rgba` names, e.g. `v.xz()`, `v.zyx()` or `color.bgra()`.
+
+use crate::{vec2::Vec2, vec3::Vec3, vec4::Vec4};
+
+macro_rules! swizzle {
+    ($vec:ident => $out:ident { $($name:ident => $($c:ident)+),+ $(,)? }) => {
+        impl<T: Copy> $vec<T> {
+            $(
+                #[inline]
+                pub const fn $name(&self) -> $out<T> {
+                    $out::new($(swizzle!(@field self $c)),+)
+                }
+            )+
+        }
+    };
+
+    (@field $self:ident x) => { $self.x };
+    (@field $self:ident y) => { $self.y };
+    (@field $self:ident z) => { $self.z };
+    (@field $self:ident w) => { $self.w };
+    (@field $self:ident r) => { $self.x };
+    (@field $self:ident g) => { $self.y };
+    (@field $self:ident b) => { $self.z };
+    (@field $self:ident a) => { $self.w };
+}
+
+// `rgb` on Vec3 and `rgba` on Vec4 are left out, they would clash with the
+// constructors of the same name.
+swizzle!(Vec2 => Vec2 {
+    xx => x x, xy => x y,
+    yx => y x, yy => y y,
+    rr => r r, rg => r g,
+    gr => g r, gg => g g,
+});
+
+swizzle!(Vec2 => Vec3 {
+    xxx => x x x, xxy => x x y,
+    xyx => x y x, xyy => x y y,
+    yxx => y x x, yxy => y x y,
+    yyx => y y x, yyy => y y y,
+    rrr => r r r, rrg => r r g,
+    rgr => r g r, rgg => r g g,
+    grr => g r r, grg => g r g,
+    ggr => g g r, ggg => g g g,
+});
+
+swizzle!(Vec2 => Vec4 {
+    xxxx => x x x x, xxxy => x x x y,
+    xxyx => x x y x, xxyy => x x y y,
+    xyxx => x y x x, xyxy => x y x y,
+    xyyx => x y y x, xyyy => x y y y,
+    yxxx => y x x x, yxxy => y x x y,
+    yxyx => y x y x, yxyy => y x y y,
+    yyxx => y y x x, yyxy => y y x y,
+    yyyx => y y y x, yyyy => y y y y,
+    rrrr => r r r r, rrrg => r r r g,
+    rrgr => r r g r, rrgg => r r g g,
+    rgrr => r g r r, rgrg => r g r g,
+    rggr => r g g r, rggg => r g g g,
+    grrr => g r r r, grrg => g r r g,
+    grgr => g r g r, grgg => g r g g,
+    ggrr => g g r r, ggrg => g g r g,
+    gggr => g g g r, gggg => g g g g,
+});
+
+swizzle!(Vec3 => Vec2 {
+    xx => x x, xy => x y, xz => x z,
+    yx => y x, yy => y y, yz => y z,
+    zx => z x, zy => z y, zz => z z,
+    rr => r r, rg => r g, rb => r b,
+    gr => g r, gg => g g, gb => g b,
+    br => b r, bg => b g, bb => b b,
+});
+
+swizzle!(Vec3 => Vec3 {
+    xxx => x x x, xxy => x x y, xxz => x x z,
+    xyx => x y x, xyy => x y y, xyz => x y z,
+    xzx => x z x, xzy => x z y, xzz => x z z,
+    yxx => y x x, yxy => y x y, yxz => y x z,
+    yyx => y y x, yyy => y y y, yyz => y y z,
+    yzx => y z x, yzy => y z y, yzz => y z z,
+    zxx => z x x, zxy => z x y, zxz => z x z,
+    zyx => z y x, zyy => z y y, zyz => z y z,
+    zzx => z z x, zzy => z z y, zzz => z z z,
+    rrr => r r r, rrg => r r g, rrb => r r b,
+    rgr => r g r, rgg => r g g,
+    rbr => r b r, rbg => r b g, rbb => r b b,
+    grr => g r r, grg => g r g, grb => g r b,
+    ggr => g g r, ggg => g g g, ggb => g g b,
+    gbr => g b r, gbg => g b g, gbb => g b b,
+    brr => b r r, brg => b r g, brb => b r b,
+    bgr => b g r, bgg => b g g, bgb => b g b,
+    bbr => b b r, bbg => b b g, bbb => b b b,
+});
+
+swizzle!(Vec3 => Vec4 {
+    xxxx => x x x x, xxxy => x x x y, xxxz => x x x z,
+    xxyx => x x y x, xxyy => x x y y, xxyz => x x y z,
+    xxzx => x x z x, xxzy => x x z y, xxzz => x x z z,
+    xyxx => x y x x, xyxy => x y x y, xyxz => x y x z,
+    xyyx => x y y x, xyyy => x y y y, xyyz => x y y z,
+    xyzx => x y z x, xyzy => x y z y, xyzz => x y z z,
+    xzxx => x z x x, xzxy => x z x y, xzxz => x z x z,
+    xzyx => x z y x, xzyy => x z y y, xzyz => x z y z,
+    xzzx => x z z x, xzzy => x z z y, xzzz => x z z z,
+    yxxx => y x x x, yxxy => y x x y, yxxz => y x x z,
+    yxyx => y x y x, yxyy => y x y y, yxyz => y x y z,
+    yxzx => y x z x, yxzy => y x z y, yxzz => y x z z,
+    yyxx => y y x x, yyxy => y y x y, yyxz => y y x z,
+    yyyx => y y y x, yyyy => y y y y, yyyz => y y y z,
+    yyzx => y y z x, yyzy => y y z y, yyzz => y y z z,
+    yzxx => y z x x, yzxy => y z x y, yzxz => y z x z,
+    yzyx => y z y x, yzyy => y z y y, yzyz => y z y z,
+    yzzx => y z z x, yzzy => y z z y, yzzz => y z z z,
+    zxxx => z x x x, zxxy => z x x y, zxxz => z x x z,
+    zxyx => z x y x, zxyy => z x y y, zxyz => z x y z,
+    zxzx => z x z x, zxzy => z x z y, zxzz => z x z z,
+    zyxx => z y x x, zyxy => z y x y, zyxz => z y x z,
+    zyyx => z y y x, zyyy => z y y y, zyyz => z y y z,
+    zyzx => z y z x, zyzy => z y z y, zyzz => z y z z,
+    zzxx => z z x x, zzxy => z z x y, zzxz => z z x z,
+    zzyx => z z y x, zzyy => z z y y, zzyz => z z y z,
+    zzzx => z z z x, zzzy => z z z y, zzzz => z z z z,
+    rrrr => r r r r, rrrg => r r r g, rrrb => r r r b,
+    rrgr => r r g r, rrgg => r r g g, rrgb => r r g b,
+    rrbr => r r b r, rrbg => r r b g, rrbb => r r b b,
+    rgrr => r g r r, rgrg => r g r g, rgrb => r g r b,
+    rggr => r g g r, rggg => r g g g, rggb => r g g b,
+    rgbr => r g b r, rgbg => r g b g, rgbb => r g b b,
+    rbrr => r b r r, rbrg => r b r g, rbrb => r b r b,
+    rbgr => r b g r, rbgg => r b g g, rbgb => r b g b,
+    rbbr => r b b r, rbbg => r b b g, rbbb => r b b b,
+    grrr => g r r r, grrg => g r r g, grrb => g r r b,
+    grgr => g r g r, grgg => g r g g, grgb => g r g b,
+    grbr => g r b r, grbg => g r b g, grbb => g r b b,
+    ggrr => g g r r, ggrg => g g r g, ggrb => g g r b,
+    gggr => g g g r, gggg => g g g g, gggb => g g g b,
+    ggbr => g g b r, ggbg => g g b g, ggbb => g g b b,
+    gbrr => g b r r, gbrg => g b r g, gbrb => g b r b,
+    gbgr => g b g r, gbgg => g b g g, gbgb => g b g b,
+    gbbr => g b b r, gbbg => g b b g, gbbb => g b b b,
+    brrr => b r r r, brrg => b r r g, brrb => b r r b,
+    brgr => b r g r, brgg => b r g g, brgb => b r g b,
+    brbr => b r b r, brbg => b r b g, brbb => b r b b,
+    bgrr => b g r r, bgrg => b g r g, bgrb => b g r b,
+    bggr => b g g r, bggg => b g g g, bggb => b g g b,
+    bgbr => b g b r, bgbg => b g b g, bgbb => b g b b,
+    bbrr => b b r r, bbrg => b b r g, bbrb => b b r b,
+    bbgr => b b g r, bbgg => b b g g, bbgb => b b g b,
+    bbbr => b b b r, bbbg => b b b g, bbbb => b b b b,
+});
+
+swizzle!(Vec4 => Vec2 {
+    xx => x x, xy => x y, xz => x z, xw => x w,
+    yx => y x, yy => y y, yz => y z, yw => y w,
+    zx => z x, zy => z y, zz => z z, zw => z w,
+    wx => w x, wy => w y, wz => w z, ww => w w,
+    rr => r r, rg => r g, rb => r b, ra => r a,
+    gr => g r, gg => g g, gb => g b, ga => g a,
+    br => b r, bg => b g, bb => b b, ba => b a,
+    ar => a r, ag => a g, ab => a b, aa => a a,
+});
+
+swizzle!(Vec4 => Vec3 {
+    xxx => x x x, xxy => x x y, xxz => x x z, xxw => x x w,
+    xyx => x y x, xyy => x y y, xyz => x y z, xyw => x y w,
+    xzx => x z x, xzy => x z y, xzz => x z z, xzw => x z w,
+    xwx => x w x, xwy => x w y, xwz => x w z, xww => x w w,
+    yxx => y x x, yxy => y x y, yxz => y x z, yxw => y x w,
+    yyx => y y x, yyy => y y y, yyz => y y z, yyw => y y w,
+    yzx => y z x, yzy => y z y, yzz => y z z, yzw => y z w,
+    ywx => y w x, ywy => y w y, ywz => y w z, yww => y w w,
+    zxx => z x x, zxy => z x y, zxz => z x z, zxw => z x w,
+    zyx => z y x, zyy => z y y, zyz => z y z, zyw => z y w,
+    zzx => z z x, zzy => z z y, zzz => z z z, zzw => z z w,
+    zwx => z w x, zwy => z w y, zwz => z w z, zww => z w w,
+    wxx => w x x, wxy => w x y, wxz => w x z, wxw => w x w,
+    wyx => w y x, wyy => w y y, wyz => w y z, wyw => w y w,
+    wzx => w z x, wzy => w z y, wzz => w z z, wzw => w z w,
+    wwx => w w x, wwy => w w y, wwz => w w z, www => w w w,
+    rrr => r r r, rrg => r r g, rrb => r r b, rra => r r a,
+    rgr => r g r, rgg => r g g, rgb => r g b, rga => r g a,
+    rbr => r b r, rbg => r b g, rbb => r b b, rba => r b a,
+    rar => r a r, rag => r a g, rab => r a b, raa => r a a,
+    grr => g r r, grg => g r g, grb => g r b, gra => g r a,
+    ggr => g g r, ggg => g g g, ggb => g g b, gga => g g a,
+    gbr => g b r, gbg => g b g, gbb => g b b, gba => g b a,
+    gar => g a r, gag => g a g, gab => g a b, gaa => g a a,
+    brr => b r r, brg => b r g, brb => b r b, bra => b r a,
+    bgr => b g r, bgg => b g g, bgb => b g b, bga => b g a,
+    bbr => b b r, bbg => b b g, bbb => b b b, bba => b b a,
+    bar => b a r, bag => b a g, bab => b a b, baa => b a a,
+    arr => a r r, arg => a r g, arb => a r b, ara => a r a,
+    agr => a g r, agg => a g g, agb => a g b, aga => a g a,
+    abr => a b r, abg => a b g, abb => a b b, aba => a b a,
+    aar => a a r, aag => a a g, aab => a a b, aaa => a a a,
+});
+
+swizzle!(Vec4 => Vec4 {
+    xxxx => x x x x, xxxy => x x x y, xxxz => x x x z, xxxw => x x x w,
+    xxyx => x x y x, xxyy => x x y y, xxyz => x x y z, xxyw => x x y w,
+    xxzx => x x z x, xxzy => x x z y, xxzz => x x z z, xxzw => x x z w,
+    xxwx => x x w x, xxwy => x x w y, xxwz => x x w z, xxww => x x w w,
+    xyxx => x y x x, xyxy => x y x y, xyxz => x y x z, xyxw => x y x w,
+    xyyx => x y y x, xyyy => x y y y, xyyz => x y y z, xyyw => x y y w,
+    xyzx => x y z x, xyzy => x y z y, xyzz => x y z z, xyzw => x y z w,
+    xywx => x y w x, xywy => x y w y, xywz => x y w z, xyww => x y w w,
+    xzxx => x z x x, xzxy => x z x y, xzxz => x z x z, xzxw => x z x w,
+    xzyx => x z y x, xzyy => x z y y, xzyz => x z y z, xzyw => x z y w,
+    xzzx => x z z x, xzzy => x z z y, xzzz => x z z z, xzzw => x z z w,
+    xzwx => x z w x, xzwy => x z w y, xzwz => x z w z, xzww => x z w w,
+    xwxx => x w x x, xwxy => x w x y, xwxz => x w x z, xwxw => x w x w,
+    xwyx => x w y x, xwyy => x w y y, xwyz => x w y z, xwyw => x w y w,
+    xwzx => x w z x, xwzy => x w z y, xwzz => x w z z, xwzw => x w z w,
+    xwwx => x w w x, xwwy => x w w y, xwwz => x w w z, xwww => x w w w,
+    yxxx => y x x x, yxxy => y x x y, yxxz => y x x z, yxxw => y x x w,
+    yxyx => y x y x, yxyy => y x y y, yxyz => y x y z, yxyw => y x y w,
+    yxzx => y x z x, yxzy => y x z y, yxzz => y x z z, yxzw => y x z w,
+    yxwx => y x w x, yxwy => y x w y, yxwz => y x w z, yxww => y x w w,
+    yyxx => y y x x, yyxy => y y x y, yyxz => y y x z, yyxw => y y x w,
+    yyyx => y y y x, yyyy => y y y y, yyyz => y y y z, yyyw => y y y w,
+    yyzx => y y z x, yyzy => y y z y, yyzz => y y z z, yyzw => y y z w,
+    yywx => y y w x, yywy => y y w y, yywz => y y w z, yyww => y y w w,
+    yzxx => y z x x, yzxy => y z x y, yzxz => y z x z, yzxw => y z x w,
+    yzyx => y z y x, yzyy => y z y y, yzyz => y z y z, yzyw => y z y w,
+    yzzx => y z z x, yzzy => y z z y, yzzz => y z z z, yzzw => y z z w,
+    yzwx => y z w x, yzwy => y z w y, yzwz => y z w z, yzww => y z w w,
+    ywxx => y w x x, ywxy => y w x y, ywxz => y w x z, ywxw => y w x w,
+    ywyx => y w y x, ywyy => y w y y, ywyz => y w y z, ywyw => y w y w,
+    ywzx => y w z x, ywzy => y w z y, ywzz => y w z z, ywzw => y w z w,
+    ywwx => y w w x, ywwy => y w w y, ywwz => y w w z, ywww => y w w w,
+    zxxx => z x x x, zxxy => z x x y, zxxz => z x x z, zxxw => z x x w,
+    zxyx => z x y x, zxyy => z x y y, zxyz => z x y z, zxyw => z x y w,
+    zxzx => z x z x, zxzy => z x z y, zxzz => z x z z, zxzw => z x z w,
+    zxwx => z x w x, zxwy => z x w y, zxwz => z x w z, zxww => z x w w,
+    zyxx => z y x x, zyxy => z y x y, zyxz => z y x z, zyxw => z y x w,
+    zyyx => z y y x, zyyy => z y y y, zyyz => z y y z, zyyw => z y y w,
+    zyzx => z y z x, zyzy => z y z y, zyzz => z y z z, zyzw => z y z w,
+    zywx => z y w x, zywy => z y w y, zywz => z y w z, zyww => z y w w,
+    zzxx => z z x x, zzxy => z z x y, zzxz => z z x z, zzxw => z z x w,
+    zzyx => z z y x, zzyy => z z y y, zzyz => z z y z, zzyw => z z y w,
+    zzzx => z z z x, zzzy => z z z y, zzzz => z z z z, zzzw => z z z w,
+    zzwx => z z w x, zzwy => z z w y, zzwz => z z w z, zzww => z z w w,
+    zwxx => z w x x, zwxy => z w x y, zwxz => z w x z, zwxw => z w x w,
+    zwyx => z w y x, zwyy => z w y y, zwyz => z w y z, zwyw => z w y w,
+    zwzx => z w z x, zwzy => z w z y, zwzz => z w z z, zwzw => z w z w,
+    zwwx => z w w x, zwwy => z w w y, zwwz => z w w z, zwww => z w w w,
+    wxxx => w x x x, wxxy => w x x y, wxxz => w x x z, wxxw => w x x w,
+    wxyx => w x y x, wxyy => w x y y, wxyz => w x y z, wxyw => w x y w,
+    wxzx => w x z x, wxzy => w x z y, wxzz => w x z z, wxzw => w x z w,
+    wxwx => w x w x, wxwy => w x w y, wxwz => w x w z, wxww => w x w w,
+    wyxx => w y x x, wyxy => w y x y, wyxz => w y x z, wyxw => w y x w,
+    wyyx => w y y x, wyyy => w y y y, wyyz => w y y z, wyyw => w y y w,
+    wyzx => w y z x, wyzy => w y z y, wyzz => w y z z, wyzw => w y z w,
+    wywx => w y w x, wywy => w y w y, wywz => w y w z, wyww => w y w w,
+    wzxx => w z x x, wzxy => w z x y, wzxz => w z x z, wzxw => w z x w,
+    wzyx => w z y x, wzyy => w z y y, wzyz => w z y z, wzyw => w z y w,
+    wzzx => w z z x, wzzy => w z z y, wzzz => w z z z, wzzw => w z z w,
+    wzwx => w z w x, wzwy => w z w y, wzwz => w z w z, wzww => w z w w,
+    wwxx => w w x x, wwxy => w w x y, wwxz => w w x z, wwxw => w w x w,
+    wwyx => w w y x, wwyy => w w y y, wwyz => w w y z, wwyw => w w y w,
+    wwzx => w w z x, wwzy => w w z y, wwzz => w w z z, wwzw => w w z w,
+    wwwx => w w w x, wwwy => w w w y, wwwz => w w w z, wwww => w w w w,
+    rrrr => r r r r, rrrg => r r r g, rrrb => r r r b, rrra => r r r a,
+    rrgr => r r g r, rrgg => r r g g, rrgb => r r g b, rrga => r r g a,
+    rrbr => r r b r, rrbg => r r b g, rrbb => r r b b, rrba => r r b a,
+    rrar => r r a r, rrag => r r a g, rrab => r r a b, rraa => r r a a,
+    rgrr => r g r r, rgrg => r g r g, rgrb => r g r b, rgra => r g r a,
+    rggr => r g g r, rggg => r g g g, rggb => r g g b, rgga => r g g a,
+    rgbr => r g b r, rgbg => r g b g, rgbb => r g b b,
+    rgar => r g a r, rgag => r g a g, rgab => r g a b, rgaa => r g a a,
+    rbrr => r b r r, rbrg => r b r g, rbrb => r b r b, rbra => r b r a,
+    rbgr => r b g r, rbgg => r b g g, rbgb => r b g b, rbga => r b g a,
+    rbbr => r b b r, rbbg => r b b g, rbbb => r b b b, rbba => r b b a,
+    rbar => r b a r, rbag => r b a g, rbab => r b a b, rbaa => r b a a,
+    rarr => r a r r, rarg => r a r g, rarb => r a r b, rara => r a r a,
+    ragr => r a g r, ragg => r a g g, ragb => r a g b, raga => r a g a,
+    rabr => r a b r, rabg => r a b g, rabb => r a b b, raba => r a b a,
+    raar => r a a r, raag => r a a g, raab => r a a b, raaa => r a a a,
+    grrr => g r r r, grrg => g r r g, grrb => g r r b, grra => g r r a,
+    grgr => g r g r, grgg => g r g g, grgb => g r g b, grga => g r g a,
+    grbr => g r b r, grbg => g r b g, grbb => g r b b, grba => g r b a,
+    grar => g r a r, grag => g r a g, grab => g r a b, graa => g r a a,
+    ggrr => g g r r, ggrg => g g r g, ggrb => g g r b, ggra => g g r a,
+    gggr => g g g r, gggg => g g g g, gggb => g g g b, ggga => g g g a,
+    ggbr => g g b r, ggbg => g g b g, ggbb => g g b b, ggba => g g b a,
+    ggar => g g a r, ggag => g g a g, ggab => g g a b, ggaa => g g a a,
+    gbrr => g b r r, gbrg => g b r g, gbrb => g b r b, gbra => g b r a,
+    gbgr => g b g r, gbgg => g b g g, gbgb => g b g b, gbga => g b g a,
+    gbbr => g b b r, gbbg => g b b g, gbbb => g b b b, gbba => g b b a,
+    gbar => g b a r, gbag => g b a g, gbab => g b a b, gbaa => g b a a,
+    garr => g a r r, garg => g a r g, garb => g a r b, gara => g a r a,
+    gagr => g a g r, gagg => g a g g, gagb => g a g b, gaga => g a g a,
+    gabr => g a b r, gabg => g a b g, gabb => g a b b, gaba => g a b a,
+    gaar => g a a r, gaag => g a a g, gaab => g a a b, gaaa => g a a a,
+    brrr => b r r r, brrg => b r r g, brrb => b r r b, brra => b r r a,
+    brgr => b r g r, brgg => b r g g, brgb => b r g b, brga => b r g a,
+    brbr => b r b r, brbg => b r b g, brbb => b r b b, brba => b r b a,
+    brar => b r a r, brag => b r a g, brab => b r a b, braa => b r a a,
+    bgrr => b g r r, bgrg => b g r g, bgrb => b g r b, bgra => b g r a,
+    bggr => b g g r, bggg => b g g g, bggb => b g g b, bgga => b g g a,
+    bgbr => b g b r, bgbg => b g b g, bgbb => b g b b, bgba => b g b a,
+    bgar => b g a r, bgag => b g a g, bgab => b g a b, bgaa => b g a a,
+    bbrr => b b r r, bbrg => b b r g, bbrb => b b r b, bbra => b b r a,
+    bbgr => b b g r, bbgg => b b g g, bbgb => b b g b, bbga => b b g a,
+    bbbr => b b b r, bbbg => b b b g, bbbb => b b b b, bbba => b b b a,
+    bbar => b b a r, bbag => b b a g, bbab => b b a b, bbaa => b b a a,
+    barr => b a r r, barg => b a r g, barb => b a r b, bara => b a r a,
+    bagr => b a g r, bagg => b a g g, bagb => b a g b, baga => b a g a,
+    babr => b a b r, babg => b a b g, babb => b a b b, baba => b a b a,
+    baar => b a a r, baag => b a a g, baab => b a a b, baaa => b a a a,
+    arrr => a r r r, arrg => a r r g, arrb => a r r b, arra => a r r a,
+    argr => a r g r, argg => a r g g, argb => a r g b, arga => a r g a,
+    arbr => a r b r, arbg => a r b g, arbb => a r b b, arba => a r b a,
+    arar => a r a r, arag => a r a g, arab => a r a b, araa => a r a a,
+    agrr => a g r r, agrg => a g r g, agrb => a g r b, agra => a g r a,
+    aggr => a g g r, aggg => a g g g, aggb => a g g b, agga => a g g a,
+    agbr => a g b r, agbg => a g b g, agbb => a g b b, agba => a g b a,
+    agar => a g a r, agag => a g a g, agab => a g a b, agaa => a g a a,
+    abrr => a b r r, abrg => a b r g, abrb => a b r b, abra => a b r a,
+    abgr => a b g r, abgg => a b g g, abgb => a b g b, abga => a b g a,
+    abbr => a b b r, abbg => a b b g, abbb => a b b b, abba => a b b a,
+    abar => a b a r, abag => a b a g, abab => a b a b, abaa => a b a a,
+    aarr => a a r r, aarg => a a r g, aarb => a a r b, aara => a a r a,
+    aagr => a a g r, aagg => a a g g, aagb => a a g b, aaga => a a g a,
+    aabr => a a b r, aabg => a a b g, aabb => a a b b, aaba => a a b a,
+    aaar => a a a r, aaag => a a a g, aaab => a a a b, aaaa => a a a a,
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swizzle_vec2() {
+        let v = Vec2::new(1, 2);
+        assert_eq!(v.yx(), Vec2::new(2, 1));
+        assert_eq!(v.xxy(), Vec3::new(1, 1, 2));
+        assert_eq!(v.gggr(), Vec4::new(2, 2, 2, 1));
+        assert_eq!(v.rg(), v);
+    }
+
+    #[test]
+    fn test_swizzle_vec3() {
+        let v = Vec3::new(1, 2, 3);
+        assert_eq!(v.xz(), Vec2::new(1, 3));
+        assert_eq!(v.zyx(), Vec3::new(3, 2, 1));
+        assert_eq!(v.xyz(), v);
+        assert_eq!(v.bgr(), Vec3::new(3, 2, 1));
+        assert_eq!(v.xyzz(), Vec4::new(1, 2, 3, 3));
+    }
+
+    #[test]
+    fn test_swizzle_vec4() {
+        let v = Vec4::new(1, 2, 3, 4);
+        assert_eq!(v.wx(), Vec2::new(4, 1));
+        assert_eq!(v.xyz(), v.truncate());
+        assert_eq!(v.rgb(), Vec3::new(1, 2, 3));
+        assert_eq!(v.wzyx(), Vec4::new(4, 3, 2, 1));
+        assert_eq!(v.bgra(), Vec4::new(3, 2, 1, 4));
+        assert_eq!(v.xyzw(), v);
+    }
+
+    #[test]
+    fn test_swizzle_const() {
+        const V: Vec3<f32> = Vec3::new(1.0, 2.0, 3.0);
+        const XZ: Vec2<f32> = V.xz();
+        assert_eq!(XZ, Vec2::new(1.0, 3.0));
+    }
+}
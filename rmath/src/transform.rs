@@ -0,0 +1,258 @@
+use std::ops::Mul;
+
+use crate::{Scalar, float::Float, mat3::Mat3, mat4::Mat4, quat::Quat, vec3::Vec3};
+
+/// Translation, rotation and scale, applied as scale, then rotation, then
+/// translation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transform<T: Copy> {
+    pub translation: Vec3<T>,
+    pub rotation: Quat<T>,
+    pub scale: Vec3<T>,
+}
+
+impl<T: Copy> Transform<T> {
+    #[inline]
+    pub const fn new(translation: Vec3<T>, rotation: Quat<T>, scale: Vec3<T>) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+}
+
+impl<T: Float> Transform<T> {
+    #[inline]
+    pub const fn identity() -> Self {
+        Self::new(Vec3::zero(), Quat::identity(), Vec3::one())
+    }
+
+    pub fn from_translation(translation: Vec3<T>) -> Self {
+        Self {
+            translation,
+            ..Self::identity()
+        }
+    }
+
+    pub fn from_rotation(rotation: Quat<T>) -> Self {
+        Self {
+            rotation,
+            ..Self::identity()
+        }
+    }
+
+    pub fn from_scale(scale: Vec3<T>) -> Self {
+        Self {
+            scale,
+            ..Self::identity()
+        }
+    }
+
+    pub fn to_mat4(&self) -> Mat4<T> {
+        let [c0, c1, c2] = self.rotation.to_mat3().columns();
+        let Vec3 { x, y, z } = self.scale;
+        Mat4::new(
+            (c0 * Scalar(x)).expand(T::ZERO),
+            (c1 * Scalar(y)).expand(T::ZERO),
+            (c2 * Scalar(z)).expand(T::ZERO),
+            self.translation.expand(T::ONE),
+        )
+    }
+
+    /// Splits an affine matrix into translation, rotation and scale.
+    ///
+    /// Shear is discarded and a mirrored basis is expressed as a negative x
+    /// scale. Returns `None` if any axis is collapsed.
+    pub fn from_mat4(m: &Mat4<T>) -> Option<Self> {
+        let [c0, c1, c2, c3] = m.columns();
+        let (c0, c1, c2) = (c0.truncate(), c1.truncate(), c2.truncate());
+
+        let mut scale = Vec3::new(c0.length(), c1.length(), c2.length());
+        if Vec3::cminf(scale) <= T::EPSILON {
+            return None;
+        }
+        if Mat3::new(c0, c1, c2).determinant() < T::ZERO {
+            scale.x = -scale.x;
+        }
+
+        let [m00, m10, m20] = (c0 / scale.x).data();
+        let [m01, m11, m21] = (c1 / scale.y).data();
+        let [m02, m12, m22] = (c2 / scale.z).data();
+        let rotation = Quat::from_rotation_cols([m00, m10, m20, m01, m11, m21, m02, m12, m22]);
+
+        Some(Self::new(c3.truncate(), rotation, scale))
+    }
+
+    /// Exact for uniform scale; with non-uniform scale and rotation the
+    /// inverse contains shear that a `Transform` cannot hold.
+    pub fn invert(&self) -> Option<Self> {
+        if self.scale.data().iter().any(|s| s.abs() <= T::EPSILON) {
+            return None;
+        }
+
+        let rotation = self.rotation.normalize().conjugate();
+        let scale = self.scale.map(|s| T::ONE / s);
+        let translation = rotation
+            .rotate(&-self.translation)
+            .map_with(&scale, |t, s| t * s);
+        Some(Self::new(translation, rotation, scale))
+    }
+
+    pub fn transform_point(&self, p: &Vec3<T>) -> Vec3<T> {
+        self.translation + self.transform_vector(p)
+    }
+
+    pub fn transform_vector(&self, v: &Vec3<T>) -> Vec3<T> {
+        self.rotation.rotate(&v.map_with(&self.scale, |v, s| v * s))
+    }
+
+    /// Component-wise lerp of translation and scale, slerp of rotation.
+    pub fn lerp(a: &Self, b: &Self, t: T) -> Self {
+        let lerp = |a: T, b: T| a + (b - a) * t;
+        Self::new(
+            a.translation.map_with(&b.translation, lerp),
+            Quat::slerp(&a.rotation, &b.rotation, t),
+            a.scale.map_with(&b.scale, lerp),
+        )
+    }
+
+    pub fn cast<U: Float>(&self) -> Transform<U> {
+        Transform::new(
+            self.translation.cast(),
+            self.rotation.cast(),
+            self.scale.cast(),
+        )
+    }
+}
+
+impl<T: Float> Default for Transform<T> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+/// `parent * child` places `child` in the space of `parent`. Like
+/// [`Transform::invert`] this is exact unless a non-uniformly scaled parent
+/// has a rotated child.
+impl<T: Float> Mul for Transform<T> {
+    type Output = Self;
+
+    fn mul(self, child: Self) -> Self {
+        Self::new(
+            self.transform_point(&child.translation),
+            self.rotation * child.rotation,
+            self.scale.map_with(&child.scale, |a, b| a * b),
+        )
+    }
+}
+
+impl<T: Float> From<Transform<T>> for Mat4<T> {
+    fn from(transform: Transform<T>) -> Self {
+        transform.to_mat4()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_approx_eq;
+
+    fn sample() -> Transform<f32> {
+        Transform::new(
+            Vec3::new(1.0, -2.0, 3.0),
+            Quat::from_axis_angle(40.0, &Vec3::new(0.2, 1.0, -0.4)),
+            Vec3::new(2.0, 0.5, 3.0),
+        )
+    }
+
+    #[test]
+    fn test_transform_to_mat4() {
+        let t = sample();
+        let expected =
+            Mat4::translation(&t.translation) * t.rotation.to_mat4() * Mat4::scale(&t.scale);
+        assert_approx_eq!(t.to_mat4(), expected, abs = 1e-4);
+
+        let p = Vec3::new(0.5, 1.0, -1.5);
+        assert_approx_eq!(
+            t.transform_point(&p),
+            expected.transform_point(&p),
+            abs = 1e-4
+        );
+    }
+
+    #[test]
+    fn test_transform_from_mat4() {
+        let t = sample();
+        let decomposed = Transform::from_mat4(&t.to_mat4()).unwrap();
+        assert_approx_eq!(decomposed.translation, t.translation, abs = 1e-4);
+        assert_approx_eq!(decomposed.scale, t.scale, abs = 1e-4);
+        assert!(decomposed.rotation.dot(&t.rotation).abs() > 1.0 - 1e-5);
+
+        let mirrored = Mat4::scale(&Vec3::new(-1.0, 1.0, 1.0));
+        let decomposed = Transform::from_mat4(&mirrored).unwrap();
+        assert_approx_eq!(decomposed.to_mat4(), mirrored, abs = 1e-4);
+
+        let flat = Mat4::scale(&Vec3::new(1.0, 0.0, 1.0));
+        assert_eq!(Transform::from_mat4(&flat), None);
+    }
+
+    #[test]
+    fn test_transform_compose() {
+        let parent = Transform::new(
+            Vec3::new(0.0, 5.0, 0.0),
+            Quat::from_axis_angle(90.0, &Vec3::new(0.0, 1.0, 0.0)),
+            Vec3::scalar(2.0),
+        );
+        let child = sample();
+        assert_approx_eq!(
+            (parent * child).to_mat4(),
+            (parent.to_mat4() * child.to_mat4()),
+            abs = 1e-4
+        );
+    }
+
+    #[test]
+    fn test_transform_invert() {
+        let t = Transform::new(
+            Vec3::new(1.0, -2.0, 3.0),
+            Quat::from_axis_angle(-70.0, &Vec3::new(1.0, 1.0, 0.0)),
+            Vec3::scalar(0.5),
+        );
+        let inverse = t.invert().unwrap();
+        assert_approx_eq!((t * inverse).to_mat4(), Mat4::identity(), abs = 1e-4);
+        assert_approx_eq!(inverse.to_mat4(), t.to_mat4().invert().unwrap(), abs = 1e-4);
+
+        assert_eq!(
+            Transform::from_scale(Vec3::new(1.0, 0.0, 1.0)).invert(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_transform_lerp() {
+        let a = Transform::<f32>::identity();
+        let b = Transform::new(
+            Vec3::new(2.0, 0.0, -4.0),
+            Quat::from_axis_angle(90.0, &Vec3::new(0.0, 0.0, 1.0)),
+            Vec3::scalar(3.0),
+        );
+        let mid = Transform::lerp(&a, &b, 0.5);
+        assert_approx_eq!(mid.translation, Vec3::new(1.0, 0.0, -2.0), abs = 1e-4);
+        assert_approx_eq!(mid.scale, Vec3::scalar(2.0), abs = 1e-4);
+        let expected = Quat::from_axis_angle(45.0, &Vec3::new(0.0, 0.0, 1.0));
+        assert!(mid.rotation.dot(&expected) > 1.0 - 1e-5);
+
+        assert_eq!(Transform::lerp(&a, &b, 0.0), a);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_transform_serde() {
+        let t = sample();
+        let json = serde_json::to_string(&t).unwrap();
+        assert!(json.contains("\"translation\":{\"x\":1.0,\"y\":-2.0,\"z\":3.0}"));
+        assert_eq!(serde_json::from_str::<Transform<f32>>(&json).unwrap(), t);
+    }
+}
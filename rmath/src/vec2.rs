@@ -1,12 +1,16 @@
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::{
+    iter::Sum,
+    ops::{Add, AddAssign, Div, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign},
+};
 
 #[cfg(feature = "imgui")]
 use imgui_sys::bindings::ImVec2;
 
-use crate::Scalar;
+use crate::{Scalar, float::Float, vec3::Vec3};
 
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vec2<T: Copy> {
     pub x: T,
     pub y: T,
@@ -22,6 +26,124 @@ impl<T: Copy> Vec2<T> {
     pub const fn data(&self) -> [T; 2] {
         [self.x, self.y]
     }
+
+    pub fn map<U, F>(&self, f: F) -> Vec2<U>
+    where
+        F: Fn(T) -> U,
+        U: Copy,
+    {
+        let Self { x, y } = *self;
+        Vec2::<U>::new(f(x), f(y))
+    }
+
+    pub fn map_with<U, F>(&self, o: &Self, f: F) -> Vec2<U>
+    where
+        F: Fn(T, T) -> U,
+        U: Copy,
+    {
+        let Self { x, y } = *self;
+        Vec2::<U>::new(f(x, o.x), f(y, o.y))
+    }
+
+    pub fn to<U>(&self) -> Vec2<U>
+    where
+        T: Into<U>,
+        U: Copy,
+    {
+        self.map(T::into)
+    }
+
+    pub const fn expand(&self, z: T) -> Vec3<T> {
+        let Self { x, y } = *self;
+        Vec3::new(x, y, z)
+    }
+
+    pub const fn from_slice(slice: &[T]) -> Option<Self> {
+        if let [x, y, ..] = *slice {
+            Some(Self::new(x, y))
+        } else {
+            None
+        }
+    }
+
+    pub fn cmin(&self) -> T
+    where
+        T: Ord,
+    {
+        self.x.min(self.y)
+    }
+
+    pub fn cmax(&self) -> T
+    where
+        T: Ord,
+    {
+        self.x.max(self.y)
+    }
+}
+
+impl<T: Float> Vec2<T> {
+    pub const fn zero() -> Self {
+        Self::scalar(T::ZERO)
+    }
+
+    pub const fn one() -> Self {
+        Self::scalar(T::ONE)
+    }
+
+    pub const fn scalar(s: T) -> Self {
+        Self::new(s, s)
+    }
+
+    pub fn normalize(&self) -> Self {
+        let length = self.length();
+        if length == T::ZERO {
+            Self::zero()
+        } else {
+            *self / Scalar(length)
+        }
+    }
+
+    pub fn length(&self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    pub fn distance(&self, other: &Self) -> T {
+        (*other - *self).length()
+    }
+
+    pub fn dot(&self, other: &Self) -> T {
+        let Self { x, y } = *self;
+        let Self { x: ox, y: oy } = *other;
+        x * ox + y * oy
+    }
+
+    pub fn min(Self { x, y }: Self, Self { x: ox, y: oy }: Self) -> Self {
+        Self::new(x.min(ox), y.min(oy))
+    }
+
+    pub fn max(Self { x, y }: Self, Self { x: ox, y: oy }: Self) -> Self {
+        Self::new(x.max(ox), y.max(oy))
+    }
+
+    pub fn clamp(&self, min: &Self, max: &Self) -> Self {
+        Self::max(Self::min(*self, *max), *min)
+    }
+
+    pub fn abs(&self) -> Self {
+        self.map(T::abs)
+    }
+
+    pub fn cminf(Self { x, y }: Self) -> T {
+        x.min(y)
+    }
+
+    pub fn cmaxf(Self { x, y }: Self) -> T {
+        x.max(y)
+    }
+
+    pub fn cast<U: Float>(&self) -> Vec2<U> {
+        self.map(T::cast)
+    }
 }
 
 impl<T: Copy> Neg for Vec2<T>
@@ -49,6 +171,20 @@ impl<T: Copy + Add<Output = T>> Add for Vec2<T> {
     }
 }
 
+impl<T: Copy + AddAssign> AddAssign for Vec2<T> {
+    fn add_assign(&mut self, Self { x, y }: Self) {
+        self.x += x;
+        self.y += y;
+    }
+}
+
+impl<T: Copy + SubAssign> SubAssign for Vec2<T> {
+    fn sub_assign(&mut self, Self { x, y }: Self) {
+        self.x -= x;
+        self.y -= y;
+    }
+}
+
 impl<T: Copy + Sub<Output = T>> Sub for Vec2<T> {
     type Output = Self;
 
@@ -71,6 +207,22 @@ impl<T: Copy + Mul<Output = T>> Mul<Scalar<T>> for Vec2<T> {
     }
 }
 
+impl<T: Copy + Mul<Output = T>> MulAssign<Scalar<T>> for Vec2<T> {
+    fn mul_assign(&mut self, Scalar(s): Scalar<T>) {
+        let Self { x, y } = *self;
+        self.x = x * s;
+        self.y = y * s;
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul for Vec2<T> {
+    type Output = Self;
+
+    fn mul(self, Self { x, y }: Self) -> Self {
+        Self::new(self.x * x, self.y * y)
+    }
+}
+
 impl<T: Copy + Mul<Output = T>> Mul<Vec2<T>> for Scalar<T> {
     type Output = Vec2<T>;
 
@@ -93,6 +245,42 @@ impl<T: Copy + Div<Output = T>> Div<Scalar<T>> for Vec2<T> {
     }
 }
 
+impl<T: Copy + Div<Output = T>> Div for Vec2<T> {
+    type Output = Self;
+
+    fn div(self, Self { x, y }: Self) -> Self {
+        Self::new(self.x / x, self.y / y)
+    }
+}
+
+impl<T: Copy> Index<usize> for Vec2<T> {
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        match i {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("Vec2 index out of bounds: {i}"),
+        }
+    }
+}
+
+impl<T: Copy> IndexMut<usize> for Vec2<T> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        match i {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("Vec2 index out of bounds: {i}"),
+        }
+    }
+}
+
+impl<T: Copy + Default + Add<Output = T>> Sum for Vec2<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
 impl<T: Copy> From<(T, T)> for Vec2<T> {
     fn from((x, y): (T, T)) -> Self {
         Self::new(x, y)
@@ -162,6 +350,78 @@ mod tests {
         assert_eq!(result, Vec2::new(0.5, 1.0));
     }
 
+    #[test]
+    fn test_vec2_assign_ops() {
+        let mut a = Vec2::new(1.0, 2.0);
+        a += Vec2::new(3.0, 4.0);
+        assert_eq!(a, Vec2::new(4.0, 6.0));
+        a -= Vec2::new(1.0, 1.0);
+        assert_eq!(a, Vec2::new(3.0, 5.0));
+        a *= Scalar(2.0);
+        assert_eq!(a, Vec2::new(6.0, 10.0));
+    }
+
+    #[test]
+    fn test_vec2_component_ops() {
+        let a = Vec2::new(2.0, 6.0);
+        let b = Vec2::new(4.0, 3.0);
+        assert_eq!(a * b, Vec2::new(8.0, 18.0));
+        assert_eq!(a / b, Vec2::new(0.5, 2.0));
+        assert_eq!(Vec2::min(a, b), Vec2::new(2.0, 3.0));
+        assert_eq!(Vec2::max(a, b), Vec2::new(4.0, 6.0));
+        assert_eq!(
+            Vec2::new(-2.0, 5.0).clamp(&Vec2::zero(), &Vec2::scalar(3.0)),
+            Vec2::new(0.0, 3.0)
+        );
+        assert_eq!(Vec2::new(-2.0, 5.0).abs(), Vec2::new(2.0, 5.0));
+        // Inverted bounds resolve to `min` instead of panicking, like Vec3/Vec4.
+        assert_eq!(
+            Vec2::new(1.0, 1.0).clamp(&Vec2::scalar(2.0), &Vec2::zero()),
+            Vec2::scalar(2.0)
+        );
+        assert_eq!(
+            Vec2::new(1.0, 5.0).clamp(&Vec2::new(f32::NAN, 0.0), &Vec2::scalar(3.0)),
+            Vec2::new(1.0, 3.0)
+        );
+    }
+
+    #[test]
+    fn test_vec2_length() {
+        let a = Vec2::new(3.0, 4.0);
+        assert_eq!(a.dot(&Vec2::new(1.0, 1.0)), 7.0);
+        assert_eq!(a.length(), 5.0);
+        assert_eq!(a.normalize(), Vec2::new(0.6, 0.8));
+        assert_eq!(Vec2::new(1.0, 1.0).distance(&Vec2::new(4.0, 5.0)), 5.0);
+        assert_eq!(Vec2::<f32>::zero().normalize(), Vec2::zero());
+    }
+
+    #[test]
+    fn test_vec2_index() {
+        let mut a = Vec2::new(1, 2);
+        a[1] = 5;
+        assert_eq!((a[0], a[1]), (1, 5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vec2_index_out_of_bounds() {
+        let _ = Vec2::new(1, 2)[2];
+    }
+
+    #[test]
+    fn test_vec2_sum() {
+        let sum: Vec2<i32> = (1..=3).map(|i| Vec2::new(i, -i)).sum();
+        assert_eq!(sum, Vec2::new(6, -6));
+    }
+
+    #[test]
+    fn test_vec2_map() {
+        assert_eq!(Vec2::new(1, 0).map(|x| x == 0), Vec2::new(false, true));
+        assert_eq!(Vec2::from_slice(&[1, 2, 3]), Some(Vec2::new(1, 2)));
+        assert_eq!(Vec2::from_slice(&[1]), None);
+        assert_eq!(Vec2::new(1, 2).expand(3), Vec3::new(1, 2, 3));
+    }
+
     #[test]
     fn test_vec2_eq() {
         let a = Vec2::new(1.0, 2.0);
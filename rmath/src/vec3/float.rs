@@ -2,26 +2,26 @@ use std::ops::Div;
 
 use super::Vec3;
 
-use crate::Scalar;
+use crate::{Scalar, float::Float};
 
-impl Vec3<f32> {
-    pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
+impl<T: Float> Vec3<T> {
+    pub const fn rgb(r: T, g: T, b: T) -> Self {
         Self::new(r, g, b)
     }
 
     pub const fn zero() -> Self {
-        Self::new(0.0, 0.0, 0.0)
+        Self::scalar(T::ZERO)
     }
 
     pub const fn one() -> Self {
-        Self::new(1.0, 1.0, 1.0)
+        Self::scalar(T::ONE)
     }
 
-    pub const fn scalar(s: f32) -> Self {
+    pub const fn scalar(s: T) -> Self {
         Self::new(s, s, s)
     }
 
-    pub fn rotate(&self, angle: f32, axis: &Vec3<f32>) -> Self {
+    pub fn rotate(&self, angle: T, axis: &Self) -> Self {
         let theta = angle.to_radians();
         let c = theta.cos();
         let s = Scalar(theta.sin());
@@ -31,16 +31,16 @@ impl Vec3<f32> {
             x: tx,
             y: ty,
             z: tz,
-        } = axis * Scalar(1.0 - c);
+        } = axis * Scalar(T::ONE - c);
         let Self { x, y, z } = *self;
         Self::new(
             x * c + tx * self.dot(&axis),
             y * c + ty * self.dot(&axis),
             z * c + tz * self.dot(&axis),
-        ) + axis.cross(&self) * s
+        ) + axis.cross(self) * s
     }
 
-    pub const fn cross(&self, other: &Self) -> Self {
+    pub fn cross(&self, other: &Self) -> Self {
         let Self { x, y, z } = *self;
         let Self {
             x: ox,
@@ -52,19 +52,18 @@ impl Vec3<f32> {
 
     pub fn normalize(&self) -> Self {
         let length = self.length();
-        if length == 0.0 {
+        if length == T::ZERO {
             Self::zero()
         } else {
             *self / length
         }
     }
 
-    pub fn length(&self) -> f32 {
-        let Self { x, y, z } = self;
-        (x * x + y * y + z * z).sqrt()
+    pub fn length(&self) -> T {
+        self.dot(self).sqrt()
     }
 
-    pub const fn dot(&self, other: &Self) -> f32 {
+    pub fn dot(&self, other: &Self) -> T {
         let Self { x, y, z } = *self;
         let Self {
             x: ox,
@@ -74,7 +73,7 @@ impl Vec3<f32> {
         x * ox + y * oy + z * oz
     }
 
-    pub const fn min(
+    pub fn min(
         Self { x, y, z }: Self,
         Self {
             x: ox,
@@ -85,7 +84,7 @@ impl Vec3<f32> {
         Self::new(x.min(ox), y.min(oy), z.min(oz))
     }
 
-    pub const fn max(
+    pub fn max(
         Self { x, y, z }: Self,
         Self {
             x: ox,
@@ -96,6 +95,18 @@ impl Vec3<f32> {
         Self::new(x.max(ox), y.max(oy), z.max(oz))
     }
 
+    pub fn clamp(&self, min: &Self, max: &Self) -> Self {
+        Self::max(Self::min(*self, *max), *min)
+    }
+
+    pub fn abs(&self) -> Self {
+        self.map(T::abs)
+    }
+
+    pub fn distance(&self, other: &Self) -> T {
+        (*other - *self).length()
+    }
+
     pub fn pow<B, E>(base: B, exp: E) -> Self
     where
         B: Into<Self>,
@@ -106,20 +117,36 @@ impl Vec3<f32> {
         Self::new(x.powf(b.x), y.powf(b.y), z.powf(b.z))
     }
 
+    pub fn cminf(Self { x, y, z }: Self) -> T {
+        x.min(y).min(z)
+    }
+
+    pub fn cmaxf(Self { x, y, z }: Self) -> T {
+        x.max(y).max(z)
+    }
+
+    pub fn cast<U: Float>(&self) -> Vec3<U> {
+        self.map(T::cast)
+    }
+}
+
+impl Vec3<f32> {
+    #[deprecated(note = "use `Vec3::cminf`")]
     pub const fn cminf32(Self { x, y, z }: Self) -> f32 {
         x.min(y).min(z)
     }
 
+    #[deprecated(note = "use `Vec3::cmaxf`")]
     pub const fn cmaxf32(Self { x, y, z }: Self) -> f32 {
         x.max(y).max(z)
     }
 }
 
-impl Div<f32> for Vec3<f32> {
+impl<T: Float> Div<T> for Vec3<T> {
     type Output = Self;
 
-    fn div(self, s: f32) -> Self {
-        if s == 0.0 {
+    fn div(self, s: T) -> Self {
+        if s == T::ZERO {
             self
         } else {
             let Self { x, y, z } = self;
@@ -166,6 +193,7 @@ impl From<assimp::Color3D> for Vec3<f32> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::assert_approx_eq;
 
     #[test]
     fn test_vec3_cross() {
@@ -180,7 +208,8 @@ mod tests {
         let a = Vec3::new(1.0, 2.0, 3.0);
         let length = a.length();
         let result = a.normalize();
-        assert_eq!(result, Vec3::new(1.0 / length, 2.0 / length, 3.0 / length));
+        assert_approx_eq!(result, Vec3::new(1.0 / length, 2.0 / length, 3.0 / length));
+        assert_approx_eq!(result.length(), 1.0);
     }
 
     #[test]
@@ -189,6 +218,17 @@ mod tests {
         assert_eq!(a.length(), 1.0);
     }
 
+    #[test]
+    fn test_vec3_clamp() {
+        let a = Vec3::new(-1.0, 0.5, 3.0);
+        assert_eq!(
+            a.clamp(&Vec3::zero(), &Vec3::one()),
+            Vec3::new(0.0, 0.5, 1.0)
+        );
+        assert_eq!(a.abs(), Vec3::new(1.0, 0.5, 3.0));
+        assert_eq!(Vec3::zero().distance(&Vec3::new(2.0, 3.0, 6.0)), 7.0);
+    }
+
     #[test]
     fn test_vec3_dot() {
         let a = Vec3::new(1.0, 2.0, 3.0);
@@ -196,6 +236,14 @@ mod tests {
         assert_eq!(a.dot(&b), 32.0);
     }
 
+    #[test]
+    #[allow(deprecated)]
+    fn test_vec3_deprecated_component_min_max() {
+        let a = Vec3::new(2.0, -1.0, 5.0);
+        assert_eq!(Vec3::cminf32(a), Vec3::cminf(a));
+        assert_eq!(Vec3::cmaxf32(a), Vec3::cmaxf(a));
+    }
+
     #[test]
     #[cfg(feature = "assimp")]
     fn test_vec3_from_assimp() {
@@ -204,6 +252,34 @@ mod tests {
         assert_eq!(vec, Vec3::new(1.0, 2.0, 3.0));
     }
 
+    #[test]
+    fn test_vec3_f64() {
+        let a = Vec3::new(1.0f64, 0.0, 0.0);
+        let b = Vec3::new(0.0f64, 1.0, 0.0);
+        assert_eq!(a.cross(&b), Vec3::new(0.0, 0.0, 1.0));
+        assert_eq!(Vec3::new(3.0f64, 4.0, 0.0).length(), 5.0);
+        assert_eq!(
+            Vec3::new(0.0f64, 0.0, 2.0).normalize(),
+            Vec3::new(0.0, 0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn test_vec3_rotate_f64_matches_f32() {
+        let axis = Vec3::new(0.3, 1.0, -0.2);
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        let r32 = v.rotate(40.0f32, &axis);
+        let r64 = v.cast::<f64>().rotate(40.0, &axis.cast());
+        assert!((r64.cast::<f32>() - r32).length() < 1e-5);
+    }
+
+    #[test]
+    fn test_vec3_cast() {
+        let a = Vec3::new(1.5f32, -2.0, 0.25);
+        assert_eq!(a.cast::<f64>(), Vec3::new(1.5f64, -2.0, 0.25));
+        assert_eq!(a.cast::<f64>().cast::<f32>(), a);
+    }
+
     #[test]
     fn test_vec3_max() {
         let a = Vec3::new(1.0, 2.0, 3.0);
@@ -1,11 +1,15 @@
-pub mod f32;
+pub mod float;
 
-use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Not, Sub, SubAssign};
+use std::{
+    iter::Sum,
+    ops::{Add, AddAssign, Div, Index, IndexMut, Mul, MulAssign, Neg, Not, Sub, SubAssign},
+};
 
 use crate::{Scalar, vec2::Vec2, vec4::Vec4};
 
 #[repr(C)]
 #[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vec3<T>
 where
     T: Copy,
@@ -193,6 +197,17 @@ where
     }
 }
 
+impl<T> Mul<Vec3<T>> for Vec3<T>
+where
+    T: Copy + Mul<Output = T>,
+{
+    type Output = Vec3<T>;
+
+    fn mul(self, Self::Output { x, y, z }: Self::Output) -> Self::Output {
+        Self::Output::new(self.x * x, self.y * y, self.z * z)
+    }
+}
+
 impl<T> Div<Vec3<T>> for Vec3<T>
 where
     T: Copy + Div<Output = T>,
@@ -216,6 +231,45 @@ where
     }
 }
 
+impl<T> Index<usize> for Vec3<T>
+where
+    T: Copy,
+{
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        match i {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Vec3 index out of bounds: {i}"),
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for Vec3<T>
+where
+    T: Copy,
+{
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        match i {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("Vec3 index out of bounds: {i}"),
+        }
+    }
+}
+
+impl<T> Sum for Vec3<T>
+where
+    T: Copy + Default + Add<Output = T>,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
 impl<T> From<[T; 3]> for Vec3<T>
 where
     T: Copy,
@@ -335,6 +389,27 @@ mod tests {
         assert_eq!(Vec3::from_slice(&[0]), None)
     }
 
+    #[test]
+    fn test_vec3_index() {
+        let mut a = Vec3::new(1, 2, 3);
+        a[2] = 9;
+        assert_eq!([a[0], a[1], a[2]], [1, 2, 9]);
+    }
+
+    #[test]
+    fn test_vec3_sum() {
+        let sum: Vec3<i32> = (1..=3).map(|i| Vec3::new(i, 0, -i)).sum();
+        assert_eq!(sum, Vec3::new(6, 0, -6));
+    }
+
+    #[test]
+    fn test_vec3_component_mul() {
+        assert_eq!(
+            Vec3::new(1, 2, 3) * Vec3::new(4, 5, 6),
+            Vec3::new(4, 10, 18)
+        );
+    }
+
     #[test]
     fn test_vec3_map() {
         assert_eq!(
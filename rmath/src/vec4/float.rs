@@ -0,0 +1,175 @@
+#[cfg(feature = "imgui")]
+use imgui_sys::bindings::ImVec4;
+
+use super::Vec4;
+
+use std::ops::Div;
+
+use crate::float::Float;
+
+impl<T: Float> Vec4<T> {
+    pub const fn rgba(r: T, g: T, b: T, a: T) -> Self {
+        Self::new(r, g, b, a)
+    }
+
+    pub const fn zero() -> Self {
+        Self::scalar(T::ZERO)
+    }
+
+    pub const fn one() -> Self {
+        Self::scalar(T::ONE)
+    }
+
+    pub const fn scalar(s: T) -> Self {
+        Self::new(s, s, s, s)
+    }
+
+    pub fn normalize(&self) -> Self {
+        let length = self.length();
+        if length == T::ZERO {
+            Self::zero()
+        } else {
+            *self / length
+        }
+    }
+
+    pub fn length(&self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    pub fn distance(&self, other: &Self) -> T {
+        (*other - *self).length()
+    }
+
+    #[inline]
+    pub fn dot(&self, other: &Self) -> T {
+        T::vec4_dot(self, other)
+    }
+
+    pub fn min(a: Self, b: Self) -> Self {
+        a.map_with(&b, T::min)
+    }
+
+    pub fn max(a: Self, b: Self) -> Self {
+        a.map_with(&b, T::max)
+    }
+
+    pub fn clamp(&self, min: &Self, max: &Self) -> Self {
+        Self::max(Self::min(*self, *max), *min)
+    }
+
+    pub fn abs(&self) -> Self {
+        self.map(T::abs)
+    }
+
+    pub fn cminf(Self { x, y, z, w }: Self) -> T {
+        x.min(y).min(z).min(w)
+    }
+
+    pub fn cmaxf(Self { x, y, z, w }: Self) -> T {
+        x.max(y).max(z).max(w)
+    }
+
+    pub fn cast<U: Float>(&self) -> Vec4<U> {
+        self.map(T::cast)
+    }
+}
+
+impl<T: Float> Div<T> for Vec4<T> {
+    type Output = Self;
+
+    fn div(self, s: T) -> Self {
+        if s == T::ZERO {
+            self
+        } else {
+            self.map(|c| c / s)
+        }
+    }
+}
+
+#[cfg(feature = "assimp")]
+impl From<assimp::Color4D> for Vec4<f32> {
+    fn from(c: assimp::Color4D) -> Self {
+        Self::rgba(c.r, c.g, c.b, c.a)
+    }
+}
+
+#[cfg(feature = "assimp")]
+impl From<Vec4<f32>> for assimp::Color4D {
+    fn from(Vec4 { x, y, z, w }: Vec4<f32>) -> Self {
+        Self::new(x, y, z, w)
+    }
+}
+
+#[cfg(feature = "imgui")]
+impl From<ImVec4> for Vec4<f32> {
+    fn from(ImVec4 { x, y, z, w }: ImVec4) -> Self {
+        Self { x, y, z, w }
+    }
+}
+
+#[cfg(feature = "imgui")]
+impl From<Vec4<f32>> for ImVec4 {
+    fn from(Vec4 { x, y, z, w }: Vec4<f32>) -> Self {
+        Self { x, y, z, w }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vec4_rgba() {
+        let color = Vec4::rgba(1.0, 0.0, 0.0, 1.0);
+        assert_eq!(color.x, 1.0);
+        assert_eq!(color.y, 0.0);
+        assert_eq!(color.z, 0.0);
+        assert_eq!(color.w, 1.0);
+    }
+
+    #[test]
+    #[cfg(feature = "assimp")]
+    fn test_vec4_from_color4d() {
+        let color = assimp::Color4D::new(1.0, 0.0, 0.0, 1.0);
+        let vec4: Vec4<f32> = color.into();
+        assert_eq!(vec4.x, 1.0);
+        assert_eq!(vec4.y, 0.0);
+        assert_eq!(vec4.z, 0.0);
+        assert_eq!(vec4.w, 1.0);
+    }
+
+    #[test]
+    #[cfg(feature = "assimp")]
+    fn test_vec4_to_color4d() {
+        let vec4 = Vec4::rgba(1.0, 0.0, 0.0, 1.0);
+        let color: assimp::Color4D = vec4.into();
+        assert_eq!(color.r, 1.0);
+        assert_eq!(color.g, 0.0);
+        assert_eq!(color.b, 0.0);
+        assert_eq!(color.a, 1.0);
+    }
+
+    #[test]
+    fn test_vec4_length() {
+        let a = Vec4::new(1.0, 2.0, 2.0, 4.0);
+        assert_eq!(a.length(), 5.0);
+        assert_eq!(a.normalize(), Vec4::new(0.2, 0.4, 0.4, 0.8));
+        assert_eq!(Vec4::zero().distance(&a), 5.0);
+        assert_eq!(Vec4::<f64>::zero().normalize(), Vec4::zero());
+    }
+
+    #[test]
+    fn test_vec4_min_max() {
+        let a = Vec4::new(-1.0, 5.0, 0.5, 2.0);
+        let b = Vec4::new(0.0, 1.0, 1.0, 2.0);
+        assert_eq!(Vec4::min(a, b), Vec4::new(-1.0, 1.0, 0.5, 2.0));
+        assert_eq!(Vec4::max(a, b), Vec4::new(0.0, 5.0, 1.0, 2.0));
+        assert_eq!(
+            a.clamp(&Vec4::zero(), &Vec4::one()),
+            Vec4::new(0.0, 1.0, 0.5, 1.0)
+        );
+        assert_eq!(a.abs(), Vec4::new(1.0, 5.0, 0.5, 2.0));
+        assert_eq!((Vec4::cminf(a), Vec4::cmaxf(a)), (-1.0, 5.0));
+    }
+}
@@ -1,11 +1,15 @@
-use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::{
+    iter::Sum,
+    ops::{Add, AddAssign, Div, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign},
+};
 
-use crate::Scalar;
+use crate::{Scalar, simd::Kernels, vec3::Vec3};
 
-pub mod f32;
+pub mod float;
 
 #[repr(C)]
 #[derive(Clone, Default, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vec4<T: Copy> {
     pub x: T,
     pub y: T,
@@ -22,6 +26,59 @@ impl<T: Copy> Vec4<T> {
     pub const fn data(&self) -> [T; 4] {
         [self.x, self.y, self.z, self.w]
     }
+
+    pub const fn truncate(&self) -> Vec3<T> {
+        let Self { x, y, z, .. } = *self;
+        Vec3::new(x, y, z)
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Vec4<U>
+    where
+        F: Fn(T) -> U,
+        U: Copy,
+    {
+        let Self { x, y, z, w } = *self;
+        Vec4::<U>::new(f(x), f(y), f(z), f(w))
+    }
+
+    pub fn map_with<U, F>(&self, o: &Self, f: F) -> Vec4<U>
+    where
+        F: Fn(T, T) -> U,
+        U: Copy,
+    {
+        let Self { x, y, z, w } = *self;
+        Vec4::<U>::new(f(x, o.x), f(y, o.y), f(z, o.z), f(w, o.w))
+    }
+
+    pub fn to<U>(&self) -> Vec4<U>
+    where
+        T: Into<U>,
+        U: Copy,
+    {
+        self.map(T::into)
+    }
+
+    pub const fn from_slice(slice: &[T]) -> Option<Self> {
+        if let [x, y, z, w, ..] = *slice {
+            Some(Self::new(x, y, z, w))
+        } else {
+            None
+        }
+    }
+
+    pub fn cmin(&self) -> T
+    where
+        T: Ord,
+    {
+        self.x.min(self.y).min(self.z).min(self.w)
+    }
+
+    pub fn cmax(&self) -> T
+    where
+        T: Ord,
+    {
+        self.x.max(self.y).max(self.z).max(self.w)
+    }
 }
 
 impl<T: Copy> Neg for Vec4<T>
@@ -40,16 +97,12 @@ where
     }
 }
 
-impl<T: Copy + Add<Output = T>> Add for Vec4<T> {
+impl<T: Kernels + Add<Output = T>> Add for Vec4<T> {
     type Output = Self;
 
-    fn add(self, Self { x, y, z, w }: Self) -> Self {
-        Self {
-            x: self.x + x,
-            y: self.y + y,
-            z: self.z + z,
-            w: self.w + w,
-        }
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        T::vec4_add(&self, &other)
     }
 }
 
@@ -77,29 +130,48 @@ where
     }
 }
 
-impl<T: Copy + Sub<Output = T>> Sub for Vec4<T> {
+impl<T: Kernels + Sub<Output = T>> Sub for Vec4<T> {
     type Output = Self;
 
-    fn sub(self, Self { x, y, z, w }: Self) -> Self {
-        Self {
-            x: self.x - x,
-            y: self.y - y,
-            z: self.z - z,
-            w: self.w - w,
-        }
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        T::vec4_sub(&self, &other)
     }
 }
 
-impl<T: Copy + Mul<Output = T>> Mul<Scalar<T>> for Vec4<T> {
+impl<T: Kernels + Mul<Output = T>> Mul<Scalar<T>> for Vec4<T> {
     type Output = Self;
 
+    #[inline]
     fn mul(self, Scalar(s): Scalar<T>) -> Self {
-        Self {
-            x: self.x * s,
-            y: self.y * s,
-            z: self.z * s,
-            w: self.w * s,
-        }
+        T::vec4_scale(&self, s)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> MulAssign<Scalar<T>> for Vec4<T> {
+    fn mul_assign(&mut self, Scalar(s): Scalar<T>) {
+        let Self { x, y, z, w } = *self;
+        self.x = x * s;
+        self.y = y * s;
+        self.z = z * s;
+        self.w = w * s;
+    }
+}
+
+impl<T: Kernels + Mul<Output = T>> Mul for Vec4<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        T::vec4_mul(&self, &other)
+    }
+}
+
+impl<T: Copy + Div<Output = T>> Div for Vec4<T> {
+    type Output = Self;
+
+    fn div(self, Self { x, y, z, w }: Self) -> Self {
+        Self::new(self.x / x, self.y / y, self.z / z, self.w / w)
     }
 }
 
@@ -117,6 +189,38 @@ impl<T: Copy + Mul<Output = T>> Mul<Vec4<T>> for Scalar<T> {
     }
 }
 
+impl<T: Copy> Index<usize> for Vec4<T> {
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        match i {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            3 => &self.w,
+            _ => panic!("Vec4 index out of bounds: {i}"),
+        }
+    }
+}
+
+impl<T: Copy> IndexMut<usize> for Vec4<T> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        match i {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            3 => &mut self.w,
+            _ => panic!("Vec4 index out of bounds: {i}"),
+        }
+    }
+}
+
+impl<T: Kernels + Default + Add<Output = T>> Sum for Vec4<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
 impl<T: Copy> From<(T, T, T, T)> for Vec4<T> {
     #[inline]
     fn from((x, y, z, w): (T, T, T, T)) -> Self {
@@ -130,3 +234,59 @@ impl<T: Copy> From<[T; 4]> for Vec4<T> {
         Self::new(x, y, z, w)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vec4_assign_ops() {
+        let mut a = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        a += Vec4::new(1.0, 1.0, 1.0, 1.0);
+        a -= Vec4::new(0.0, 1.0, 2.0, 3.0);
+        assert_eq!(a, Vec4::new(2.0, 2.0, 2.0, 2.0));
+        a *= Scalar(0.5);
+        assert_eq!(a, Vec4::new(1.0, 1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn test_vec4_component_ops() {
+        let a = Vec4::new(1, 2, 3, 4);
+        let b = Vec4::new(2, 2, 3, 2);
+        assert_eq!(a * b, Vec4::new(2, 4, 9, 8));
+        assert_eq!(a / b, Vec4::new(0, 1, 1, 2));
+        assert_eq!((a.cmin(), a.cmax()), (1, 4));
+    }
+
+    #[test]
+    fn test_vec4_index() {
+        let mut a = Vec4::new(1, 2, 3, 4);
+        a[3] = 7;
+        assert_eq!([a[0], a[1], a[2], a[3]], [1, 2, 3, 7]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vec4_index_out_of_bounds() {
+        let _ = Vec4::new(1, 2, 3, 4)[4];
+    }
+
+    #[test]
+    fn test_vec4_sum() {
+        let sum: Vec4<i32> = (0..4).map(|i| Vec4::new(i, 1, -i, 0)).sum();
+        assert_eq!(sum, Vec4::new(6, 4, -6, 0));
+    }
+
+    #[test]
+    fn test_vec4_map() {
+        assert_eq!(
+            Vec4::new(1, 0, 1, 0).map_with(&Vec4::new(1, 1, 0, 0), |a, b| a == b),
+            Vec4::new(true, false, false, true)
+        );
+        assert_eq!(
+            Vec4::from_slice(&[1, 2, 3, 4, 5]),
+            Some(Vec4::new(1, 2, 3, 4))
+        );
+        assert_eq!(Vec4::from_slice(&[1, 2, 3]), None);
+    }
+}